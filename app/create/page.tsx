@@ -43,7 +43,7 @@ const steps = [
 
 export default function CreateCampaignPage() {
   const { connected } = useWallet()
-  const { createCampaign, addMilestone: addMilestoneToChain, loading, error } = useHopeRise()
+  const { createCampaign, addMilestone: addMilestoneToChain, launchCampaign, loading, error } = useHopeRise()
   const router = useRouter()
   const [currentStep, setCurrentStep] = useState(1)
   const [isSubmitting, setIsSubmitting] = useState(false)
@@ -181,6 +181,9 @@ export default function CreateCampaignPage() {
         )
       }
 
+      // Campaigns start as drafts; launching opens them to contributions
+      await launchCampaign(result.campaignPda)
+
       // Redirect to explore page after successful creation
       router.push('/explore')
     } catch (err: unknown) {
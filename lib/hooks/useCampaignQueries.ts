@@ -2,12 +2,13 @@
 
 import { useQuery } from '@tanstack/react-query';
 import { useConnection } from '@solana/wallet-adapter-react';
-import { Program, AnchorProvider, BN } from '@coral-xyz/anchor';
+import { Program, AnchorProvider, BN, EventParser } from '@coral-xyz/anchor';
 import type { HopeRise } from '@/lib/idl/hope_rise';
 import { PublicKey } from '@solana/web3.js';
 import {
   idl,
   getCategoryString,
+  getStatusString,
   type Campaign,
   type Milestone,
   type Contribution,
@@ -21,8 +22,16 @@ export const campaignKeys = {
   detail: (id: string) => [...campaignKeys.details(), id] as const,
   milestones: (campaignId: string) => [...campaignKeys.all, 'milestones', campaignId] as const,
   contributions: (campaignId: string) => [...campaignKeys.all, 'contributions', campaignId] as const,
+  activity: (campaignId: string) => [...campaignKeys.all, 'activity', campaignId] as const,
 };
 
+export interface CampaignActivity {
+  signature: string;
+  name: string;
+  data: Record<string, unknown>;
+  timestamp: number;
+}
+
 function createReadOnlyProgram(connection: ReturnType<typeof useConnection>['connection']) {
   const provider = new AnchorProvider(
     connection,
@@ -36,6 +45,32 @@ function createReadOnlyProgram(connection: ReturnType<typeof useConnection>['con
   return new Program<HopeRise>(idl as unknown as HopeRise, provider);
 }
 
+// Every program event carries the campaign it concerns and the time it was emitted.
+export function parseCampaignActivity(
+  program: Program<HopeRise>,
+  campaign: PublicKey,
+  signature: string,
+  logs: string[],
+  blockTime?: number | null
+): CampaignActivity[] {
+  const parser = new EventParser(program.programId, program.coder);
+  const activity: CampaignActivity[] = [];
+
+  for (const event of parser.parseLogs(logs)) {
+    const data = event.data as Record<string, unknown>;
+    if (!(data.campaign as PublicKey | undefined)?.equals(campaign)) continue;
+
+    activity.push({
+      signature,
+      name: event.name,
+      data,
+      timestamp: (data.timestamp as BN | undefined)?.toNumber() ?? blockTime ?? 0,
+    });
+  }
+
+  return activity;
+}
+
 function transformCampaign(acc: { publicKey: PublicKey; account: Record<string, unknown> }): Campaign {
   return {
     publicKey: acc.publicKey,
@@ -50,7 +85,7 @@ function transformCampaign(acc: { publicKey: PublicKey; account: Record<string,
     deadline: (acc.account.deadline as BN).toNumber(),
     amountRaised: (acc.account.amountRaised as BN).toNumber(),
     backerCount: (acc.account.backerCount as BN).toNumber(),
-    isActive: acc.account.isActive as boolean,
+    status: getStatusString(acc.account.status as Record<string, object>),
     createdAt: (acc.account.createdAt as BN).toNumber(),
     milestoneCount: acc.account.milestoneCount as number,
     bump: acc.account.bump as number,
@@ -116,7 +151,7 @@ export function useCampaign(pubkeyString: string | undefined) {
         deadline: (acc.deadline as BN).toNumber(),
         amountRaised: (acc.amountRaised as BN).toNumber(),
         backerCount: (acc.backerCount as BN).toNumber(),
-        isActive: acc.isActive as boolean,
+        status: getStatusString(acc.status as Record<string, object>),
         createdAt: (acc.createdAt as BN).toNumber(),
         milestoneCount: acc.milestoneCount as number,
         bump: acc.bump as number,
@@ -204,3 +239,40 @@ export function useContributions(campaignPubkey: string | undefined, options?: {
     refetchInterval: options?.refetchInterval || 10000,
   });
 }
+
+export function useCampaignActivity(
+  campaignPubkey: string | undefined,
+  options?: { limit?: number; refetchInterval?: number }
+) {
+  const { connection } = useConnection();
+
+  return useQuery({
+    queryKey: campaignKeys.activity(campaignPubkey || ''),
+    queryFn: async (): Promise<CampaignActivity[]> => {
+      if (!campaignPubkey) return [];
+
+      const program = createReadOnlyProgram(connection);
+      const pubkey = new PublicKey(campaignPubkey);
+
+      const signatures = (await connection.getSignaturesForAddress(pubkey, { limit: options?.limit || 50 }))
+        .filter((sig) => !sig.err)
+        .map((sig) => sig.signature);
+      const transactions = await connection.getTransactions(signatures, {
+        commitment: 'confirmed',
+        maxSupportedTransactionVersion: 0,
+      });
+
+      return transactions
+        .flatMap((tx, i) =>
+          tx?.meta?.logMessages
+            ? parseCampaignActivity(program, pubkey, signatures[i], tx.meta.logMessages, tx.blockTime)
+            : []
+        )
+        .sort((a, b) => b.timestamp - a.timestamp);
+    },
+    enabled: !!campaignPubkey,
+    staleTime: 30 * 1000,
+    gcTime: 2 * 60 * 1000,
+    refetchInterval: options?.refetchInterval || 10000,
+  });
+}
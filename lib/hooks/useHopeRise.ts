@@ -7,20 +7,27 @@ import type { HopeRise } from '@/lib/idl/hope_rise';
 import { PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
 import {
   getAssociatedTokenAddress,
+  getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
   getAccount,
   createAssociatedTokenAccountInstruction
 } from '@solana/spl-token';
 import {
   idl,
+  getPlatformConfigPDA,
   getCampaignCounterPDA,
+  getProgramDataPDA,
   getCampaignPDA,
   getMilestonePDA,
+  getStretchGoalPDA,
   getContributionPDA,
+  getReceiptMintPDA,
   getCampaignVaultPDA,
   getCategoryEnum,
   getCategoryString,
+  getStatusString,
   displayToUsdc,
   usdcToDisplay,
   USDC_MINT,
@@ -70,7 +77,7 @@ export function useHopeRise() {
     return new Program<HopeRise>(idl as unknown as HopeRise, provider);
   }, [connection]);
 
-  // Initialize campaign counter (one-time admin operation)
+  // Initialize campaign counter (one-time operation)
   const initialize = useCallback(async () => {
     if (!publicKey) throw new Error('Wallet not connected');
     setLoading(true);
@@ -99,7 +106,39 @@ export function useHopeRise() {
     }
   }, [publicKey, getProgram]);
 
-  // Create a new campaign
+  // Create the platform config; only the program's upgrade authority may sign
+  const initializePlatformConfig = useCallback(async () => {
+    if (!publicKey) throw new Error('Wallet not connected');
+    setLoading(true);
+    setError(null);
+
+    try {
+      const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
+      const [programDataPda] = getProgramDataPDA();
+
+      const tx = await program.methods
+        .initializePlatformConfig()
+        .accountsPartial({
+          platformConfig: platformConfigPda,
+          program: program.programId,
+          programData: programDataPda,
+          authority: publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      return tx;
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : 'Failed to initialize platform config';
+      setError(message);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  }, [publicKey, getProgram]);
+
+  // Create a new campaign as a draft; launchCampaign opens it to contributions
   const createCampaign = useCallback(async (params: {
     title: string;
     shortDescription: string;
@@ -108,6 +147,11 @@ export function useHopeRise() {
     storyUrl: string;
     fundingGoalUsdc: number;
     durationDays: number;
+    voteQuorumBps?: number;
+    voteApprovalBps?: number;
+    allowEarlyClose?: boolean;
+    transferableReceipts?: boolean;
+    unpledgeCutoffHours?: number;
   }) => {
     if (!publicKey) throw new Error('Wallet not connected');
     setLoading(true);
@@ -116,6 +160,7 @@ export function useHopeRise() {
     try {
       const program = await getProgram();
       const [campaignCounterPda] = getCampaignCounterPDA();
+      const [platformConfigPda] = getPlatformConfigPDA();
 
       // Check if counter is initialized, if not initialize it first
       let counter;
@@ -137,6 +182,7 @@ export function useHopeRise() {
       }
 
       const [campaignPda] = getCampaignPDA(publicKey, counter.count);
+      const [campaignVaultPda] = getCampaignVaultPDA(campaignPda);
 
       const tx = await program.methods
         .createCampaign(
@@ -146,13 +192,24 @@ export function useHopeRise() {
           params.coverImageUrl,
           params.storyUrl,
           displayToUsdc(params.fundingGoalUsdc),
-          new BN(params.durationDays)
+          new BN(params.durationDays),
+          { token: {} },
+          params.voteQuorumBps ?? 0,
+          params.voteApprovalBps ?? 5000,
+          params.allowEarlyClose ?? false,
+          params.transferableReceipts ?? false,
+          params.unpledgeCutoffHours ?? 48
         )
         .accountsPartial({
           campaign: campaignPda,
           campaignCounter: campaignCounterPda,
+          platformConfig: platformConfigPda,
+          mint: USDC_MINT,
+          solVault: null,
           creator: publicKey,
           systemProgram: SystemProgram.programId,
+          campaignVault: campaignVaultPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
         })
         .rpc();
 
@@ -166,6 +223,35 @@ export function useHopeRise() {
       setLoading(false);
     }
   }, [publicKey, getProgram]);
+
+  // Launch a draft campaign, starting its funding period
+  const launchCampaign = useCallback(async (campaignPubkey: PublicKey) => {
+    if (!publicKey) throw new Error('Wallet not connected');
+    setLoading(true);
+    setError(null);
+
+    try {
+      const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
+
+      const tx = await program.methods
+        .launchCampaign()
+        .accountsPartial({
+          campaign: campaignPubkey,
+          platformConfig: platformConfigPda,
+          creator: publicKey,
+        })
+        .rpc();
+
+      return tx;
+    } catch (err: unknown) {
+      const message = err instanceof Error ? err.message : 'Failed to launch campaign';
+      setError(message);
+      throw err;
+    } finally {
+      setLoading(false);
+    }
+  }, [publicKey, getProgram]);
   
   // Fund a campaign with USDC
   const fundCampaign = useCallback(async (
@@ -178,8 +264,27 @@ export function useHopeRise() {
 
     try {
       const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
       const [contributionPda] = getContributionPDA(campaignPubkey, publicKey);
       const [campaignVaultPda] = getCampaignVaultPDA(campaignPubkey);
+      const [receiptMintPda] = getReceiptMintPDA(contributionPda);
+      const receiptTokenAccount = getAssociatedTokenAddressSync(
+        receiptMintPda,
+        publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // Locked stretch goals come along so the pledge can unlock them
+      const campaign = await program.account.campaign.fetch(campaignPubkey);
+      const lockedStretchGoals = [];
+      for (let i = campaign.unlockedStretchGoals; i < campaign.stretchGoalCount; i++) {
+        lockedStretchGoals.push({
+          pubkey: getStretchGoalPDA(campaignPubkey, i)[0],
+          isWritable: true,
+          isSigner: false,
+        });
+      }
 
       // Get contributor's USDC ATA
       const contributorTokenAccount = await getAssociatedTokenAddress(
@@ -214,17 +319,25 @@ export function useHopeRise() {
       }
 
       const tx = await program.methods
-        .fundCampaign(displayToUsdc(amountUsdc))
+        .fundCampaign(displayToUsdc(amountUsdc), null)
         .accountsPartial({
           campaign: campaignPubkey,
-          campaignVault: campaignVaultPda,
+          platformConfig: platformConfigPda,
           contribution: contributionPda,
+          campaignVault: campaignVaultPda,
+          solVault: null,
           contributor: publicKey,
           contributorTokenAccount: contributorTokenAccount,
-          usdcMint: USDC_MINT,
+          mint: USDC_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          rewardTier: null,
+          receiptMint: receiptMintPda,
+          receiptTokenAccount: receiptTokenAccount,
+          receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         })
+        .remainingAccounts(lockedStretchGoals)
         .rpc();
 
       return tx;
@@ -237,7 +350,7 @@ export function useHopeRise() {
     }
   }, [publicKey, signTransaction, connection, getProgram]);
 
-  // Withdraw USDC funds (creator only)
+  // Withdraw the USDC unlocked for the creator (creator only)
   const withdrawFunds = useCallback(async (campaignPubkey: PublicKey) => {
     if (!publicKey) throw new Error('Wallet not connected');
     setLoading(true);
@@ -245,6 +358,7 @@ export function useHopeRise() {
 
     try {
       const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
       const [campaignVaultPda] = getCampaignVaultPDA(campaignPubkey);
 
       // Get creator's USDC ATA
@@ -253,14 +367,26 @@ export function useHopeRise() {
         publicKey
       );
 
+      // The platform fee goes to the treasury's USDC ATA
+      const { treasury } = await program.account.platformConfig.fetch(platformConfigPda);
+      const treasuryTokenAccount = await getAssociatedTokenAddress(
+        USDC_MINT,
+        treasury,
+        true
+      );
+
       const tx = await program.methods
         .withdrawFunds()
         .accountsPartial({
           campaign: campaignPubkey,
           campaignVault: campaignVaultPda,
+          solVault: null,
           creator: publicKey,
           creatorTokenAccount: creatorTokenAccount,
-          usdcMint: USDC_MINT,
+          platformConfig: platformConfigPda,
+          treasury,
+          treasuryTokenAccount,
+          mint: USDC_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -277,12 +403,15 @@ export function useHopeRise() {
     }
   }, [publicKey, getProgram]);
 
-  // Add milestone to campaign
+  // Add milestone to a draft or active campaign, paying out payoutBps of the
+  // funds raised once backers approve it
   const addMilestone = useCallback(async (
     campaignPubkey: PublicKey,
     milestoneIndex: number,
     title: string,
-    targetAmountUsdc: number
+    targetAmountUsdc: number,
+    payoutBps: number = 0,
+    stretchGoal: number | null = null
   ) => {
     if (!publicKey) throw new Error('Wallet not connected');
     setLoading(true);
@@ -290,12 +419,14 @@ export function useHopeRise() {
 
     try {
       const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
       const [milestonePda] = getMilestonePDA(campaignPubkey, milestoneIndex);
 
       const tx = await program.methods
-        .addMilestone(title, displayToUsdc(targetAmountUsdc))
+        .addMilestone(title, displayToUsdc(targetAmountUsdc), payoutBps, stretchGoal)
         .accountsPartial({
           campaign: campaignPubkey,
+          platformConfig: platformConfigPda,
           milestone: milestonePda,
           creator: publicKey,
           systemProgram: SystemProgram.programId,
@@ -312,7 +443,7 @@ export function useHopeRise() {
     }
   }, [publicKey, getProgram]);
 
-  // Complete milestone
+  // Settle a milestone vote once voting has closed (anyone may call it)
   const completeMilestone = useCallback(async (
     campaignPubkey: PublicKey,
     milestonePubkey: PublicKey
@@ -323,13 +454,14 @@ export function useHopeRise() {
 
     try {
       const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
 
       const tx = await program.methods
         .completeMilestone()
         .accountsPartial({
           campaign: campaignPubkey,
+          platformConfig: platformConfigPda,
           milestone: milestonePubkey,
-          creator: publicKey,
         })
         .rpc();
 
@@ -343,7 +475,8 @@ export function useHopeRise() {
     }
   }, [publicKey, getProgram]);
 
-  // Close campaign
+  // Cancel a draft, or an active campaign that has not met its goal before its
+  // deadline
   const closeCampaign = useCallback(async (campaignPubkey: PublicKey) => {
     if (!publicKey) throw new Error('Wallet not connected');
     setLoading(true);
@@ -351,11 +484,13 @@ export function useHopeRise() {
 
     try {
       const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
 
       const tx = await program.methods
         .closeCampaign()
         .accountsPartial({
           campaign: campaignPubkey,
+          platformConfig: platformConfigPda,
           creator: publicKey,
         })
         .rpc();
@@ -370,7 +505,7 @@ export function useHopeRise() {
     }
   }, [publicKey, getProgram]);
 
-  // Claim USDC refund
+  // Claim USDC refund, burning the contribution's receipt
   const claimRefund = useCallback(async (campaignPubkey: PublicKey) => {
     if (!publicKey) throw new Error('Wallet not connected');
     setLoading(true);
@@ -378,8 +513,16 @@ export function useHopeRise() {
 
     try {
       const program = await getProgram();
+      const [platformConfigPda] = getPlatformConfigPDA();
       const [contributionPda] = getContributionPDA(campaignPubkey, publicKey);
       const [campaignVaultPda] = getCampaignVaultPDA(campaignPubkey);
+      const [receiptMintPda] = getReceiptMintPDA(contributionPda);
+      const receiptTokenAccount = getAssociatedTokenAddressSync(
+        receiptMintPda,
+        publicKey,
+        false,
+        TOKEN_2022_PROGRAM_ID
+      );
 
       // Get contributor's USDC ATA
       const contributorTokenAccount = await getAssociatedTokenAddress(
@@ -391,14 +534,19 @@ export function useHopeRise() {
         .claimRefund()
         .accountsPartial({
           campaign: campaignPubkey,
+          platformConfig: platformConfigPda,
           campaignVault: campaignVaultPda,
+          solVault: null,
           contribution: contributionPda,
           contributor: publicKey,
           contributorTokenAccount: contributorTokenAccount,
-          usdcMint: USDC_MINT,
+          mint: USDC_MINT,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
+          receiptMint: receiptMintPda,
+          receiptTokenAccount: receiptTokenAccount,
+          receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .rpc();
 
@@ -431,7 +579,7 @@ export function useHopeRise() {
         deadline: (acc.account.deadline as BN).toNumber(),
         amountRaised: (acc.account.amountRaised as BN).toNumber(),
         backerCount: (acc.account.backerCount as BN).toNumber(),
-        isActive: acc.account.isActive as boolean,
+        status: getStatusString(acc.account.status as Record<string, object>),
         createdAt: (acc.account.createdAt as BN).toNumber(),
         milestoneCount: acc.account.milestoneCount as number,
         bump: acc.account.bump as number,
@@ -461,7 +609,7 @@ export function useHopeRise() {
         deadline: (acc.deadline as BN).toNumber(),
         amountRaised: (acc.amountRaised as BN).toNumber(),
         backerCount: (acc.backerCount as BN).toNumber(),
-        isActive: acc.isActive as boolean,
+        status: getStatusString(acc.status as Record<string, object>),
         createdAt: (acc.createdAt as BN).toNumber(),
         milestoneCount: acc.milestoneCount as number,
         bump: acc.bump as number,
@@ -579,7 +727,9 @@ export function useHopeRise() {
 
     // Write operations
     initialize,
+    initializePlatformConfig,
     createCampaign,
+    launchCampaign,
     fundCampaign,
     withdrawFunds,
     addMilestone,
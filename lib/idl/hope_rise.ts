@@ -13,10 +13,114 @@ export type HopeRise = {
     "description": "Solana smart contract for Hope Rise crowdfunding platform"
   },
   "instructions": [
+    {
+      "name": "acceptAdmin",
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "newAdmin",
+          "signer": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addAcceptedMint",
+      "discriminator": [
+        241,
+        72,
+        208,
+        73,
+        223,
+        58,
+        81,
+        69
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "addMilestone",
       "docs": [
-        "Add a milestone to a campaign"
+        "Adds a milestone paying out `payout_bps` of the funds raised. A",
+        "milestone gated on `stretch_goal` only takes proof once that goal is",
+        "unlocked."
       ],
       "discriminator": [
         165,
@@ -60,6 +164,33 @@ export type HopeRise = {
             ]
           }
         },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "milestone",
           "writable": true
@@ -85,23 +216,34 @@ export type HopeRise = {
         {
           "name": "targetAmount",
           "type": "u64"
+        },
+        {
+          "name": "payoutBps",
+          "type": "u16"
+        },
+        {
+          "name": "stretchGoal",
+          "type": {
+            "option": "u8"
+          }
         }
       ]
     },
     {
-      "name": "claimRefund",
+      "name": "addRewardTier",
       "docs": [
-        "Claim USDC refund if campaign failed (goal not met)"
+        "Adds a reward tier that backers claim by pledging at least `min_pledge`,",
+        "until `max_supply` of them did"
       ],
       "discriminator": [
-        15,
-        16,
-        30,
-        161,
-        255,
-        228,
-        97,
-        60
+        47,
+        242,
+        34,
+        203,
+        113,
+        138,
+        46,
+        245
       ],
       "accounts": [
         {
@@ -136,41 +278,84 @@ export type HopeRise = {
           }
         },
         {
-          "name": "campaignVault",
-          "docs": [
-            "Campaign's USDC vault (PDA token account)"
-          ],
-          "writable": true,
+          "name": "platformConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  97,
-                  109,
                   112,
+                  108,
                   97,
-                  105,
-                  103,
-                  110,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
                   95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "campaign"
               }
             ]
           }
         },
         {
-          "name": "contribution",
+          "name": "rewardTier",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "minPledge",
+          "type": "u64"
+        },
+        {
+          "name": "maxSupply",
+          "type": "u32"
+        },
+        {
+          "name": "metadataUrl",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "addStretchGoal",
+      "docs": [
+        "Adds a stretch goal that unlocks once the amount raised reaches",
+        "`threshold`. Thresholds start above the funding goal and rise with",
+        "each goal, so goals unlock in the order they were added."
+      ],
+      "discriminator": [
+        241,
+        188,
+        214,
+        22,
+        184,
+        72,
+        23,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
           "writable": true,
           "pda": {
             "seeds": [
@@ -178,163 +363,101 @@ export type HopeRise = {
                 "kind": "const",
                 "value": [
                   99,
-                  111,
-                  110,
-                  116,
-                  114,
-                  105,
-                  98,
-                  117,
-                  116,
+                  97,
+                  109,
+                  112,
+                  97,
                   105,
-                  111,
+                  103,
                   110
                 ]
               },
               {
                 "kind": "account",
-                "path": "campaign"
+                "path": "campaign.creator",
+                "account": "campaign"
               },
               {
                 "kind": "account",
-                "path": "contributor"
+                "path": "campaign.campaign_id",
+                "account": "campaign"
               }
             ]
           }
         },
         {
-          "name": "contributor",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "contributorTokenAccount",
-          "docs": [
-            "Contributor's USDC token account (ATA)"
-          ],
-          "writable": true,
+          "name": "platformConfig",
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "contributor"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
                   95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "usdcMint"
               }
-            ],
-            "program": {
-              "kind": "const",
-              "value": [
-                140,
-                151,
-                37,
-                143,
-                78,
-                36,
-                137,
-                241,
-                187,
-                61,
-                16,
-                41,
-                20,
-                142,
-                13,
-                131,
-                11,
-                90,
-                19,
-                153,
-                218,
-                255,
-                16,
-                132,
-                4,
-                142,
-                123,
-                216,
-                219,
-                233,
-                248,
-                89
-              ]
-            }
+            ]
           }
         },
         {
-          "name": "usdcMint",
-          "docs": [
-            "USDC mint"
-          ]
+          "name": "stretchGoal",
+          "writable": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "previousStretchGoal",
+          "docs": [
+            "Last stretch goal added, required once the campaign has one"
+          ],
+          "optional": true
         },
         {
-          "name": "associatedTokenProgram",
-          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u64"
+        },
+        {
+          "name": "metadataUrl",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "closeCampaign",
-      "docs": [
-        "Close a campaign"
-      ],
+      "name": "claimPartialRefund",
       "discriminator": [
-        65,
-        49,
-        110,
-        7,
-        63,
-        238,
-        206,
-        77
+        177,
+        125,
+        31,
+        191,
+        223,
+        141,
+        237,
+        16
       ],
       "accounts": [
         {
@@ -369,95 +492,36 @@ export type HopeRise = {
           }
         },
         {
-          "name": "creator",
-          "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "completeMilestone",
-      "docs": [
-        "Mark a milestone as complete"
-      ],
-      "discriminator": [
-        137,
-        164,
-        160,
-        100,
-        33,
-        64,
-        178,
-        10
-      ],
-      "accounts": [
-        {
-          "name": "campaign",
+          "name": "platformConfig",
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  97,
-                  109,
                   112,
+                  108,
                   97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
                   105,
-                  103,
-                  110
+                  103
                 ]
-              },
-              {
-                "kind": "account",
-                "path": "campaign.creator",
-                "account": "campaign"
-              },
-              {
-                "kind": "account",
-                "path": "campaign.campaign_id",
-                "account": "campaign"
               }
             ]
           }
         },
         {
-          "name": "milestone",
-          "writable": true
-        },
-        {
-          "name": "creator",
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "createCampaign",
-      "docs": [
-        "Create a new crowdfunding campaign"
-      ],
-      "discriminator": [
-        111,
-        131,
-        187,
-        98,
-        160,
-        193,
-        114,
-        244
-      ],
-      "accounts": [
-        {
-          "name": "campaign",
+          "name": "campaignVault",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -470,113 +534,51 @@ export type HopeRise = {
                   97,
                   105,
                   103,
-                  110
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
                 ]
               },
               {
                 "kind": "account",
-                "path": "creator"
-              },
-              {
-                "kind": "account",
-                "path": "campaign_counter.count",
-                "account": "campaignCounter"
+                "path": "campaign"
               }
             ]
           }
         },
         {
-          "name": "campaignCounter",
+          "name": "solVault",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
-                  99,
-                  97,
-                  109,
-                  112,
-                  97,
-                  105,
-                  103,
-                  110,
-                  95,
-                  99,
+                  115,
                   111,
+                  108,
+                  95,
+                  118,
+                  97,
                   117,
-                  110,
-                  116,
-                  101,
-                  114
+                  108,
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
               }
             ]
           }
         },
         {
-          "name": "creator",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "shortDescription",
-          "type": "string"
-        },
-        {
-          "name": "category",
-          "type": {
-            "defined": {
-              "name": "category"
-            }
-          }
-        },
-        {
-          "name": "coverImageUrl",
-          "type": "string"
-        },
-        {
-          "name": "storyUrl",
-          "type": "string"
-        },
-        {
-          "name": "fundingGoal",
-          "type": "u64"
-        },
-        {
-          "name": "durationDays",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "fundCampaign",
-      "docs": [
-        "Contribute USDC to a campaign"
-      ],
-      "discriminator": [
-        109,
-        57,
-        56,
-        239,
-        99,
-        111,
-        221,
-        121
-      ],
-      "accounts": [
-        {
-          "name": "campaign",
+          "name": "contribution",
           "writable": true,
           "pda": {
             "seeds": [
@@ -584,200 +586,168 @@ export type HopeRise = {
                 "kind": "const",
                 "value": [
                   99,
-                  97,
-                  109,
-                  112,
-                  97,
+                  111,
+                  110,
+                  116,
+                  114,
                   105,
-                  103,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
                   110
                 ]
               },
               {
                 "kind": "account",
-                "path": "campaign.creator",
-                "account": "campaign"
+                "path": "campaign"
               },
               {
                 "kind": "account",
-                "path": "campaign.campaign_id",
-                "account": "campaign"
+                "path": "contributor"
               }
             ]
           }
         },
         {
-          "name": "campaignVault",
-          "docs": [
-            "Campaign's USDC vault (PDA token account)"
-          ],
+          "name": "contributor",
           "writable": true,
-          "pda": {
-            "seeds": [
-              {
-                "kind": "const",
-                "value": [
-                  99,
-                  97,
-                  109,
-                  112,
-                  97,
-                  105,
-                  103,
-                  110,
-                  95,
-                  118,
-                  97,
-                  117,
-                  108,
-                  116
-                ]
-              },
-              {
-                "kind": "account",
-                "path": "campaign"
-              }
-            ]
-          }
+          "signer": true
         },
         {
-          "name": "contribution",
+          "name": "contributorTokenAccount",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
-                "kind": "const",
-                "value": [
-                  99,
-                  111,
-                  110,
-                  116,
-                  114,
-                  105,
-                  98,
-                  117,
-                  116,
-                  105,
-                  111,
-                  110
-                ]
+                "kind": "account",
+                "path": "contributor"
               },
               {
                 "kind": "account",
-                "path": "campaign"
+                "path": "tokenProgram"
               },
               {
                 "kind": "account",
-                "path": "contributor"
+                "path": "mint"
               }
-            ]
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
           }
         },
         {
-          "name": "contributor",
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "contributorTokenAccount",
-          "docs": [
-            "Contributor's USDC token account (ATA)"
-          ],
-          "writable": true
+          "name": "mint",
+          "optional": true
         },
         {
-          "name": "usdcMint",
-          "docs": [
-            "USDC mint"
-          ]
+          "name": "tokenProgram",
+          "optional": true
         },
         {
-          "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
-        }
-      ],
-      "args": [
-        {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initialize",
-      "docs": [
-        "Initialize the global campaign counter (one-time setup)"
-      ],
-      "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
-      ],
-      "accounts": [
+        },
         {
-          "name": "campaignCounter",
+          "name": "receiptMint",
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
                 "kind": "const",
                 "value": [
+                  114,
+                  101,
                   99,
-                  97,
-                  109,
-                  112,
-                  97,
+                  101,
                   105,
-                  103,
-                  110,
+                  112,
+                  116,
                   95,
-                  99,
-                  111,
-                  117,
+                  109,
+                  105,
                   110,
-                  116,
-                  101,
-                  114
+                  116
                 ]
+              },
+              {
+                "kind": "account",
+                "path": "contribution"
               }
             ]
           }
         },
         {
-          "name": "authority",
+          "name": "receiptTokenAccount",
+          "docs": [
+            "to. Read only when the contribution still has a receipt, so a claim",
+            "repeated after the burn fails on the claim itself"
+          ],
           "writable": true,
-          "signer": true
+          "optional": true
         },
         {
-          "name": "systemProgram",
-          "address": "11111111111111111111111111111111"
+          "name": "receiptTokenProgram",
+          "optional": true,
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         }
       ],
       "args": []
     },
     {
-      "name": "withdrawFunds",
-      "docs": [
-        "Withdraw USDC funds (creator only, after goal met)"
-      ],
+      "name": "claimRefund",
       "discriminator": [
-        241,
-        36,
-        29,
-        111,
-        208,
-        31,
-        104,
-        217
+        15,
+        16,
+        30,
+        161,
+        255,
+        228,
+        97,
+        60
       ],
       "accounts": [
         {
@@ -811,12 +781,37 @@ export type HopeRise = {
             ]
           }
         },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
         {
           "name": "campaignVault",
-          "docs": [
-            "Campaign's USDC vault (PDA token account)"
-          ],
           "writable": true,
+          "optional": true,
           "pda": {
             "seeds": [
               {
@@ -846,68 +841,90 @@ export type HopeRise = {
           }
         },
         {
-          "name": "creator",
+          "name": "solVault",
           "writable": true,
-          "signer": true,
-          "relations": [
-            "campaign"
-          ]
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
         },
         {
-          "name": "creatorTokenAccount",
-          "docs": [
-            "Creator's USDC token account (ATA)"
-          ],
+          "name": "contribution",
           "writable": true,
           "pda": {
             "seeds": [
-              {
-                "kind": "account",
-                "path": "creator"
-              },
               {
                 "kind": "const",
                 "value": [
-                  6,
-                  221,
-                  246,
-                  225,
-                  215,
-                  101,
-                  161,
-                  147,
-                  217,
-                  203,
-                  225,
-                  70,
-                  206,
-                  235,
-                  121,
-                  172,
-                  28,
-                  180,
-                  133,
-                  237,
-                  95,
-                  91,
-                  55,
-                  145,
-                  58,
-                  140,
-                  245,
-                  133,
-                  126,
-                  255,
-                  0,
-                  169
+                  99,
+                  111,
+                  110,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
                 ]
               },
               {
                 "kind": "account",
-                "path": "usdcMint"
-              }
-            ],
-            "program": {
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "contributor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contributor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "contributorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "contributor"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
               "kind": "const",
               "value": [
                 140,
@@ -947,345 +964,6083 @@ export type HopeRise = {
           }
         },
         {
-          "name": "usdcMint",
-          "docs": [
-            "USDC mint"
-          ]
+          "name": "mint",
+          "optional": true
         },
         {
           "name": "tokenProgram",
-          "address": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+          "optional": true
         },
         {
           "name": "associatedTokenProgram",
+          "optional": true,
           "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
         },
         {
           "name": "systemProgram",
           "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "receiptMint",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contribution"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptTokenAccount",
+          "docs": [
+            "to. Read only when the contribution still has a receipt, so a claim",
+            "repeated after the burn fails on the claim itself"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptTokenProgram",
+          "optional": true,
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
         }
       ],
       "args": []
-    }
-  ],
-  "accounts": [
-    {
-      "name": "campaign",
-      "discriminator": [
-        50,
-        40,
-        49,
-        11,
-        157,
-        220,
-        229,
-        192
-      ]
-    },
-    {
-      "name": "campaignCounter",
-      "discriminator": [
-        166,
-        204,
-        173,
-        116,
-        178,
-        217,
-        1,
-        210
-      ]
     },
     {
-      "name": "contribution",
+      "name": "closeCampaign",
       "discriminator": [
-        182,
-        187,
-        14,
-        111,
-        72,
-        167,
-        242,
-        212
-      ]
+        65,
+        49,
+        110,
+        7,
+        63,
+        238,
+        206,
+        77
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "name": "milestone",
+      "name": "closeCampaignAccounts",
+      "docs": [
+        "Closes a settled campaign and its vault once every contribution,",
+        "milestone, reward tier and stretch goal account is gone. Rounding dust",
+        "left in the vault goes to the creator together with the rent."
+      ],
       "discriminator": [
-        38,
-        210,
-        239,
-        177,
-        85,
-        184,
-        10,
-        44
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "unauthorized",
-      "msg": "You are not authorized to perform this action"
-    },
-    {
-      "code": 6001,
-      "name": "campaignNotActive",
-      "msg": "Campaign is not active"
-    },
-    {
-      "code": 6002,
-      "name": "campaignStillActive",
-      "msg": "Campaign is still active"
-    },
-    {
-      "code": 6003,
-      "name": "campaignEnded",
-      "msg": "Campaign has ended"
-    },
-    {
-      "code": 6004,
-      "name": "campaignNotEnded",
-      "msg": "Campaign has not ended yet"
-    },
-    {
-      "code": 6005,
-      "name": "goalWasMet",
-      "msg": "Funding goal was already met"
-    },
-    {
-      "code": 6006,
-      "name": "goalNotMet",
-      "msg": "Funding goal was not met"
-    },
-    {
-      "code": 6007,
-      "name": "withdrawalNotAllowed",
-      "msg": "Withdrawal is not allowed at this time"
-    },
-    {
-      "code": 6008,
-      "name": "refundAlreadyClaimed",
-      "msg": "Refund has already been claimed"
-    },
-    {
-      "code": 6009,
-      "name": "noContribution",
-      "msg": "No contribution found to refund"
-    },
-    {
-      "code": 6010,
-      "name": "milestoneAlreadyCompleted",
-      "msg": "Milestone has already been completed"
-    },
-    {
-      "code": 6011,
-      "name": "milestoneTargetNotReached",
-      "msg": "Milestone target amount has not been reached"
-    },
-    {
+        9,
+        81,
+        152,
+        63,
+        246,
+        134,
+        65,
+        244
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeContribution",
+      "docs": [
+        "Closes a contribution nobody needs anymore, returning its rent to the",
+        "contributor. Anyone may call it so creators can clear their campaign."
+      ],
+      "discriminator": [
+        212,
+        162,
+        137,
+        29,
+        10,
+        95,
+        186,
+        129
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "contribution",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "contributor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contributor",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMilestone",
+      "docs": [
+        "Closes a milestone of a settled campaign, returning its rent to the creator"
+      ],
+      "discriminator": [
+        64,
+        73,
+        247,
+        200,
+        45,
+        76,
+        197,
+        241
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeMilestoneVote",
+      "docs": [
+        "Closes a vote on a milestone that was released or whose campaign is",
+        "settled, returning its rent to the voter. Anyone may call it."
+      ],
+      "discriminator": [
+        110,
+        216,
+        164,
+        233,
+        212,
+        65,
+        248,
+        20
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "docs": [
+            "decoded by the instruction otherwise. Only the program writes to its",
+            "PDA, so the data there is a milestone."
+          ]
+        },
+        {
+          "name": "milestoneVote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  108,
+                  101,
+                  115,
+                  116,
+                  111,
+                  110,
+                  101,
+                  95,
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "milestone"
+              },
+              {
+                "kind": "account",
+                "path": "voter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "voter",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneIndex",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeRewardTier",
+      "docs": [
+        "Closes a reward tier of a settled campaign, returning its rent to the creator"
+      ],
+      "discriminator": [
+        228,
+        15,
+        28,
+        215,
+        31,
+        198,
+        222,
+        87
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "rewardTier",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeStretchGoal",
+      "docs": [
+        "Closes a stretch goal of a settled campaign, returning its rent to the creator"
+      ],
+      "discriminator": [
+        110,
+        51,
+        67,
+        116,
+        248,
+        61,
+        100,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "stretchGoal",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "completeMilestone",
+      "discriminator": [
+        137,
+        164,
+        160,
+        100,
+        33,
+        64,
+        178,
+        10
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createCampaign",
+      "discriminator": [
+        111,
+        131,
+        187,
+        98,
+        160,
+        193,
+        114,
+        244
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "campaign_counter.count",
+                "account": "campaignCounter"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "solVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "campaignVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "shortDescription",
+          "type": "string"
+        },
+        {
+          "name": "category",
+          "type": {
+            "defined": {
+              "name": "category"
+            }
+          }
+        },
+        {
+          "name": "coverImageUrl",
+          "type": "string"
+        },
+        {
+          "name": "storyUrl",
+          "type": "string"
+        },
+        {
+          "name": "fundingGoal",
+          "type": "u64"
+        },
+        {
+          "name": "durationDays",
+          "type": "u64"
+        },
+        {
+          "name": "asset",
+          "type": {
+            "defined": {
+              "name": "fundingAsset"
+            }
+          }
+        },
+        {
+          "name": "voteQuorumBps",
+          "type": "u16"
+        },
+        {
+          "name": "voteApprovalBps",
+          "type": "u16"
+        },
+        {
+          "name": "allowEarlyClose",
+          "type": "bool"
+        },
+        {
+          "name": "transferableReceipts",
+          "type": "bool"
+        },
+        {
+          "name": "unpledgeCutoffHours",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "finalizeCampaign",
+      "docs": [
+        "Ends the funding period. A successful campaign with stretch goals",
+        "still locked takes all of its milestones after the named accounts,",
+        "writable and in index order, followed by its locked stretch goals.",
+        "Goals the amount raised crossed without being unlocked unlock first.",
+        "Milestones gated on a goal still locked can never take proof, so",
+        "their shares move to the milestones that can, keeping them under a",
+        "backer vote."
+      ],
+      "discriminator": [
+        241,
+        76,
+        201,
+        221,
+        33,
+        222,
+        220,
+        138
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "forceFailCampaign",
+      "discriminator": [
+        94,
+        180,
+        90,
+        27,
+        248,
+        171,
+        138,
+        158
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "fundCampaign",
+      "docs": [
+        "Contributes `amount` to an active campaign, optionally claiming the",
+        "reward tier at `reward_tier` once the contributor's total meets its",
+        "minimum pledge. A contributor claims at most one tier. The first",
+        "contribution mints the contributor a receipt token.",
+        "",
+        "The campaign's locked stretch goals may follow the named accounts in",
+        "index order, starting with the next one; each goal passed that the",
+        "amount raised now crosses is unlocked, and goals left out wait for a",
+        "later pledge or finalization. Unlocked goals stay unlocked if pledges",
+        "are withdrawn later."
+      ],
+      "discriminator": [
+        109,
+        57,
+        56,
+        239,
+        99,
+        111,
+        221,
+        121
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "contribution",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "contributor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contributor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "contributorTokenAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rewardTier",
+          "docs": [
+            "Reward tier claimed with this contribution, if any"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptMint",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contribution"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptTokenAccount",
+          "docs": [
+            "by the associated token program"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptTokenProgram",
+          "optional": true,
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "rewardTier",
+          "type": {
+            "option": "u8"
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize",
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "campaignCounter",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  99,
+                  111,
+                  117,
+                  110,
+                  116,
+                  101,
+                  114
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializePlatformConfig",
+      "docs": [
+        "Creates the platform config with the program's upgrade authority as",
+        "admin and treasury. Separate from `initialize`, so deployments whose",
+        "campaign counter predates the config can add it."
+      ],
+      "discriminator": [
+        23,
+        52,
+        237,
+        53,
+        176,
+        235,
+        3,
+        187
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "program",
+          "address": "BAaDjLVffrtNzgKLoUjmM9t1tWBHxMF6UFdnL1NYmQ3J"
+        },
+        {
+          "name": "programData"
+        },
+        {
+          "name": "authority",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "launchCampaign",
+      "discriminator": [
+        249,
+        22,
+        139,
+        203,
+        65,
+        20,
+        143,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateAccount",
+      "docs": [
+        "Upgrades a campaign, milestone, contribution, reward tier or stretch",
+        "goal account written by an older program version to the current layout. Anyone may",
+        "migrate any account, paying for the space the layout adds. Allowed",
+        "while the program is paused, so accounts can be upgraded before it",
+        "resumes."
+      ],
+      "discriminator": [
+        177,
+        228,
+        60,
+        125,
+        13,
+        116,
+        44,
+        84
+      ],
+      "accounts": [
+        {
+          "name": "account",
+          "docs": [
+            "contribution, reward tier and stretch goal accounts"
+          ],
+          "writable": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "Pays the rent of any space the current layout adds"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "proposeAdmin",
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "releaseMilestone",
+      "discriminator": [
+        56,
+        2,
+        199,
+        164,
+        184,
+        108,
+        167,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "campaignVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeAcceptedMint",
+      "discriminator": [
+        71,
+        204,
+        181,
+        197,
+        187,
+        85,
+        94,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "setPlatformFee",
+      "discriminator": [
+        19,
+        70,
+        111,
+        182,
+        156,
+        58,
+        208,
+        203
+      ],
+      "accounts": [
+        {
+          "name": "platformConfig",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "feeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "submitMilestoneProof",
+      "discriminator": [
+        95,
+        246,
+        144,
+        35,
+        212,
+        197,
+        162,
+        197
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "creator",
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "proofUrl",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "suspendCampaign",
+      "discriminator": [
+        199,
+        6,
+        71,
+        72,
+        64,
+        193,
+        251,
+        248
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "unpledge",
+      "docs": [
+        "Withdraws `amount` of the contributor's pledge from an active campaign",
+        "until `unpledge_cutoff` before the deadline. Dropping below the",
+        "claimed reward tier's minimum gives the reward back, and withdrawing",
+        "everything burns the receipt and removes the contributor from the",
+        "backers."
+      ],
+      "discriminator": [
+        11,
+        52,
+        88,
+        109,
+        20,
+        88,
+        239,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contribution",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "contributor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contributor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "contributorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "contributor"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        },
+        {
+          "name": "rewardTier",
+          "docs": [
+            "Reward tier claimed by the contributor, required while they hold one"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptMint",
+          "docs": [
+            "handler once the whole pledge is withdrawn"
+          ],
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  114,
+                  101,
+                  99,
+                  101,
+                  105,
+                  112,
+                  116,
+                  95,
+                  109,
+                  105,
+                  110,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "contribution"
+              }
+            ]
+          }
+        },
+        {
+          "name": "receiptTokenAccount",
+          "docs": [
+            "to. Read only when the whole pledge is withdrawn"
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiptTokenProgram",
+          "optional": true,
+          "address": "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "unsuspendCampaign",
+      "discriminator": [
+        144,
+        47,
+        228,
+        133,
+        138,
+        107,
+        212,
+        204
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "admin",
+          "signer": true,
+          "relations": [
+            "platformConfig"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reason",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "updateCampaignMetadata",
+      "docs": [
+        "Replaces the campaign's metadata, resizing the account to fit it"
+      ],
+      "discriminator": [
+        173,
+        142,
+        5,
+        158,
+        174,
+        229,
+        109,
+        207
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "shortDescription",
+          "type": "string"
+        },
+        {
+          "name": "coverImageUrl",
+          "type": "string"
+        },
+        {
+          "name": "storyUrl",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "voteMilestone",
+      "discriminator": [
+        43,
+        27,
+        71,
+        239,
+        231,
+        20,
+        102,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestone",
+          "writable": true
+        },
+        {
+          "name": "contribution",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  111,
+                  110,
+                  116,
+                  114,
+                  105,
+                  98,
+                  117,
+                  116,
+                  105,
+                  111,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "contributor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "milestoneVote",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  109,
+                  105,
+                  108,
+                  101,
+                  115,
+                  116,
+                  111,
+                  110,
+                  101,
+                  95,
+                  118,
+                  111,
+                  116,
+                  101
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "milestone"
+              },
+              {
+                "kind": "account",
+                "path": "contributor"
+              }
+            ]
+          }
+        },
+        {
+          "name": "contributor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": [
+        {
+          "name": "approve",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "withdrawFunds",
+      "discriminator": [
+        241,
+        36,
+        29,
+        111,
+        208,
+        31,
+        104,
+        217
+      ],
+      "accounts": [
+        {
+          "name": "campaign",
+          "writable": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign.creator",
+                "account": "campaign"
+              },
+              {
+                "kind": "account",
+                "path": "campaign.campaign_id",
+                "account": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "campaignVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  99,
+                  97,
+                  109,
+                  112,
+                  97,
+                  105,
+                  103,
+                  110,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "solVault",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  115,
+                  111,
+                  108,
+                  95,
+                  118,
+                  97,
+                  117,
+                  108,
+                  116
+                ]
+              },
+              {
+                "kind": "account",
+                "path": "campaign"
+              }
+            ]
+          }
+        },
+        {
+          "name": "creator",
+          "writable": true,
+          "signer": true,
+          "relations": [
+            "campaign"
+          ]
+        },
+        {
+          "name": "creatorTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "creator"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "platformConfig",
+          "pda": {
+            "seeds": [
+              {
+                "kind": "const",
+                "value": [
+                  112,
+                  108,
+                  97,
+                  116,
+                  102,
+                  111,
+                  114,
+                  109,
+                  95,
+                  99,
+                  111,
+                  110,
+                  102,
+                  105,
+                  103
+                ]
+              }
+            ]
+          }
+        },
+        {
+          "name": "treasury",
+          "writable": true
+        },
+        {
+          "name": "treasuryTokenAccount",
+          "writable": true,
+          "optional": true,
+          "pda": {
+            "seeds": [
+              {
+                "kind": "account",
+                "path": "treasury"
+              },
+              {
+                "kind": "account",
+                "path": "tokenProgram"
+              },
+              {
+                "kind": "account",
+                "path": "mint"
+              }
+            ],
+            "program": {
+              "kind": "const",
+              "value": [
+                140,
+                151,
+                37,
+                143,
+                78,
+                36,
+                137,
+                241,
+                187,
+                61,
+                16,
+                41,
+                20,
+                142,
+                13,
+                131,
+                11,
+                90,
+                19,
+                153,
+                218,
+                255,
+                16,
+                132,
+                4,
+                142,
+                123,
+                216,
+                219,
+                233,
+                248,
+                89
+              ]
+            }
+          }
+        },
+        {
+          "name": "mint",
+          "optional": true
+        },
+        {
+          "name": "tokenProgram",
+          "optional": true
+        },
+        {
+          "name": "associatedTokenProgram",
+          "optional": true,
+          "address": "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+        },
+        {
+          "name": "systemProgram",
+          "address": "11111111111111111111111111111111"
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "campaign",
+      "discriminator": [
+        50,
+        40,
+        49,
+        11,
+        157,
+        220,
+        229,
+        192
+      ]
+    },
+    {
+      "name": "campaignCounter",
+      "discriminator": [
+        166,
+        204,
+        173,
+        116,
+        178,
+        217,
+        1,
+        210
+      ]
+    },
+    {
+      "name": "contribution",
+      "discriminator": [
+        182,
+        187,
+        14,
+        111,
+        72,
+        167,
+        242,
+        212
+      ]
+    },
+    {
+      "name": "milestone",
+      "discriminator": [
+        38,
+        210,
+        239,
+        177,
+        85,
+        184,
+        10,
+        44
+      ]
+    },
+    {
+      "name": "milestoneVote",
+      "discriminator": [
+        5,
+        129,
+        119,
+        11,
+        182,
+        73,
+        115,
+        227
+      ]
+    },
+    {
+      "name": "platformConfig",
+      "discriminator": [
+        160,
+        78,
+        128,
+        0,
+        248,
+        83,
+        230,
+        160
+      ]
+    },
+    {
+      "name": "rewardTier",
+      "discriminator": [
+        60,
+        133,
+        134,
+        225,
+        105,
+        145,
+        87,
+        110
+      ]
+    },
+    {
+      "name": "stretchGoal",
+      "discriminator": [
+        23,
+        227,
+        210,
+        28,
+        74,
+        45,
+        45,
+        172
+      ]
+    }
+  ],
+  "events": [
+    {
+      "discriminator": [
+        158,
+        143,
+        128,
+        251,
+        84,
+        131,
+        2,
+        90
+      ],
+      "name": "campaignClosed"
+    },
+    {
+      "discriminator": [
+        114,
+        100,
+        117,
+        191,
+        22,
+        109,
+        23,
+        132
+      ],
+      "name": "campaignCompleted"
+    },
+    {
+      "discriminator": [
+        9,
+        98,
+        69,
+        61,
+        53,
+        131,
+        64,
+        152
+      ],
+      "name": "campaignCreated"
+    },
+    {
+      "discriminator": [
+        50,
+        192,
+        118,
+        12,
+        197,
+        171,
+        198,
+        177
+      ],
+      "name": "campaignFailed"
+    },
+    {
+      "discriminator": [
+        219,
+        169,
+        142,
+        66,
+        105,
+        67,
+        124,
+        255
+      ],
+      "name": "campaignFinalized"
+    },
+    {
+      "discriminator": [
+        163,
+        108,
+        147,
+        241,
+        97,
+        134,
+        169,
+        120
+      ],
+      "name": "campaignLaunched"
+    },
+    {
+      "discriminator": [
+        38,
+        121,
+        114,
+        51,
+        171,
+        167,
+        106,
+        197
+      ],
+      "name": "campaignMetadataUpdated"
+    },
+    {
+      "discriminator": [
+        18,
+        180,
+        250,
+        81,
+        186,
+        67,
+        50,
+        218
+      ],
+      "name": "campaignModerated"
+    },
+    {
+      "discriminator": [
+        81,
+        218,
+        72,
+        109,
+        93,
+        96,
+        131,
+        199
+      ],
+      "name": "contributionMade"
+    },
+    {
+      "discriminator": [
+        56,
+        130,
+        230,
+        154,
+        35,
+        92,
+        11,
+        118
+      ],
+      "name": "fundsWithdrawn"
+    },
+    {
+      "discriminator": [
+        25,
+        65,
+        182,
+        178,
+        253,
+        180,
+        118,
+        77
+      ],
+      "name": "milestoneAdded"
+    },
+    {
+      "discriminator": [
+        44,
+        25,
+        3,
+        4,
+        74,
+        141,
+        142,
+        66
+      ],
+      "name": "milestoneCompleted"
+    },
+    {
+      "discriminator": [
+        48,
+        7,
+        52,
+        45,
+        206,
+        51,
+        239,
+        173
+      ],
+      "name": "milestonePayoutReassigned"
+    },
+    {
+      "discriminator": [
+        207,
+        247,
+        103,
+        220,
+        160,
+        162,
+        65,
+        252
+      ],
+      "name": "milestoneProofSubmitted"
+    },
+    {
+      "discriminator": [
+        64,
+        220,
+        77,
+        108,
+        50,
+        207,
+        250,
+        201
+      ],
+      "name": "milestoneQuorumMissed"
+    },
+    {
+      "discriminator": [
+        49,
+        225,
+        91,
+        223,
+        34,
+        165,
+        109,
+        181
+      ],
+      "name": "milestoneReleased"
+    },
+    {
+      "discriminator": [
+        136,
+        57,
+        5,
+        53,
+        167,
+        246,
+        6,
+        176
+      ],
+      "name": "milestoneVoted"
+    },
+    {
+      "discriminator": [
+        52,
+        243,
+        93,
+        163,
+        236,
+        65,
+        178,
+        122
+      ],
+      "name": "pledgeWithdrawn"
+    },
+    {
+      "discriminator": [
+        136,
+        64,
+        242,
+        99,
+        4,
+        244,
+        208,
+        130
+      ],
+      "name": "refundClaimed"
+    },
+    {
+      "discriminator": [
+        16,
+        190,
+        138,
+        123,
+        66,
+        219,
+        187,
+        22
+      ],
+      "name": "rewardTierAdded"
+    },
+    {
+      "discriminator": [
+        87,
+        128,
+        246,
+        139,
+        40,
+        93,
+        5,
+        42
+      ],
+      "name": "stretchGoalAdded"
+    },
+    {
+      "discriminator": [
+        156,
+        187,
+        150,
+        206,
+        20,
+        247,
+        147,
+        164
+      ],
+      "name": "stretchGoalUnlocked"
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "You are not authorized to perform this action"
+    },
+    {
+      "code": 6001,
+      "name": "campaignNotActive",
+      "msg": "Campaign is not active"
+    },
+    {
+      "code": 6002,
+      "name": "campaignStillActive",
+      "msg": "Campaign is still active"
+    },
+    {
+      "code": 6003,
+      "name": "campaignEnded",
+      "msg": "Campaign has ended"
+    },
+    {
+      "code": 6004,
+      "name": "campaignNotEnded",
+      "msg": "Campaign has not ended yet"
+    },
+    {
+      "code": 6005,
+      "name": "goalWasMet",
+      "msg": "Funding goal was already met"
+    },
+    {
+      "code": 6006,
+      "name": "goalNotMet",
+      "msg": "Funding goal was not met"
+    },
+    {
+      "code": 6007,
+      "name": "withdrawalNotAllowed",
+      "msg": "Withdrawal is not allowed at this time"
+    },
+    {
+      "code": 6008,
+      "name": "refundAlreadyClaimed",
+      "msg": "Refund has already been claimed"
+    },
+    {
+      "code": 6009,
+      "name": "noContribution",
+      "msg": "No contribution found to refund"
+    },
+    {
+      "code": 6010,
+      "name": "milestoneAlreadyCompleted",
+      "msg": "Milestone has already been completed"
+    },
+    {
+      "code": 6011,
+      "name": "milestoneTargetNotReached",
+      "msg": "Milestone target amount has not been reached"
+    },
+    {
       "code": 6012,
       "name": "maxMilestonesReached",
       "msg": "Maximum number of milestones (10) reached"
     },
     {
-      "code": 6013,
-      "name": "titleTooLong",
-      "msg": "Title exceeds maximum length of 80 characters"
+      "code": 6013,
+      "name": "titleTooLong",
+      "msg": "Title exceeds maximum length of 80 characters"
+    },
+    {
+      "code": 6014,
+      "name": "descriptionTooLong",
+      "msg": "Description exceeds maximum length of 200 characters"
+    },
+    {
+      "code": 6015,
+      "name": "urlTooLong",
+      "msg": "URL exceeds maximum length of 200 characters"
+    },
+    {
+      "code": 6016,
+      "name": "milestoneTitleTooLong",
+      "msg": "Milestone title exceeds maximum length of 100 characters"
+    },
+    {
+      "code": 6017,
+      "name": "invalidFundingGoal",
+      "msg": "Funding goal must be greater than zero"
+    },
+    {
+      "code": 6018,
+      "name": "invalidContributionAmount",
+      "msg": "Contribution amount must be greater than zero"
+    },
+    {
+      "code": 6019,
+      "name": "invalidDuration",
+      "msg": "Campaign duration must be between 1 and 90 days"
+    },
+    {
+      "code": 6020,
+      "name": "arithmeticOverflow",
+      "msg": "Arithmetic overflow occurred"
+    },
+    {
+      "code": 6021,
+      "name": "insufficientFunds",
+      "msg": "Insufficient funds in campaign account"
+    },
+    {
+      "code": 6022,
+      "name": "invalidMint",
+      "msg": "Invalid token mint address"
+    },
+    {
+      "code": 6023,
+      "name": "invalidTokenAccount",
+      "msg": "Invalid token account"
+    },
+    {
+      "code": 6024,
+      "name": "milestoneNotCompleted",
+      "msg": "Milestone has not been completed"
+    },
+    {
+      "code": 6025,
+      "name": "milestoneAlreadyReleased",
+      "msg": "Milestone funds have already been released"
+    },
+    {
+      "code": 6026,
+      "name": "invalidPayoutShare",
+      "msg": "Milestone payout shares cannot exceed 100% of funds raised"
+    },
+    {
+      "code": 6027,
+      "name": "fundsAlreadyReleased",
+      "msg": "Milestones cannot be added after funds have been released"
+    },
+    {
+      "code": 6028,
+      "name": "invalidVoteThreshold",
+      "msg": "Vote quorum must be at most 100% and approval between 50% and 100%"
+    },
+    {
+      "code": 6029,
+      "name": "milestoneProofAlreadySubmitted",
+      "msg": "Milestone proof has already been submitted"
+    },
+    {
+      "code": 6030,
+      "name": "votingNotOpen",
+      "msg": "Milestone voting has not started"
+    },
+    {
+      "code": 6031,
+      "name": "votingClosed",
+      "msg": "Milestone voting period has ended"
+    },
+    {
+      "code": 6032,
+      "name": "votingStillOpen",
+      "msg": "Milestone voting period has not ended yet"
+    },
+    {
+      "code": 6033,
+      "name": "milestoneVoteFailed",
+      "msg": "Milestone was not approved by backers"
+    },
+    {
+      "code": 6034,
+      "name": "campaignNotFailed",
+      "msg": "Campaign has not failed at a milestone"
+    },
+    {
+      "code": 6035,
+      "name": "mintAlreadyAccepted",
+      "msg": "Token mint is already accepted"
+    },
+    {
+      "code": 6036,
+      "name": "maxAcceptedMintsReached",
+      "msg": "Maximum number of accepted mints reached"
+    },
+    {
+      "code": 6037,
+      "name": "missingVaultAccount",
+      "msg": "Vault accounts for the campaign's funding asset were not provided"
+    },
+    {
+      "code": 6038,
+      "name": "invalidPlatformFee",
+      "msg": "Platform fee exceeds the maximum of 10%"
+    },
+    {
+      "code": 6039,
+      "name": "programPaused",
+      "msg": "Program is paused"
+    },
+    {
+      "code": 6040,
+      "name": "campaignSuspended",
+      "msg": "Campaign has been suspended by the platform"
+    },
+    {
+      "code": 6041,
+      "name": "illegalStatusTransition",
+      "msg": "Illegal campaign status transition"
+    },
+    {
+      "code": 6042,
+      "name": "invalidCampaignStatus",
+      "msg": "Action is not allowed in the campaign's current status"
+    },
+    {
+      "code": 6043,
+      "name": "contributionNotSettled",
+      "msg": "Contribution is still needed: claim its refund or wait for the campaign to complete"
+    },
+    {
+      "code": 6044,
+      "name": "campaignNotSettled",
+      "msg": "Campaign must be completed, failed or cancelled before its accounts are closed"
+    },
+    {
+      "code": 6045,
+      "name": "campaignAccountsStillOpen",
+      "msg": "Contribution, milestone, reward tier and stretch goal accounts of the campaign must be closed first"
+    },
+    {
+      "code": 6046,
+      "name": "accountNotMigrated",
+      "msg": "Account uses an outdated layout, upgrade it with migrate_account first"
+    },
+    {
+      "code": 6047,
+      "name": "accountAlreadyMigrated",
+      "msg": "Account already uses the current layout"
+    },
+    {
+      "code": 6048,
+      "name": "notVersionedAccount",
+      "msg": "Only campaign, milestone, contribution, reward tier and stretch goal accounts can be migrated"
+    },
+    {
+      "code": 6049,
+      "name": "maxRewardTiersReached",
+      "msg": "Maximum number of reward tiers (10) reached"
+    },
+    {
+      "code": 6050,
+      "name": "invalidRewardTier",
+      "msg": "Reward tier needs a minimum pledge and a supply above zero"
+    },
+    {
+      "code": 6051,
+      "name": "rewardTierMismatch",
+      "msg": "Reward tier account does not match the requested tier"
+    },
+    {
+      "code": 6052,
+      "name": "pledgeBelowTierMinimum",
+      "msg": "Contribution total is below the reward tier's minimum pledge"
+    },
+    {
+      "code": 6053,
+      "name": "rewardTierSoldOut",
+      "msg": "Reward tier has no rewards left"
+    },
+    {
+      "code": 6054,
+      "name": "rewardTierAlreadyClaimed",
+      "msg": "Contributor already claimed another reward tier"
+    },
+    {
+      "code": 6055,
+      "name": "missingReceiptAccount",
+      "msg": "Receipt mint, token program and associated token program are required"
+    },
+    {
+      "code": 6056,
+      "name": "invalidReceiptAccount",
+      "msg": "Token account does not hold the contribution's receipt"
+    },
+    {
+      "code": 6057,
+      "name": "invalidUnpledgeCutoff",
+      "msg": "Unpledge cutoff cannot exceed the campaign duration"
+    },
+    {
+      "code": 6058,
+      "name": "unpledgeWindowClosed",
+      "msg": "Pledges can no longer be withdrawn from this campaign"
+    },
+    {
+      "code": 6059,
+      "name": "unpledgeExceedsContribution",
+      "msg": "Cannot withdraw more than the contribution"
+    },
+    {
+      "code": 6060,
+      "name": "maxStretchGoalsReached",
+      "msg": "Maximum number of stretch goals (10) reached"
+    },
+    {
+      "code": 6061,
+      "name": "invalidStretchGoalThreshold",
+      "msg": "Stretch goal threshold must exceed the funding goal, the amount raised and the previous stretch goal"
+    },
+    {
+      "code": 6062,
+      "name": "stretchGoalMismatch",
+      "msg": "Stretch goal account does not match the expected stretch goal"
+    },
+    {
+      "code": 6063,
+      "name": "stretchGoalNotFound",
+      "msg": "Campaign has no stretch goal at that index"
+    },
+    {
+      "code": 6064,
+      "name": "stretchGoalLocked",
+      "msg": "Milestone's stretch goal has not been unlocked"
+    },
+    {
+      "code": 6065,
+      "name": "campaignNotSuspended",
+      "msg": "Campaign is not suspended"
+    },
+    {
+      "code": 6066,
+      "name": "milestoneMismatch",
+      "msg": "Milestone account does not match the expected milestone"
+    },
+    {
+      "code": 6067,
+      "name": "milestoneVoteStillNeeded",
+      "msg": "Milestone vote is still needed: wait for the milestone to be released or the campaign to settle"
+    }
+  ],
+  "types": [
+    {
+      "name": "campaign",
+      "docs": [
+        "Main campaign account storing all campaign data, sized to its metadata"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaignId",
+            "docs": [
+              "Unique campaign identifier"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "creator",
+            "docs": [
+              "Campaign creator's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "title",
+            "docs": [
+              "Campaign title (max 80 characters)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "shortDescription",
+            "docs": [
+              "Short description (max 200 characters)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "category",
+            "docs": [
+              "Campaign category"
+            ],
+            "type": {
+              "defined": {
+                "name": "category"
+              }
+            }
+          },
+          {
+            "name": "coverImageUrl",
+            "docs": [
+              "IPFS hash for cover image"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "storyUrl",
+            "docs": [
+              "IPFS hash for long story content"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "fundingGoal",
+            "docs": [
+              "Target funding goal in base units of the campaign asset"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Campaign deadline (Unix timestamp)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "amountRaised",
+            "docs": [
+              "Amount currently pledged in base units of the campaign asset, net of",
+              "unpledges and refunds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "backerCount",
+            "docs": [
+              "Number of backers with a pledge still in the campaign"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "status",
+            "docs": [
+              "Lifecycle status"
+            ],
+            "type": {
+              "defined": {
+                "name": "campaignStatus"
+              }
+            }
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "Creation timestamp"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "milestoneCount",
+            "docs": [
+              "Number of milestones added"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "releasedAmount",
+            "docs": [
+              "Total amount released from the vault to the creator"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "allocatedBps",
+            "docs": [
+              "Sum of payout shares (basis points) assigned to milestones"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "releasedBps",
+            "docs": [
+              "Sum of payout shares (basis points) of released milestones"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "voteQuorumBps",
+            "docs": [
+              "Share of amount raised that must vote on a milestone (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "voteApprovalBps",
+            "docs": [
+              "Share of cast votes that must approve a milestone (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "failedMilestone",
+            "docs": [
+              "Index of the milestone rejected by backers, if the campaign failed at one"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "Token mint the campaign raises funds in (default pubkey for SOL campaigns)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "asset",
+            "docs": [
+              "Asset the campaign raises funds in"
+            ],
+            "type": {
+              "defined": {
+                "name": "fundingAsset"
+              }
+            }
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "Platform fee on payouts (basis points), fixed at creation"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "moderationReason",
+            "docs": [
+              "Platform reason code recorded by the last moderation action (0 = none)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "allowEarlyClose",
+            "docs": [
+              "Whether the campaign may be finalized before the deadline once the goal is met"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "finalizedAt",
+            "docs": [
+              "Timestamp the funding period was finalized (0 until finalized)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "openContributions",
+            "docs": [
+              "Contribution accounts created for the campaign and not closed yet"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "openMilestones",
+            "docs": [
+              "Milestone accounts created for the campaign and not closed yet"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version the account was last written with"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rewardTierCount",
+            "docs": [
+              "Number of reward tiers added"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "openRewardTiers",
+            "docs": [
+              "Reward tier accounts created for the campaign and not closed yet"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "transferableReceipts",
+            "docs": [
+              "Whether backers may transfer their contribution receipts"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "unpledgeCutoff",
+            "docs": [
+              "Seconds before the deadline after which pledges can no longer be withdrawn"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "totalRefunded",
+            "docs": [
+              "Pledges refunded to backers and taken out of `amount_raised`"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stretchGoalCount",
+            "docs": [
+              "Number of stretch goals added"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "openStretchGoals",
+            "docs": [
+              "Stretch goal accounts created for the campaign and not closed yet"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "unlockedStretchGoals",
+            "docs": [
+              "Number of stretch goals unlocked. Goals unlock in index order, so",
+              "these are the first ones."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "suspendedFrom",
+            "docs": [
+              "Status the campaign had before it was suspended, restored by",
+              "`unsuspend_campaign`"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "campaignStatus"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the creator cancels a campaign, opening refunds"
+      ],
+      "name": "campaignClosed",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Campaign creator's wallet address"
+            ],
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Total amount raised before cancellation"
+            ],
+            "name": "amountRaised",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Number of unique backers before cancellation"
+            ],
+            "name": "backerCount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Cancellation timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when everything raised has been paid out to the creator"
+      ],
+      "name": "campaignCompleted",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Total amount released from the vault"
+            ],
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Completion timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "campaignCounter",
+      "docs": [
+        "Global campaign counter for generating unique campaign IDs"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "docs": [
+              "Total number of campaigns created"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority that initialized the program"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a creator sets up a new campaign (still in draft)"
+      ],
+      "name": "campaignCreated",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Campaign creator's wallet address"
+            ],
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Unique campaign identifier"
+            ],
+            "name": "campaignId",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Asset the campaign raises funds in"
+            ],
+            "name": "asset",
+            "type": {
+              "defined": {
+                "name": "fundingAsset"
+              }
+            }
+          },
+          {
+            "docs": [
+              "Token mint (default pubkey for SOL campaigns)"
+            ],
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Target funding goal in base units of the campaign asset"
+            ],
+            "name": "fundingGoal",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Campaign deadline (Unix timestamp), moved forward again at launch"
+            ],
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "docs": [
+              "Platform fee on payouts (basis points), fixed at creation"
+            ],
+            "name": "feeBps",
+            "type": "u16"
+          },
+          {
+            "docs": [
+              "Creation timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when backers reject a milestone, failing the campaign"
+      ],
+      "name": "campaignFailed",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Index of the rejected milestone"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Contribution weight that voted to approve"
+            ],
+            "name": "yesWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Contribution weight that voted to reject"
+            ],
+            "name": "noWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Failure timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the funding period ends, as succeeded or failed"
+      ],
+      "name": "campaignFinalized",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Status the campaign moved to, `Succeeded` or `Failed`"
+            ],
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "campaignStatus"
+              }
+            }
+          },
+          {
+            "docs": [
+              "Total amount raised by the end of funding"
+            ],
+            "name": "amountRaised",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Number of backers by the end of funding"
+            ],
+            "name": "backerCount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Finalization timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the creator launches a draft campaign, opening contributions"
+      ],
+      "name": "campaignLaunched",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Campaign deadline (Unix timestamp), counted from launch"
+            ],
+            "name": "deadline",
+            "type": "i64"
+          },
+          {
+            "docs": [
+              "Launch timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the creator edits a campaign's metadata"
+      ],
+      "name": "campaignMetadataUpdated",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Campaign title"
+            ],
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "docs": [
+              "Short description"
+            ],
+            "name": "shortDescription",
+            "type": "string"
+          },
+          {
+            "docs": [
+              "IPFS hash for cover image"
+            ],
+            "name": "coverImageUrl",
+            "type": "string"
+          },
+          {
+            "docs": [
+              "IPFS hash for long story content"
+            ],
+            "name": "storyUrl",
+            "type": "string"
+          },
+          {
+            "docs": [
+              "Update timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when the platform admin suspends or fails a campaign"
+      ],
+      "name": "campaignModerated",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Status the campaign moved to"
+            ],
+            "name": "status",
+            "type": {
+              "defined": {
+                "name": "campaignStatus"
+              }
+            }
+          },
+          {
+            "docs": [
+              "Platform reason code given for the action"
+            ],
+            "name": "reason",
+            "type": "u16"
+          },
+          {
+            "docs": [
+              "Moderation timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "campaignStatus",
+      "docs": [
+        "Campaign lifecycle status"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "draft"
+          },
+          {
+            "name": "active"
+          },
+          {
+            "name": "succeeded"
+          },
+          {
+            "name": "failed"
+          },
+          {
+            "name": "cancelled"
+          },
+          {
+            "name": "suspended"
+          },
+          {
+            "name": "completed"
+          }
+        ]
+      }
+    },
+    {
+      "name": "category",
+      "docs": [
+        "Campaign category enum matching frontend categories"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "environment"
+          },
+          {
+            "name": "education"
+          },
+          {
+            "name": "healthcare"
+          },
+          {
+            "name": "technology"
+          },
+          {
+            "name": "community"
+          },
+          {
+            "name": "arts"
+          }
+        ]
+      }
+    },
+    {
+      "name": "contribution",
+      "docs": [
+        "Contribution account tracking individual backer contributions"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "docs": [
+              "Reference to campaign"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "contributor",
+            "docs": [
+              "Contributor's wallet address"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount pledged (in base units of the campaign asset), zeroed once",
+              "refunded"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "contributedAt",
+            "docs": [
+              "Timestamp of first contribution"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "refundClaimed",
+            "docs": [
+              "Whether refund has been claimed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version the account was last written with"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rewardTier",
+            "docs": [
+              "Index of the reward tier claimed by the contributor, if any"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "name": "hasReceipt",
+            "docs": [
+              "Whether a receipt token minted for the contribution is still live"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted for every contribution landing in the campaign vault"
+      ],
+      "name": "contributionMade",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Contributor's wallet address"
+            ],
+            "name": "contributor",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Amount received by the vault, net of any Token-2022 transfer fee"
+            ],
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Contributor's running total on the campaign"
+            ],
+            "name": "contributionTotal",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Campaign's running total raised"
+            ],
+            "name": "amountRaised",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Number of unique backers after this contribution"
+            ],
+            "name": "backerCount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Reward tier claimed by the contributor, if any"
+            ],
+            "name": "rewardTier",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "docs": [
+              "Contribution timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "fundingAsset",
+      "docs": [
+        "Asset a campaign raises funds in"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "token"
+          },
+          {
+            "name": "sol"
+          }
+        ]
+      }
+    },
+    {
+      "docs": [
+        "Emitted when funds leave the vault for the creator, either through",
+        "`withdraw_funds` or a milestone release"
+      ],
+      "name": "fundsWithdrawn",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Campaign creator's wallet address"
+            ],
+            "name": "creator",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone whose release paid out the funds, if any"
+            ],
+            "name": "milestoneIndex",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "docs": [
+              "Total amount taken out of the vault"
+            ],
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Part of `amount` paid to the creator"
+            ],
+            "name": "creatorAmount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Part of `amount` paid to the platform treasury"
+            ],
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Campaign's running total released from the vault"
+            ],
+            "name": "releasedAmount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Withdrawal timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "name": "milestone",
+      "docs": [
+        "Milestone account linked to a campaign"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "campaign",
+            "docs": [
+              "Reference to parent campaign"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "milestoneIndex",
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "title",
+            "docs": [
+              "Milestone title (max 100 characters)"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "targetAmount",
+            "docs": [
+              "Target amount for this milestone (in base units of the campaign asset)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "isCompleted",
+            "docs": [
+              "Whether milestone is completed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "payoutBps",
+            "docs": [
+              "Share of funds raised paid out for this milestone (basis points)"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "isReleased",
+            "docs": [
+              "Whether the milestone tranche has been released to the creator"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "proofUrl",
+            "docs": [
+              "IPFS hash of the creator's proof of delivery"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "votingEndsAt",
+            "docs": [
+              "End of the backer voting window (Unix timestamp, 0 until proof is submitted)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "yesWeight",
+            "docs": [
+              "Contribution weight voting to approve"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "noWeight",
+            "docs": [
+              "Contribution weight voting to reject"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version the account was last written with"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "stretchGoal",
+            "docs": [
+              "Index of the stretch goal that has to unlock before proof can be",
+              "submitted, if the milestone is gated on one"
+            ],
+            "type": {
+              "option": "u8"
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 6014,
-      "name": "descriptionTooLong",
-      "msg": "Description exceeds maximum length of 200 characters"
+      "docs": [
+        "Emitted when a creator adds a milestone to a draft campaign"
+      ],
+      "name": "milestoneAdded",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Target amount for this milestone (in base units of the campaign asset)"
+            ],
+            "name": "targetAmount",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Share of funds raised paid out for this milestone (basis points)"
+            ],
+            "name": "payoutBps",
+            "type": "u16"
+          },
+          {
+            "docs": [
+              "Campaign's running sum of milestone payout shares (basis points)"
+            ],
+            "name": "allocatedBps",
+            "type": "u16"
+          },
+          {
+            "docs": [
+              "Stretch goal the milestone is gated on, if any"
+            ],
+            "name": "stretchGoal",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "docs": [
+              "Timestamp the milestone was added"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6015,
-      "name": "urlTooLong",
-      "msg": "URL exceeds maximum length of 200 characters"
+      "docs": [
+        "Emitted when backers approve a milestone and its tranche can be released"
+      ],
+      "name": "milestoneCompleted",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Contribution weight that voted to approve"
+            ],
+            "name": "yesWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Contribution weight that voted to reject"
+            ],
+            "name": "noWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Completion timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6016,
-      "name": "milestoneTitleTooLong",
-      "msg": "Milestone title exceeds maximum length of 100 characters"
+      "docs": [
+        "Emitted when finalizing a campaign moves the shares of milestones gated",
+        "on stretch goals that never unlocked"
+      ],
+      "name": "milestonePayoutReassigned",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Milestone's new share of funds raised (basis points)"
+            ],
+            "name": "payoutBps",
+            "type": "u16"
+          },
+          {
+            "docs": [
+              "Stretch goal the milestone is still gated on, if any"
+            ],
+            "name": "stretchGoal",
+            "type": {
+              "option": "u8"
+            }
+          },
+          {
+            "docs": [
+              "Finalization timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6017,
-      "name": "invalidFundingGoal",
-      "msg": "Funding goal must be greater than zero"
+      "docs": [
+        "Emitted when the creator submits proof of a milestone, opening the backer vote"
+      ],
+      "name": "milestoneProofSubmitted",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "IPFS hash of the creator's proof of delivery"
+            ],
+            "name": "proofUrl",
+            "type": "string"
+          },
+          {
+            "docs": [
+              "End of the backer voting window (Unix timestamp)"
+            ],
+            "name": "votingEndsAt",
+            "type": "i64"
+          },
+          {
+            "docs": [
+              "Submission timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6018,
-      "name": "invalidContributionAmount",
-      "msg": "Contribution amount must be greater than zero"
+      "docs": [
+        "Emitted when a milestone vote closes without reaching quorum, reopening",
+        "the milestone for a new proof submission"
+      ],
+      "name": "milestoneQuorumMissed",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Contribution weight that voted to approve"
+            ],
+            "name": "yesWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Contribution weight that voted to reject"
+            ],
+            "name": "noWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Timestamp the vote was closed"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6019,
-      "name": "invalidDuration",
-      "msg": "Campaign duration must be between 1 and 90 days"
+      "docs": [
+        "Emitted when the creator releases an approved milestone's tranche. The",
+        "payout itself is reported by `FundsWithdrawn`."
+      ],
+      "name": "milestoneReleased",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Campaign's running sum of released payout shares (basis points)"
+            ],
+            "name": "releasedBps",
+            "type": "u16"
+          },
+          {
+            "docs": [
+              "Release timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6020,
-      "name": "arithmeticOverflow",
-      "msg": "Arithmetic overflow occurred"
+      "name": "milestoneVote",
+      "docs": [
+        "Vote cast by a contributor on a milestone, one per (milestone, contributor)"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "milestone",
+            "docs": [
+              "Reference to the milestone voted on"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "voter",
+            "docs": [
+              "Contributor who cast the vote"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Vote weight (contribution amount at the time of voting)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "approve",
+            "docs": [
+              "Whether the vote approves the milestone"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "votedAt",
+            "docs": [
+              "Timestamp of the vote"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 6021,
-      "name": "insufficientFunds",
-      "msg": "Insufficient funds in campaign account"
+      "docs": [
+        "Emitted for every backer vote on a milestone"
+      ],
+      "name": "milestoneVoted",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone account"
+            ],
+            "name": "milestone",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Milestone index (0-based)"
+            ],
+            "name": "milestoneIndex",
+            "type": "u8"
+          },
+          {
+            "docs": [
+              "Contributor who cast the vote"
+            ],
+            "name": "voter",
+            "type": "pubkey"
+          },
+          {
+            "docs": [
+              "Whether the vote approves the milestone"
+            ],
+            "name": "approve",
+            "type": "bool"
+          },
+          {
+            "docs": [
+              "Vote weight (contribution amount at the time of voting)"
+            ],
+            "name": "weight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Milestone's running contribution weight voting to approve"
+            ],
+            "name": "yesWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Milestone's running contribution weight voting to reject"
+            ],
+            "name": "noWeight",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Vote timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
     },
     {
-      "code": 6022,
-      "name": "invalidMint",
-      "msg": "Invalid token mint address"
+      "name": "platformConfig",
+      "docs": [
+        "Program-level configuration: admin, pause flag, accepted token mints and platform fee"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Admin allowed to update the configuration"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "acceptedMints",
+            "docs": [
+              "Token mints campaigns may raise funds in"
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "PDA bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "feeBps",
+            "docs": [
+              "Fee taken from creator payouts (basis points), snapshotted by new campaigns"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Wallet receiving platform fees (or the owner of its token accounts)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "Admin proposed by the current admin, pending acceptance"
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "isPaused",
+            "docs": [
+              "Whether state-mutating instructions are frozen"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 6023,
-      "name": "invalidTokenAccount",
-      "msg": "Invalid token account"
-    }
-  ],
-  "types": [
-    {
-      "name": "campaign",
       "docs": [
-        "Main campaign account storing all campaign data"
+        "Emitted when a contributor withdraws part or all of their pledge"
       ],
+      "name": "pledgeWithdrawn",
       "type": {
-        "kind": "struct",
         "fields": [
           {
-            "name": "campaignId",
             "docs": [
-              "Unique campaign identifier"
+              "Campaign account"
             ],
-            "type": "u64"
+            "name": "campaign",
+            "type": "pubkey"
           },
           {
-            "name": "creator",
             "docs": [
-              "Campaign creator's wallet address"
+              "Contributor's wallet address"
             ],
+            "name": "contributor",
             "type": "pubkey"
           },
           {
-            "name": "title",
             "docs": [
-              "Campaign title (max 80 characters)"
+              "Amount paid back out of the vault"
             ],
-            "type": "string"
+            "name": "amount",
+            "type": "u64"
           },
           {
-            "name": "shortDescription",
             "docs": [
-              "Short description (max 200 characters)"
+              "Contributor's running total on the campaign"
             ],
-            "type": "string"
+            "name": "contributionTotal",
+            "type": "u64"
           },
           {
-            "name": "category",
             "docs": [
-              "Campaign category"
+              "Campaign's running total raised"
             ],
-            "type": {
-              "defined": {
-                "name": "category"
-              }
-            }
+            "name": "amountRaised",
+            "type": "u64"
           },
           {
-            "name": "coverImageUrl",
             "docs": [
-              "IPFS hash for cover image"
+              "Number of unique backers after the withdrawal"
             ],
-            "type": "string"
+            "name": "backerCount",
+            "type": "u64"
           },
           {
-            "name": "storyUrl",
             "docs": [
-              "IPFS hash for long story content"
+              "Reward tier still claimed by the contributor, if any"
             ],
-            "type": "string"
+            "name": "rewardTier",
+            "type": {
+              "option": "u8"
+            }
           },
           {
-            "name": "fundingGoal",
             "docs": [
-              "Target funding goal in lamports"
+              "Withdrawal timestamp"
             ],
-            "type": "u64"
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a contributor is refunded from the vault"
+      ],
+      "name": "refundClaimed",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
           },
           {
-            "name": "deadline",
             "docs": [
-              "Campaign deadline (Unix timestamp)"
+              "Contributor's wallet address"
             ],
-            "type": "i64"
+            "name": "contributor",
+            "type": "pubkey"
           },
           {
-            "name": "amountRaised",
             "docs": [
-              "Total amount raised in lamports"
+              "Amount refunded"
             ],
+            "name": "amount",
             "type": "u64"
           },
           {
-            "name": "backerCount",
             "docs": [
-              "Number of unique backers"
+              "Contribution the refund was computed from"
             ],
+            "name": "contributionAmount",
             "type": "u64"
           },
           {
-            "name": "isActive",
             "docs": [
-              "Whether campaign is active"
+              "Whether the refund is a pro-rata share after a rejected milestone"
             ],
+            "name": "partial",
             "type": "bool"
           },
           {
-            "name": "createdAt",
             "docs": [
-              "Creation timestamp"
+              "Campaign's amount raised after the refunded pledge was taken out"
             ],
-            "type": "i64"
+            "name": "amountRaised",
+            "type": "u64"
           },
           {
-            "name": "milestoneCount",
             "docs": [
-              "Number of milestones added"
+              "Number of backers left after the refund"
             ],
-            "type": "u8"
+            "name": "backerCount",
+            "type": "u64"
           },
           {
-            "name": "bump",
             "docs": [
-              "PDA bump"
+              "Campaign's total of refunded pledges"
             ],
-            "type": "u8"
+            "name": "totalRefunded",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Refund timestamp"
+            ],
+            "name": "timestamp",
+            "type": "i64"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     },
     {
-      "name": "campaignCounter",
+      "name": "rewardTier",
       "docs": [
-        "Global campaign counter for generating unique campaign IDs"
+        "Perk of a campaign, claimed by backers pledging at least `min_pledge`",
+        "while supply lasts"
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "count",
+            "name": "campaign",
             "docs": [
-              "Total number of campaigns created"
+              "Reference to parent campaign"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "tierIndex",
+            "docs": [
+              "Tier index (0-based)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "minPledge",
+            "docs": [
+              "Minimum total contribution to claim the tier (in base units of the campaign asset)"
             ],
             "type": "u64"
           },
           {
-            "name": "authority",
+            "name": "maxSupply",
             "docs": [
-              "Authority that initialized the program"
+              "Number of backers that can claim the tier"
             ],
-            "type": "pubkey"
+            "type": "u32"
+          },
+          {
+            "name": "claimed",
+            "docs": [
+              "Number of backers that claimed the tier"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "metadataUrl",
+            "docs": [
+              "IPFS hash of the reward description"
+            ],
+            "type": "string"
           },
           {
             "name": "bump",
@@ -1293,43 +7048,82 @@ export type HopeRise = {
               "PDA bump"
             ],
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version the account was last written with"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "category",
       "docs": [
-        "Campaign category enum matching frontend categories"
+        "Emitted when a creator adds a reward tier to a campaign"
       ],
+      "name": "rewardTierAdded",
       "type": {
-        "kind": "enum",
-        "variants": [
+        "fields": [
           {
-            "name": "environment"
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
           },
           {
-            "name": "education"
+            "docs": [
+              "Reward tier account"
+            ],
+            "name": "rewardTier",
+            "type": "pubkey"
           },
           {
-            "name": "healthcare"
+            "docs": [
+              "Tier index (0-based)"
+            ],
+            "name": "tierIndex",
+            "type": "u8"
           },
           {
-            "name": "technology"
+            "docs": [
+              "Minimum total contribution to claim the tier (in base units of the campaign asset)"
+            ],
+            "name": "minPledge",
+            "type": "u64"
           },
           {
-            "name": "community"
+            "docs": [
+              "Number of backers that can claim the tier"
+            ],
+            "name": "maxSupply",
+            "type": "u32"
           },
           {
-            "name": "arts"
+            "docs": [
+              "IPFS hash of the reward description"
+            ],
+            "name": "metadataUrl",
+            "type": "string"
+          },
+          {
+            "docs": [
+              "Timestamp the tier was added"
+            ],
+            "name": "timestamp",
+            "type": "i64"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     },
     {
-      "name": "contribution",
+      "name": "stretchGoal",
       "docs": [
-        "Contribution account tracking individual backer contributions"
+        "Amount raised beyond the funding goal that unlocks more of the creator's",
+        "plans, announced as a stretch goal"
       ],
       "type": {
         "kind": "struct",
@@ -1337,37 +7131,37 @@ export type HopeRise = {
           {
             "name": "campaign",
             "docs": [
-              "Reference to campaign"
+              "Reference to parent campaign"
             ],
             "type": "pubkey"
           },
           {
-            "name": "contributor",
+            "name": "goalIndex",
             "docs": [
-              "Contributor's wallet address"
+              "Stretch goal index (0-based)"
             ],
-            "type": "pubkey"
+            "type": "u8"
           },
           {
-            "name": "amount",
+            "name": "threshold",
             "docs": [
-              "Total amount contributed (in lamports)"
+              "Amount raised that unlocks the goal (in base units of the campaign asset)"
             ],
             "type": "u64"
           },
           {
-            "name": "contributedAt",
+            "name": "metadataUrl",
             "docs": [
-              "Timestamp of first contribution"
+              "IPFS hash of the stretch goal description"
             ],
-            "type": "i64"
+            "type": "string"
           },
           {
-            "name": "refundClaimed",
+            "name": "unlockedAt",
             "docs": [
-              "Whether refund has been claimed"
+              "Timestamp the amount raised first crossed the threshold (0 while locked)"
             ],
-            "type": "bool"
+            "type": "i64"
           },
           {
             "name": "bump",
@@ -1375,61 +7169,121 @@ export type HopeRise = {
               "PDA bump"
             ],
             "type": "u8"
+          },
+          {
+            "name": "version",
+            "docs": [
+              "Layout version the account was last written with"
+            ],
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "milestone",
       "docs": [
-        "Milestone account linked to a campaign"
+        "Emitted when a creator adds a stretch goal to a campaign"
       ],
+      "name": "stretchGoalAdded",
       "type": {
-        "kind": "struct",
         "fields": [
           {
+            "docs": [
+              "Campaign account"
+            ],
             "name": "campaign",
+            "type": "pubkey"
+          },
+          {
             "docs": [
-              "Reference to parent campaign"
+              "Stretch goal account"
             ],
+            "name": "stretchGoal",
             "type": "pubkey"
           },
           {
-            "name": "milestoneIndex",
             "docs": [
-              "Milestone index (0-based)"
+              "Stretch goal index (0-based)"
             ],
+            "name": "goalIndex",
             "type": "u8"
           },
           {
-            "name": "title",
             "docs": [
-              "Milestone title (max 100 characters)"
+              "Amount raised that unlocks the goal (in base units of the campaign asset)"
+            ],
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "IPFS hash of the stretch goal description"
             ],
+            "name": "metadataUrl",
             "type": "string"
           },
           {
-            "name": "targetAmount",
             "docs": [
-              "Target amount for this milestone (in lamports)"
+              "Timestamp the goal was added"
             ],
-            "type": "u64"
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ],
+        "kind": "struct"
+      }
+    },
+    {
+      "docs": [
+        "Emitted when a contribution takes the amount raised past a stretch goal"
+      ],
+      "name": "stretchGoalUnlocked",
+      "type": {
+        "fields": [
+          {
+            "docs": [
+              "Campaign account"
+            ],
+            "name": "campaign",
+            "type": "pubkey"
           },
           {
-            "name": "isCompleted",
             "docs": [
-              "Whether milestone is completed"
+              "Stretch goal account"
             ],
-            "type": "bool"
+            "name": "stretchGoal",
+            "type": "pubkey"
           },
           {
-            "name": "bump",
             "docs": [
-              "PDA bump"
+              "Stretch goal index (0-based)"
             ],
+            "name": "goalIndex",
             "type": "u8"
+          },
+          {
+            "docs": [
+              "Amount raised that unlocked the goal (in base units of the campaign asset)"
+            ],
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Campaign's amount raised after the contribution"
+            ],
+            "name": "amountRaised",
+            "type": "u64"
+          },
+          {
+            "docs": [
+              "Timestamp the goal was unlocked"
+            ],
+            "name": "timestamp",
+            "type": "i64"
           }
-        ]
+        ],
+        "kind": "struct"
       }
     }
   ]
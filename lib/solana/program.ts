@@ -8,6 +8,9 @@ export const PROGRAM_ID = new PublicKey('BAaDjLVffrtNzgKLoUjmM9t1tWBHxMF6UFdnL1N
 // Circle's official USDC Mint on Solana devnet (faucet: https://faucet.circle.com/)
 export const USDC_MINT = new PublicKey('Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr');
 
+// Loader of upgradeable programs, which derives the program data account
+export const BPF_LOADER_UPGRADEABLE_ID = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+
 // USDC has 6 decimals
 export const USDC_DECIMALS = 6;
 
@@ -15,10 +18,13 @@ export const USDC_DECIMALS = 6;
 export const connection = new Connection(clusterApiUrl('devnet'), 'confirmed');
 
 // PDA Seeds
+export const PLATFORM_CONFIG_SEED = 'platform_config';
 export const CAMPAIGN_COUNTER_SEED = 'campaign_counter';
 export const CAMPAIGN_SEED = 'campaign';
 export const MILESTONE_SEED = 'milestone';
+export const STRETCH_GOAL_SEED = 'stretch_goal';
 export const CONTRIBUTION_SEED = 'contribution';
+export const RECEIPT_MINT_SEED = 'receipt_mint';
 export const CAMPAIGN_VAULT_SEED = 'campaign_vault';
 
 // Category enum mapping
@@ -45,7 +51,25 @@ export function getCategoryString(category: Record<string, object>): string {
   return key.charAt(0).toUpperCase() + key.slice(1);
 }
 
+// Helper to convert campaign status enum to string
+export function getStatusString(status: Record<string, object>): string {
+  const key = Object.keys(status)[0];
+  return key.charAt(0).toUpperCase() + key.slice(1);
+}
+
 // PDA derivation helpers
+export function getPlatformConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from(PLATFORM_CONFIG_SEED)],
+    PROGRAM_ID
+  );
+}
+
+// Program data account holding the program's upgrade authority
+export function getProgramDataPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([PROGRAM_ID.toBuffer()], BPF_LOADER_UPGRADEABLE_ID);
+}
+
 export function getCampaignCounterPDA(): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from(CAMPAIGN_COUNTER_SEED)],
@@ -76,6 +100,17 @@ export function getMilestonePDA(campaignPubkey: PublicKey, milestoneIndex: numbe
   );
 }
 
+export function getStretchGoalPDA(campaignPubkey: PublicKey, goalIndex: number): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(STRETCH_GOAL_SEED),
+      campaignPubkey.toBuffer(),
+      Buffer.from([goalIndex]),
+    ],
+    PROGRAM_ID
+  );
+}
+
 export function getContributionPDA(campaignPubkey: PublicKey, contributor: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [
@@ -98,6 +133,17 @@ export function getCampaignVaultPDA(campaignPubkey: PublicKey): [PublicKey, numb
   );
 }
 
+// Get the Token-2022 receipt mint minted for a contribution
+export function getReceiptMintPDA(contributionPubkey: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(RECEIPT_MINT_SEED),
+      contributionPubkey.toBuffer(),
+    ],
+    PROGRAM_ID
+  );
+}
+
 // Helper to convert USDC base units to display value (6 decimals)
 export function usdcToDisplay(baseUnits: number | BN): number {
   const value = typeof baseUnits === 'number' ? baseUnits : baseUnits.toNumber();
@@ -127,7 +173,7 @@ export interface Campaign {
   deadline: number; // unix timestamp
   amountRaised: number; // in USDC base units (6 decimals)
   backerCount: number;
-  isActive: boolean;
+  status: string; // Draft, Active, Succeeded, Failed, Cancelled, Completed or Suspended
   createdAt: number; // unix timestamp
   milestoneCount: number;
   bump: number;
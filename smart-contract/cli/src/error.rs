@@ -0,0 +1,32 @@
+use anchor_lang::prelude::Pubkey;
+use thiserror::Error;
+
+/// Errors raised while talking to the cluster or reading program accounts
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("RPC request failed: {0}")]
+    Rpc(String),
+
+    #[error("transaction {signature} failed: {reason}")]
+    TransactionFailed { signature: String, reason: String },
+
+    #[error("account {0} does not exist")]
+    AccountNotFound(Pubkey),
+
+    #[error("account {address} is not a valid {expected}")]
+    InvalidAccount {
+        address: Pubkey,
+        expected: &'static str,
+    },
+
+    #[error("could not read keypair {path}: {reason}")]
+    Keypair { path: String, reason: String },
+
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+pub type Result<T> = std::result::Result<T, CliError>;
@@ -0,0 +1,15 @@
+//! Operator tooling for the hope_rise program.
+//!
+//! [`rpc::RpcClient`] talks JSON RPC to a cluster and submits transactions,
+//! [`query`] fetches and decodes program accounts and [`output`] renders them
+//! as tables or JSON. The `hope-rise` binary wires these to subcommands that
+//! build instructions with `hope_rise-client`.
+
+pub mod error;
+pub mod output;
+pub mod query;
+pub mod rpc;
+
+pub use error::{CliError, Result};
+pub use output::OutputFormat;
+pub use rpc::RpcClient;
@@ -0,0 +1,654 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use hope_rise::state::Category;
+use hope_rise_cli::output::{
+    render, CampaignDetails, CampaignList, CampaignView, ContributionList, ContributionView,
+    MilestoneView, Sent,
+};
+use hope_rise_cli::{query, CliError, OutputFormat, Result, RpcClient};
+use hope_rise_client::{instructions as ix, pda, Asset, CampaignArgs};
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+
+#[derive(Parser)]
+#[command(
+    name = "hope-rise",
+    about = "Operate hope_rise campaigns from the command line"
+)]
+struct Cli {
+    /// JSON RPC endpoint of the cluster
+    #[arg(
+        long,
+        short = 'u',
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Keypair that signs and pays for transactions [default: ~/.config/solana/id.json]
+    #[arg(long, short = 'k', global = true)]
+    keypair: Option<PathBuf>,
+
+    #[arg(long, short = 'o', global = true, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the campaign counter
+    Init,
+    /// Create the platform config, making the signer (the program's upgrade
+    /// authority) admin
+    InitConfig,
+    /// Create a draft campaign owned by the signer
+    Create(CreateArgs),
+    /// Edit the title, description or links of a draft or active campaign
+    Update(UpdateArgs),
+    /// Open a draft campaign for funding
+    Launch { campaign: Pubkey },
+    /// Contribute to a campaign, in lamports or base token units
+    Fund {
+        campaign: Pubkey,
+        amount: u64,
+
+        /// Claim the reward tier at this index
+        #[arg(long)]
+        tier: Option<u8>,
+    },
+    /// Withdraw part or all of the signer's pledge before the unpledge cutoff
+    Unpledge { campaign: Pubkey, amount: u64 },
+    /// Settle a campaign past its deadline, or early once funded if allowed
+    Finalize { campaign: Pubkey },
+    /// Cancel a draft, or an active campaign short of its goal, so backers
+    /// can claim refunds
+    Close { campaign: Pubkey },
+    /// Pay out the unlocked funds of a succeeded campaign to the creator
+    Withdraw { campaign: Pubkey },
+    /// Claim the signer's refund from a failed or cancelled campaign
+    Refund {
+        campaign: Pubkey,
+
+        /// Claim the unreleased share after a failed milestone vote
+        #[arg(long)]
+        partial: bool,
+    },
+    /// Manage campaign milestones
+    #[command(subcommand)]
+    Milestone(MilestoneCommand),
+    /// Manage campaign reward tiers
+    #[command(subcommand)]
+    Reward(RewardCommand),
+    /// Manage campaign stretch goals
+    #[command(subcommand)]
+    Stretch(StretchCommand),
+    /// Close accounts of settled campaigns and reclaim their rent
+    #[command(subcommand)]
+    Reclaim(ReclaimCommand),
+    /// Upgrade a campaign, milestone, contribution, reward tier or stretch goal
+    /// account to the current layout
+    Migrate { account: Pubkey },
+    /// List campaigns
+    Campaigns {
+        #[arg(long)]
+        creator: Option<Pubkey>,
+    },
+    /// Show a campaign and its milestones
+    Inspect { campaign: Pubkey },
+    /// List contributions
+    Contributions {
+        #[arg(long)]
+        campaign: Option<Pubkey>,
+
+        #[arg(long)]
+        contributor: Option<Pubkey>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MilestoneCommand {
+    /// Add the next milestone to a campaign that has released no funds
+    Add {
+        campaign: Pubkey,
+
+        #[arg(long)]
+        title: String,
+
+        #[arg(long)]
+        target_amount: u64,
+
+        /// Share of the raised funds this milestone releases
+        #[arg(long)]
+        payout_bps: u16,
+
+        /// Only accept proof once the stretch goal at this index is unlocked
+        #[arg(long)]
+        stretch_goal: Option<u8>,
+    },
+    /// Submit proof of a milestone, opening the backer vote
+    SubmitProof {
+        campaign: Pubkey,
+        index: u8,
+        proof_url: String,
+    },
+    /// Vote on a milestone as a backer
+    Vote {
+        campaign: Pubkey,
+        index: u8,
+        #[arg(value_enum)]
+        vote: Vote,
+    },
+    /// Tally a milestone vote once its window closed
+    Complete { campaign: Pubkey, index: u8 },
+    /// Pay out an approved milestone's tranche to the creator
+    Release { campaign: Pubkey, index: u8 },
+}
+
+#[derive(Subcommand)]
+enum RewardCommand {
+    /// Add the next reward tier to a draft or active campaign
+    Add {
+        campaign: Pubkey,
+
+        /// Smallest contribution total that claims the tier
+        #[arg(long)]
+        min_pledge: u64,
+
+        /// Number of backers that can claim the tier
+        #[arg(long)]
+        max_supply: u32,
+
+        #[arg(long)]
+        metadata_url: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum StretchCommand {
+    /// Add the next stretch goal to a draft or active campaign
+    Add {
+        campaign: Pubkey,
+
+        /// Amount raised that unlocks the goal, above the funding goal and
+        /// the previous stretch goal
+        #[arg(long)]
+        threshold: u64,
+
+        #[arg(long)]
+        metadata_url: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReclaimCommand {
+    /// Close a refunded or completed contribution, its rent goes to the contributor
+    Contribution {
+        campaign: Pubkey,
+
+        /// Contributor whose account to close [default: the signer]
+        #[arg(long)]
+        contributor: Option<Pubkey>,
+    },
+    /// Close a milestone of a settled campaign, its rent goes to the creator
+    Milestone { campaign: Pubkey, index: u8 },
+    /// Close a vote on a released milestone or one of a settled campaign, its
+    /// rent goes to the voter
+    MilestoneVote {
+        campaign: Pubkey,
+        index: u8,
+
+        /// Voter whose vote to close [default: the signer]
+        #[arg(long)]
+        voter: Option<Pubkey>,
+    },
+    /// Close a reward tier of a settled campaign, its rent goes to the creator
+    RewardTier { campaign: Pubkey, index: u8 },
+    /// Close a stretch goal of a settled campaign, its rent goes to the creator
+    StretchGoal { campaign: Pubkey, index: u8 },
+    /// Close a settled campaign and its vault once its contributions,
+    /// milestones, reward tiers and stretch goals are closed
+    Campaign { campaign: Pubkey },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Vote {
+    Approve,
+    Reject,
+}
+
+#[derive(Args)]
+struct CreateArgs {
+    #[arg(long)]
+    title: String,
+
+    #[arg(long)]
+    description: String,
+
+    #[arg(long, value_enum)]
+    category: CategoryArg,
+
+    #[arg(long)]
+    cover_image_url: String,
+
+    #[arg(long)]
+    story_url: String,
+
+    /// In lamports or base token units
+    #[arg(long)]
+    funding_goal: u64,
+
+    #[arg(long)]
+    duration_days: u64,
+
+    /// Accepted token mint to raise; raises SOL when omitted
+    #[arg(long)]
+    mint: Option<Pubkey>,
+
+    #[arg(long, default_value_t = 0)]
+    vote_quorum_bps: u16,
+
+    #[arg(long, default_value_t = 5_000)]
+    vote_approval_bps: u16,
+
+    /// Allow finalizing before the deadline once the goal is met
+    #[arg(long)]
+    allow_early_close: bool,
+
+    /// Let backers transfer their contribution receipts
+    #[arg(long)]
+    transferable_receipts: bool,
+
+    /// Hours before the deadline after which backers can no longer unpledge
+    #[arg(long, default_value_t = 48)]
+    unpledge_cutoff_hours: u32,
+}
+
+/// Fields left out keep their current value
+#[derive(Args)]
+struct UpdateArgs {
+    campaign: Pubkey,
+
+    #[arg(long)]
+    title: Option<String>,
+
+    #[arg(long)]
+    description: Option<String>,
+
+    #[arg(long)]
+    cover_image_url: Option<String>,
+
+    #[arg(long)]
+    story_url: Option<String>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CategoryArg {
+    Environment,
+    Education,
+    Healthcare,
+    Technology,
+    Community,
+    Arts,
+}
+
+impl From<CategoryArg> for Category {
+    fn from(category: CategoryArg) -> Self {
+        match category {
+            CategoryArg::Environment => Category::Environment,
+            CategoryArg::Education => Category::Education,
+            CategoryArg::Healthcare => Category::Healthcare,
+            CategoryArg::Technology => Category::Technology,
+            CategoryArg::Community => Category::Community,
+            CategoryArg::Arts => Category::Arts,
+        }
+    }
+}
+
+fn load_keypair(path: Option<PathBuf>) -> Result<Keypair> {
+    let path = path.unwrap_or_else(|| {
+        let home = std::env::var_os("HOME").unwrap_or_default();
+        PathBuf::from(home).join(".config/solana/id.json")
+    });
+    read_keypair_file(&path).map_err(|err| CliError::Keypair {
+        path: path.display().to_string(),
+        reason: err.to_string(),
+    })
+}
+
+fn send(
+    rpc: &RpcClient,
+    signer: &Keypair,
+    instruction: Instruction,
+    account: Option<Pubkey>,
+) -> Result<Sent> {
+    let signature = rpc.send(&[instruction], signer, &[])?;
+    Ok(Sent {
+        signature: signature.to_string(),
+        account: account.map(|account| account.to_string()),
+    })
+}
+
+fn run(cli: Cli) -> Result<String> {
+    let rpc = RpcClient::new(&cli.url);
+
+    match cli.command {
+        Command::Campaigns { creator } => {
+            let campaigns = query::campaigns(&rpc, creator.as_ref())?;
+            let views = campaigns
+                .iter()
+                .map(|(address, campaign)| CampaignView::new(address, campaign))
+                .collect();
+            render(&CampaignList(views), cli.output)
+        }
+        Command::Inspect { campaign } => {
+            let state = query::campaign(&rpc, &campaign)?;
+            let milestones = query::milestones(&rpc, &campaign)?;
+            let details = CampaignDetails {
+                campaign: CampaignView::new(&campaign, &state),
+                milestones: milestones
+                    .iter()
+                    .map(|(address, milestone)| MilestoneView::new(address, milestone))
+                    .collect(),
+            };
+            render(&details, cli.output)
+        }
+        Command::Contributions {
+            campaign,
+            contributor,
+        } => {
+            let contributions =
+                query::contributions(&rpc, campaign.as_ref(), contributor.as_ref())?;
+            let views = contributions
+                .iter()
+                .map(|(address, contribution)| ContributionView::new(address, contribution))
+                .collect();
+            render(&ContributionList(views), cli.output)
+        }
+        command => {
+            let signer = load_keypair(cli.keypair)?;
+            render(&execute(&rpc, &signer, command)?, cli.output)
+        }
+    }
+}
+
+fn execute(rpc: &RpcClient, signer: &Keypair, command: Command) -> Result<Sent> {
+    let me = signer.pubkey();
+    match command {
+        Command::Init => send(rpc, signer, ix::initialize(&me), None),
+        Command::InitConfig => send(rpc, signer, ix::initialize_platform_config(&me), None),
+        Command::Create(args) => {
+            let campaign_id = query::next_campaign_id(rpc)?;
+            let asset = match args.mint {
+                Some(mint) => Asset::Token {
+                    mint,
+                    token_program: rpc
+                        .account(&mint)?
+                        .ok_or(CliError::AccountNotFound(mint))?
+                        .owner,
+                },
+                None => Asset::Sol,
+            };
+            let campaign_args = CampaignArgs {
+                title: args.title,
+                short_description: args.description,
+                category: args.category.into(),
+                cover_image_url: args.cover_image_url,
+                story_url: args.story_url,
+                funding_goal: args.funding_goal,
+                duration_days: args.duration_days,
+                vote_quorum_bps: args.vote_quorum_bps,
+                vote_approval_bps: args.vote_approval_bps,
+                allow_early_close: args.allow_early_close,
+                transferable_receipts: args.transferable_receipts,
+                unpledge_cutoff_hours: args.unpledge_cutoff_hours,
+            };
+            let instruction = ix::create_campaign(&me, campaign_id, asset, campaign_args);
+            send(
+                rpc,
+                signer,
+                instruction,
+                Some(pda::campaign(&me, campaign_id)),
+            )
+        }
+        Command::Update(args) => {
+            let (current, keys) = query::campaign_keys(rpc, &args.campaign)?;
+            let instruction = ix::update_campaign_metadata(
+                &keys,
+                &args.title.unwrap_or(current.title),
+                &args.description.unwrap_or(current.short_description),
+                &args.cover_image_url.unwrap_or(current.cover_image_url),
+                &args.story_url.unwrap_or(current.story_url),
+            );
+            send(rpc, signer, instruction, None)
+        }
+        Command::Launch { campaign } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            send(rpc, signer, ix::launch_campaign(&keys), None)
+        }
+        Command::Fund {
+            campaign,
+            amount,
+            tier,
+        } => {
+            let (state, keys) = query::campaign_keys(rpc, &campaign)?;
+            // Locked stretch goals come along so the pledge can unlock them
+            let locked = state.unlocked_stretch_goals..state.stretch_goal_count;
+            send(
+                rpc,
+                signer,
+                ix::fund_campaign(&keys, &me, amount, tier, locked),
+                None,
+            )
+        }
+        Command::Unpledge { campaign, amount } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            // A held reward tier has to come along to be released
+            let tier = query::contribution(rpc, &campaign, &me)?.reward_tier;
+            send(rpc, signer, ix::unpledge(&keys, &me, amount, tier), None)
+        }
+        Command::Finalize { campaign } => {
+            let state = query::campaign(rpc, &campaign)?;
+            // Locked stretch goals unlock if crossed, and milestones gated on
+            // one still locked hand their share on
+            let (milestones, stretch_goals) =
+                if state.unlocked_stretch_goals < state.stretch_goal_count {
+                    (
+                        0..state.milestone_count,
+                        state.unlocked_stretch_goals..state.stretch_goal_count,
+                    )
+                } else {
+                    (0..0, 0..0)
+                };
+            send(
+                rpc,
+                signer,
+                ix::finalize_campaign(&campaign, milestones, stretch_goals),
+                None,
+            )
+        }
+        Command::Close { campaign } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            send(rpc, signer, ix::close_campaign(&keys), None)
+        }
+        Command::Withdraw { campaign } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            let treasury = query::platform_config(rpc)?.treasury;
+            send(rpc, signer, ix::withdraw_funds(&keys, &treasury), None)
+        }
+        Command::Refund { campaign, partial } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            let instruction = if partial {
+                ix::claim_partial_refund(&keys, &me)
+            } else {
+                ix::claim_refund(&keys, &me)
+            };
+            send(rpc, signer, instruction, None)
+        }
+        Command::Milestone(command) => execute_milestone(rpc, signer, command),
+        Command::Reward(command) => execute_reward(rpc, signer, command),
+        Command::Stretch(command) => execute_stretch(rpc, signer, command),
+        Command::Reclaim(command) => execute_reclaim(rpc, signer, command),
+        Command::Migrate { account } => send(rpc, signer, ix::migrate_account(&account, &me), None),
+        Command::Campaigns { .. } | Command::Inspect { .. } | Command::Contributions { .. } => {
+            unreachable!("read-only commands do not send transactions")
+        }
+    }
+}
+
+fn execute_milestone(rpc: &RpcClient, signer: &Keypair, command: MilestoneCommand) -> Result<Sent> {
+    match command {
+        MilestoneCommand::Add {
+            campaign,
+            title,
+            target_amount,
+            payout_bps,
+            stretch_goal,
+        } => {
+            let (state, keys) = query::campaign_keys(rpc, &campaign)?;
+            let index = state.milestone_count;
+            let instruction = ix::add_milestone(
+                &keys,
+                index,
+                &title,
+                target_amount,
+                payout_bps,
+                stretch_goal,
+            );
+            send(
+                rpc,
+                signer,
+                instruction,
+                Some(pda::milestone(&campaign, index)),
+            )
+        }
+        MilestoneCommand::SubmitProof {
+            campaign,
+            index,
+            proof_url,
+        } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            let instruction = ix::submit_milestone_proof(&keys, index, &proof_url);
+            send(rpc, signer, instruction, None)
+        }
+        MilestoneCommand::Vote {
+            campaign,
+            index,
+            vote,
+        } => {
+            let approve = matches!(vote, Vote::Approve);
+            let instruction = ix::vote_milestone(&campaign, index, &signer.pubkey(), approve);
+            send(rpc, signer, instruction, None)
+        }
+        MilestoneCommand::Complete { campaign, index } => {
+            send(rpc, signer, ix::complete_milestone(&campaign, index), None)
+        }
+        MilestoneCommand::Release { campaign, index } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            let treasury = query::platform_config(rpc)?.treasury;
+            send(
+                rpc,
+                signer,
+                ix::release_milestone(&keys, index, &treasury),
+                None,
+            )
+        }
+    }
+}
+
+fn execute_reward(rpc: &RpcClient, signer: &Keypair, command: RewardCommand) -> Result<Sent> {
+    match command {
+        RewardCommand::Add {
+            campaign,
+            min_pledge,
+            max_supply,
+            metadata_url,
+        } => {
+            let (state, keys) = query::campaign_keys(rpc, &campaign)?;
+            let index = state.reward_tier_count;
+            let instruction =
+                ix::add_reward_tier(&keys, index, min_pledge, max_supply, &metadata_url);
+            send(
+                rpc,
+                signer,
+                instruction,
+                Some(pda::reward_tier(&campaign, index)),
+            )
+        }
+    }
+}
+
+fn execute_stretch(rpc: &RpcClient, signer: &Keypair, command: StretchCommand) -> Result<Sent> {
+    match command {
+        StretchCommand::Add {
+            campaign,
+            threshold,
+            metadata_url,
+        } => {
+            let (state, keys) = query::campaign_keys(rpc, &campaign)?;
+            let index = state.stretch_goal_count;
+            let instruction = ix::add_stretch_goal(&keys, index, threshold, &metadata_url);
+            send(
+                rpc,
+                signer,
+                instruction,
+                Some(pda::stretch_goal(&campaign, index)),
+            )
+        }
+    }
+}
+
+fn execute_reclaim(rpc: &RpcClient, signer: &Keypair, command: ReclaimCommand) -> Result<Sent> {
+    match command {
+        ReclaimCommand::Contribution {
+            campaign,
+            contributor,
+        } => {
+            let contributor = contributor.unwrap_or_else(|| signer.pubkey());
+            let instruction = ix::close_contribution(&campaign, &contributor);
+            send(rpc, signer, instruction, None)
+        }
+        ReclaimCommand::Milestone { campaign, index } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            send(rpc, signer, ix::close_milestone(&keys, index), None)
+        }
+        ReclaimCommand::MilestoneVote {
+            campaign,
+            index,
+            voter,
+        } => {
+            let voter = voter.unwrap_or_else(|| signer.pubkey());
+            let instruction = ix::close_milestone_vote(&campaign, index, &voter);
+            send(rpc, signer, instruction, None)
+        }
+        ReclaimCommand::RewardTier { campaign, index } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            send(rpc, signer, ix::close_reward_tier(&keys, index), None)
+        }
+        ReclaimCommand::StretchGoal { campaign, index } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            send(rpc, signer, ix::close_stretch_goal(&keys, index), None)
+        }
+        ReclaimCommand::Campaign { campaign } => {
+            let (_, keys) = query::campaign_keys(rpc, &campaign)?;
+            send(rpc, signer, ix::close_campaign_accounts(&keys), None)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use anchor_lang::prelude::Pubkey;
+use clap::ValueEnum;
+use hope_rise::state::{Campaign, Contribution, FundingAsset, Milestone};
+use serde::Serialize;
+
+use crate::error::Result;
+
+/// How command results are printed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for people
+    Table,
+    /// Pretty printed JSON for scripts
+    Json,
+}
+
+/// A command result printable in either format
+pub trait Render: Serialize {
+    fn table(&self) -> String;
+}
+
+pub fn render(value: &impl Render, format: OutputFormat) -> Result<String> {
+    Ok(match format {
+        OutputFormat::Table => value.table(),
+        OutputFormat::Json => serde_json::to_string_pretty(value)?,
+    })
+}
+
+/// Left aligned columns separated by two spaces
+fn table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|header| header.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:width$}"))
+            .collect();
+        padded.join("  ").trim_end().to_string()
+    };
+    let mut lines = vec![line(headers.to_vec())];
+    lines.extend(
+        rows.iter()
+            .map(|row| line(row.iter().map(String::as_str).collect())),
+    );
+    lines.join("\n")
+}
+
+/// `label: value` lines with the values aligned
+fn fields(pairs: &[(&str, String)]) -> String {
+    let width = pairs
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    pairs
+        .iter()
+        .map(|(label, value)| format!("{:width$} {value}", format!("{label}:")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn optional(value: Option<impl ToString>) -> String {
+    value.map_or_else(|| "-".to_string(), |value| value.to_string())
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignView {
+    pub address: String,
+    pub campaign_id: u64,
+    pub creator: String,
+    pub title: String,
+    pub short_description: String,
+    pub category: String,
+    pub cover_image_url: String,
+    pub story_url: String,
+    pub status: String,
+    pub asset: String,
+    /// `None` for SOL campaigns
+    pub mint: Option<String>,
+    pub funding_goal: u64,
+    pub amount_raised: u64,
+    pub backer_count: u64,
+    pub released_amount: u64,
+    pub total_refunded: u64,
+    pub created_at: i64,
+    pub deadline: i64,
+    pub finalized_at: Option<i64>,
+    pub milestone_count: u8,
+    pub allocated_bps: u16,
+    pub released_bps: u16,
+    pub vote_quorum_bps: u16,
+    pub vote_approval_bps: u16,
+    pub failed_milestone: Option<u8>,
+    pub fee_bps: u16,
+    pub moderation_reason: u16,
+    pub allow_early_close: bool,
+    pub transferable_receipts: bool,
+    /// Seconds before the deadline after which pledges stay in
+    pub unpledge_cutoff: i64,
+    pub stretch_goal_count: u8,
+    pub unlocked_stretch_goals: u8,
+}
+
+impl CampaignView {
+    pub fn new(address: &Pubkey, campaign: &Campaign) -> Self {
+        Self {
+            address: address.to_string(),
+            campaign_id: campaign.campaign_id,
+            creator: campaign.creator.to_string(),
+            title: campaign.title.clone(),
+            short_description: campaign.short_description.clone(),
+            category: format!("{:?}", campaign.category),
+            cover_image_url: campaign.cover_image_url.clone(),
+            story_url: campaign.story_url.clone(),
+            status: format!("{:?}", campaign.status),
+            asset: format!("{:?}", campaign.asset),
+            mint: (campaign.asset == FundingAsset::Token).then(|| campaign.mint.to_string()),
+            funding_goal: campaign.funding_goal,
+            amount_raised: campaign.amount_raised,
+            backer_count: campaign.backer_count,
+            released_amount: campaign.released_amount,
+            total_refunded: campaign.total_refunded,
+            created_at: campaign.created_at,
+            deadline: campaign.deadline,
+            finalized_at: (campaign.finalized_at != 0).then_some(campaign.finalized_at),
+            milestone_count: campaign.milestone_count,
+            allocated_bps: campaign.allocated_bps,
+            released_bps: campaign.released_bps,
+            vote_quorum_bps: campaign.vote_quorum_bps,
+            vote_approval_bps: campaign.vote_approval_bps,
+            failed_milestone: campaign.failed_milestone,
+            fee_bps: campaign.fee_bps,
+            moderation_reason: campaign.moderation_reason,
+            allow_early_close: campaign.allow_early_close,
+            transferable_receipts: campaign.transferable_receipts,
+            unpledge_cutoff: campaign.unpledge_cutoff,
+            stretch_goal_count: campaign.stretch_goal_count,
+            unlocked_stretch_goals: campaign.unlocked_stretch_goals,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct CampaignList(pub Vec<CampaignView>);
+
+impl Render for CampaignList {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .0
+            .iter()
+            .map(|campaign| {
+                vec![
+                    campaign.address.clone(),
+                    campaign.campaign_id.to_string(),
+                    campaign.title.clone(),
+                    campaign.status.clone(),
+                    campaign.asset.clone(),
+                    campaign.amount_raised.to_string(),
+                    campaign.funding_goal.to_string(),
+                    campaign.backer_count.to_string(),
+                    campaign.deadline.to_string(),
+                ]
+            })
+            .collect();
+        table(
+            &[
+                "ADDRESS", "ID", "TITLE", "STATUS", "ASSET", "RAISED", "GOAL", "BACKERS",
+                "DEADLINE",
+            ],
+            &rows,
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneView {
+    pub address: String,
+    pub index: u8,
+    pub title: String,
+    pub target_amount: u64,
+    pub payout_bps: u16,
+    pub proof_url: String,
+    /// `None` until a proof opened the vote
+    pub voting_ends_at: Option<i64>,
+    pub yes_weight: u64,
+    pub no_weight: u64,
+    pub completed: bool,
+    pub released: bool,
+    /// Stretch goal that has to unlock before proof is accepted
+    pub stretch_goal: Option<u8>,
+}
+
+impl MilestoneView {
+    pub fn new(address: &Pubkey, milestone: &Milestone) -> Self {
+        Self {
+            address: address.to_string(),
+            index: milestone.milestone_index,
+            title: milestone.title.clone(),
+            target_amount: milestone.target_amount,
+            payout_bps: milestone.payout_bps,
+            proof_url: milestone.proof_url.clone(),
+            voting_ends_at: (milestone.voting_ends_at != 0).then_some(milestone.voting_ends_at),
+            yes_weight: milestone.yes_weight,
+            no_weight: milestone.no_weight,
+            completed: milestone.is_completed,
+            released: milestone.is_released,
+            stretch_goal: milestone.stretch_goal,
+        }
+    }
+}
+
+/// A campaign with its milestones, for `inspect`
+#[derive(Debug, Serialize)]
+pub struct CampaignDetails {
+    pub campaign: CampaignView,
+    pub milestones: Vec<MilestoneView>,
+}
+
+impl Render for CampaignDetails {
+    fn table(&self) -> String {
+        let c = &self.campaign;
+        let mut out = fields(&[
+            ("Address", c.address.clone()),
+            ("Campaign id", c.campaign_id.to_string()),
+            ("Creator", c.creator.clone()),
+            ("Title", c.title.clone()),
+            ("Description", c.short_description.clone()),
+            ("Category", c.category.clone()),
+            ("Cover image", c.cover_image_url.clone()),
+            ("Story", c.story_url.clone()),
+            ("Status", c.status.clone()),
+            ("Asset", c.asset.clone()),
+            ("Mint", optional(c.mint.as_ref())),
+            ("Funding goal", c.funding_goal.to_string()),
+            ("Amount raised", c.amount_raised.to_string()),
+            ("Backers", c.backer_count.to_string()),
+            ("Released", c.released_amount.to_string()),
+            ("Refunded", c.total_refunded.to_string()),
+            ("Created at", c.created_at.to_string()),
+            ("Deadline", c.deadline.to_string()),
+            ("Finalized at", optional(c.finalized_at)),
+            ("Fee (bps)", c.fee_bps.to_string()),
+            ("Vote quorum (bps)", c.vote_quorum_bps.to_string()),
+            ("Vote approval (bps)", c.vote_approval_bps.to_string()),
+            ("Early close", c.allow_early_close.to_string()),
+            ("Receipt transfers", c.transferable_receipts.to_string()),
+            ("Unpledge cutoff (s)", c.unpledge_cutoff.to_string()),
+            (
+                "Stretch goals",
+                format!(
+                    "{}/{} unlocked",
+                    c.unlocked_stretch_goals, c.stretch_goal_count
+                ),
+            ),
+            ("Failed milestone", optional(c.failed_milestone)),
+            ("Moderation reason", c.moderation_reason.to_string()),
+        ]);
+
+        if !self.milestones.is_empty() {
+            let rows: Vec<Vec<String>> = self
+                .milestones
+                .iter()
+                .map(|milestone| {
+                    vec![
+                        milestone.index.to_string(),
+                        milestone.title.clone(),
+                        milestone.payout_bps.to_string(),
+                        milestone.target_amount.to_string(),
+                        optional(milestone.stretch_goal),
+                        optional(milestone.voting_ends_at),
+                        milestone.yes_weight.to_string(),
+                        milestone.no_weight.to_string(),
+                        milestone.completed.to_string(),
+                        milestone.released.to_string(),
+                    ]
+                })
+                .collect();
+            out.push_str("\n\n");
+            out.push_str(&table(
+                &[
+                    "#",
+                    "MILESTONE",
+                    "BPS",
+                    "TARGET",
+                    "STRETCH GOAL",
+                    "VOTE ENDS",
+                    "YES",
+                    "NO",
+                    "COMPLETED",
+                    "RELEASED",
+                ],
+                &rows,
+            ));
+        }
+        out
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionView {
+    pub address: String,
+    pub campaign: String,
+    pub contributor: String,
+    pub amount: u64,
+    pub contributed_at: i64,
+    pub refund_claimed: bool,
+}
+
+impl ContributionView {
+    pub fn new(address: &Pubkey, contribution: &Contribution) -> Self {
+        Self {
+            address: address.to_string(),
+            campaign: contribution.campaign.to_string(),
+            contributor: contribution.contributor.to_string(),
+            amount: contribution.amount,
+            contributed_at: contribution.contributed_at,
+            refund_claimed: contribution.refund_claimed,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct ContributionList(pub Vec<ContributionView>);
+
+impl Render for ContributionList {
+    fn table(&self) -> String {
+        let rows: Vec<Vec<String>> = self
+            .0
+            .iter()
+            .map(|contribution| {
+                vec![
+                    contribution.address.clone(),
+                    contribution.campaign.clone(),
+                    contribution.contributor.clone(),
+                    contribution.amount.to_string(),
+                    contribution.contributed_at.to_string(),
+                    contribution.refund_claimed.to_string(),
+                ]
+            })
+            .collect();
+        table(
+            &[
+                "ADDRESS",
+                "CAMPAIGN",
+                "CONTRIBUTOR",
+                "AMOUNT",
+                "CONTRIBUTED AT",
+                "REFUNDED",
+            ],
+            &rows,
+        )
+    }
+}
+
+/// A confirmed transaction and the account it created, if any
+#[derive(Debug, Serialize)]
+pub struct Sent {
+    pub signature: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+}
+
+impl Render for Sent {
+    fn table(&self) -> String {
+        let mut pairs = vec![("Signature", self.signature.clone())];
+        if let Some(account) = &self.account {
+            pairs.push(("Account", account.clone()));
+        }
+        fields(&pairs)
+    }
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use hope_rise::state::{
+    Campaign, CampaignCounter, Contribution, FundingAsset, Milestone, PlatformConfig,
+};
+use hope_rise_client::{account, pda, CampaignKeys};
+
+use crate::error::{CliError, Result};
+use crate::rpc::{Memcmp, RpcClient};
+
+/// Fetches and decodes the `T` account at `address`
+fn fetch<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+    expected: &'static str,
+) -> Result<T> {
+    let raw = rpc
+        .account(address)?
+        .ok_or(CliError::AccountNotFound(*address))?;
+    if raw.owner != hope_rise::ID {
+        return Err(CliError::InvalidAccount {
+            address: *address,
+            expected,
+        });
+    }
+    account::decode(&raw.data).map_err(|_| CliError::InvalidAccount {
+        address: *address,
+        expected,
+    })
+}
+
+/// Every `T` account of the program matching `filters`
+fn list<T: AccountDeserialize + Discriminator>(
+    rpc: &RpcClient,
+    expected: &'static str,
+    mut filters: Vec<Memcmp>,
+) -> Result<Vec<(Pubkey, T)>> {
+    filters.insert(
+        0,
+        Memcmp {
+            offset: 0,
+            bytes: T::DISCRIMINATOR.to_vec(),
+        },
+    );
+    rpc.program_accounts(&hope_rise::ID, &filters)?
+        .into_iter()
+        .map(|(address, raw)| {
+            let state = account::decode(&raw.data)
+                .map_err(|_| CliError::InvalidAccount { address, expected })?;
+            Ok((address, state))
+        })
+        .collect()
+}
+
+pub fn platform_config(rpc: &RpcClient) -> Result<PlatformConfig> {
+    fetch(rpc, &pda::platform_config(), "platform config")
+}
+
+/// Id the next created campaign gets
+pub fn next_campaign_id(rpc: &RpcClient) -> Result<u64> {
+    let counter: CampaignCounter = fetch(rpc, &pda::campaign_counter(), "campaign counter")?;
+    Ok(counter.count)
+}
+
+pub fn campaign(rpc: &RpcClient, address: &Pubkey) -> Result<Campaign> {
+    fetch(rpc, address, "campaign")
+}
+
+pub fn contribution(
+    rpc: &RpcClient,
+    campaign: &Pubkey,
+    contributor: &Pubkey,
+) -> Result<Contribution> {
+    fetch(
+        rpc,
+        &pda::contribution(campaign, contributor),
+        "contribution",
+    )
+}
+
+/// A campaign with the keys its instructions need, looking up the token
+/// program that owns a token campaign's mint
+pub fn campaign_keys(rpc: &RpcClient, address: &Pubkey) -> Result<(Campaign, CampaignKeys)> {
+    let campaign = campaign(rpc, address)?;
+    let token_program = match campaign.asset {
+        FundingAsset::Token => {
+            rpc.account(&campaign.mint)?
+                .ok_or(CliError::AccountNotFound(campaign.mint))?
+                .owner
+        }
+        FundingAsset::Sol => Pubkey::default(),
+    };
+    let keys = CampaignKeys::from_account(address, &campaign, &token_program);
+    Ok((campaign, keys))
+}
+
+/// Campaigns, optionally of one creator, oldest first
+pub fn campaigns(rpc: &RpcClient, creator: Option<&Pubkey>) -> Result<Vec<(Pubkey, Campaign)>> {
+    let filters = creator
+        .map(|creator| Memcmp {
+            offset: account::CAMPAIGN_CREATOR_OFFSET,
+            bytes: creator.to_bytes().to_vec(),
+        })
+        .into_iter()
+        .collect();
+    let mut campaigns: Vec<(Pubkey, Campaign)> = list(rpc, "campaign", filters)?;
+    campaigns.sort_by_key(|(address, campaign)| (campaign.created_at, *address));
+    Ok(campaigns)
+}
+
+/// Milestones of a campaign in index order
+pub fn milestones(rpc: &RpcClient, campaign: &Pubkey) -> Result<Vec<(Pubkey, Milestone)>> {
+    let filters = vec![Memcmp {
+        offset: account::MILESTONE_CAMPAIGN_OFFSET,
+        bytes: campaign.to_bytes().to_vec(),
+    }];
+    let mut milestones: Vec<(Pubkey, Milestone)> = list(rpc, "milestone", filters)?;
+    milestones.sort_by_key(|(_, milestone)| milestone.milestone_index);
+    Ok(milestones)
+}
+
+/// Contributions to a campaign and/or by a contributor, oldest first
+pub fn contributions(
+    rpc: &RpcClient,
+    campaign: Option<&Pubkey>,
+    contributor: Option<&Pubkey>,
+) -> Result<Vec<(Pubkey, Contribution)>> {
+    let mut filters = Vec::new();
+    if let Some(campaign) = campaign {
+        filters.push(Memcmp {
+            offset: account::CONTRIBUTION_CAMPAIGN_OFFSET,
+            bytes: campaign.to_bytes().to_vec(),
+        });
+    }
+    if let Some(contributor) = contributor {
+        filters.push(Memcmp {
+            offset: account::CONTRIBUTION_CONTRIBUTOR_OFFSET,
+            bytes: contributor.to_bytes().to_vec(),
+        });
+    }
+    let mut contributions: Vec<(Pubkey, Contribution)> = list(rpc, "contribution", filters)?;
+    contributions.sort_by_key(|(address, contribution)| (contribution.contributed_at, *address));
+    Ok(contributions)
+}
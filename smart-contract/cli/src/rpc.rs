@@ -0,0 +1,214 @@
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use serde_json::{json, Value};
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+
+use crate::error::{CliError, Result};
+
+/// How long to wait for a sent transaction to be confirmed
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Pause between signature status polls
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A raw account as returned by `getAccountInfo`
+#[derive(Debug, Clone)]
+pub struct RawAccount {
+    pub owner: Pubkey,
+    pub lamports: u64,
+    pub data: Vec<u8>,
+}
+
+/// Filter on a byte range of program account data, see `getProgramAccounts`
+pub struct Memcmp {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+/// Minimal JSON RPC client covering the calls the CLI makes, all at
+/// `confirmed` commitment
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let mut response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|err| CliError::Rpc(err.to_string()))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            let message = error["message"].as_str().unwrap_or_default();
+            let logs = error["data"]["logs"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+                .join("\n  ");
+            return Err(CliError::Rpc(if logs.is_empty() {
+                format!("{method}: {message}")
+            } else {
+                format!("{method}: {message}\n  {logs}")
+            }));
+        }
+        Ok(response["result"].take())
+    }
+
+    pub fn account(&self, address: &Pubkey) -> Result<Option<RawAccount>> {
+        let params = json!([
+            address.to_string(),
+            { "encoding": "base64", "commitment": "confirmed" },
+        ]);
+        let result = self.call("getAccountInfo", params)?;
+        match &result["value"] {
+            Value::Null => Ok(None),
+            value => raw_account(value).map(Some),
+        }
+    }
+
+    /// Accounts owned by `program_id` matching every filter
+    pub fn program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[Memcmp],
+    ) -> Result<Vec<(Pubkey, RawAccount)>> {
+        let filters: Vec<Value> = filters
+            .iter()
+            .map(|filter| {
+                json!({
+                    "memcmp": {
+                        "offset": filter.offset,
+                        "bytes": bs58::encode(&filter.bytes).into_string(),
+                    }
+                })
+            })
+            .collect();
+        let params = json!([
+            program_id.to_string(),
+            { "encoding": "base64", "commitment": "confirmed", "filters": filters },
+        ]);
+        let result = self.call("getProgramAccounts", params)?;
+
+        result
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|entry| {
+                let address = entry["pubkey"]
+                    .as_str()
+                    .and_then(|key| Pubkey::from_str(key).ok())
+                    .ok_or_else(|| CliError::Rpc(format!("invalid program account {entry}")))?;
+                Ok((address, raw_account(&entry["account"])?))
+            })
+            .collect()
+    }
+
+    pub fn latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        result["value"]["blockhash"]
+            .as_str()
+            .and_then(|hash| Hash::from_str(hash).ok())
+            .ok_or_else(|| CliError::Rpc(format!("invalid blockhash in {result}")))
+    }
+
+    /// Signs `instructions` with `payer` and `signers`, sends them in one
+    /// transaction and waits until it is confirmed
+    pub fn send(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+        signers: &[&Keypair],
+    ) -> Result<Signature> {
+        let mut keypairs = vec![payer];
+        keypairs.extend(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &keypairs,
+            self.latest_blockhash()?,
+        );
+        let wire = bincode::serialize(&transaction)
+            .map_err(|err| CliError::Rpc(format!("could not serialize transaction: {err}")))?;
+
+        let params = json!([
+            BASE64_STANDARD.encode(wire),
+            { "encoding": "base64", "preflightCommitment": "confirmed" },
+        ]);
+        self.call("sendTransaction", params)?;
+
+        let signature = transaction.signatures[0];
+        self.confirm(&signature)?;
+        Ok(signature)
+    }
+
+    fn confirm(&self, signature: &Signature) -> Result<()> {
+        let started = Instant::now();
+        while started.elapsed() < CONFIRMATION_TIMEOUT {
+            let params = json!([[signature.to_string()]]);
+            let result = self.call("getSignatureStatuses", params)?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(CliError::TransactionFailed {
+                        signature: signature.to_string(),
+                        reason: status["err"].to_string(),
+                    });
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed" | "finalized")
+                ) {
+                    return Ok(());
+                }
+            }
+            thread::sleep(CONFIRMATION_POLL_INTERVAL);
+        }
+        Err(CliError::TransactionFailed {
+            signature: signature.to_string(),
+            reason: "not confirmed in time".to_string(),
+        })
+    }
+}
+
+fn raw_account(value: &Value) -> Result<RawAccount> {
+    let invalid = || CliError::Rpc(format!("invalid account in response: {value}"));
+    let owner = value["owner"]
+        .as_str()
+        .and_then(|owner| Pubkey::from_str(owner).ok())
+        .ok_or_else(invalid)?;
+    let data = value["data"][0]
+        .as_str()
+        .and_then(|data| BASE64_STANDARD.decode(data).ok())
+        .ok_or_else(invalid)?;
+
+    Ok(RawAccount {
+        owner,
+        lamports: value["lamports"].as_u64().unwrap_or_default(),
+        data,
+    })
+}
@@ -0,0 +1,520 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountSerialize;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use hope_rise::state::{
+    Campaign, CampaignCounter, CampaignStatus, Category, Contribution, FundingAsset, Milestone,
+};
+use hope_rise_cli::output::{
+    render, CampaignDetails, CampaignList, CampaignView, ContributionList, ContributionView,
+    MilestoneView, Sent,
+};
+use hope_rise_cli::{query, CliError, OutputFormat, RpcClient};
+use hope_rise_client::{instructions as ix, pda, Asset};
+use serde_json::{json, Value};
+use solana_sdk::hash::Hash;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+/// How the mock cluster reports a sent transaction
+#[derive(Clone, Copy)]
+enum Outcome {
+    Confirmed,
+    Failed,
+    Rejected,
+}
+
+struct Cluster {
+    accounts: BTreeMap<Pubkey, (Pubkey, Vec<u8>)>,
+    sent: Vec<Transaction>,
+    outcome: Outcome,
+}
+
+/// A JSON RPC server answering the calls `RpcClient` makes from in-memory
+/// accounts
+struct MockCluster {
+    url: String,
+    state: Arc<Mutex<Cluster>>,
+}
+
+impl MockCluster {
+    fn start() -> Self {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let state = Arc::new(Mutex::new(Cluster {
+            accounts: BTreeMap::new(),
+            sent: Vec::new(),
+            outcome: Outcome::Confirmed,
+        }));
+
+        let shared = state.clone();
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                request.as_reader().read_to_string(&mut body).unwrap();
+                let request_json: Value = serde_json::from_str(&body).unwrap();
+                let response = answer(&mut shared.lock().unwrap(), &request_json);
+                request
+                    .respond(tiny_http::Response::from_string(response.to_string()))
+                    .unwrap();
+            }
+        });
+        Self { url, state }
+    }
+
+    fn rpc(&self) -> RpcClient {
+        RpcClient::new(&self.url)
+    }
+
+    fn set_account(&self, address: Pubkey, owner: Pubkey, data: Vec<u8>) {
+        let mut state = self.state.lock().unwrap();
+        state.accounts.insert(address, (owner, data));
+    }
+
+    fn set_program_account(&self, address: Pubkey, account: &impl AccountSerialize) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        self.set_account(address, hope_rise::ID, data);
+    }
+
+    fn set_outcome(&self, outcome: Outcome) {
+        self.state.lock().unwrap().outcome = outcome;
+    }
+
+    fn sent(&self) -> Vec<Transaction> {
+        self.state.lock().unwrap().sent.clone()
+    }
+}
+
+fn encode(owner: &Pubkey, data: &[u8]) -> Value {
+    json!({
+        "owner": owner.to_string(),
+        "lamports": 1_000_000,
+        "data": [BASE64_STANDARD.encode(data), "base64"],
+        "executable": false,
+    })
+}
+
+fn answer(cluster: &mut Cluster, request: &Value) -> Value {
+    let params = &request["params"];
+    let result = match request["method"].as_str().unwrap() {
+        "getAccountInfo" => {
+            let address = Pubkey::from_str(params[0].as_str().unwrap()).unwrap();
+            let value = cluster
+                .accounts
+                .get(&address)
+                .map_or(Value::Null, |(owner, data)| encode(owner, data));
+            json!({ "context": { "slot": 1 }, "value": value })
+        }
+        "getProgramAccounts" => {
+            let program = Pubkey::from_str(params[0].as_str().unwrap()).unwrap();
+            let filters: Vec<(usize, Vec<u8>)> = params[1]["filters"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|filter| {
+                    let memcmp = &filter["memcmp"];
+                    let bytes = bs58::decode(memcmp["bytes"].as_str().unwrap())
+                        .into_vec()
+                        .unwrap();
+                    (memcmp["offset"].as_u64().unwrap() as usize, bytes)
+                })
+                .collect();
+            let matches: Vec<Value> = cluster
+                .accounts
+                .iter()
+                .filter(|(_, (owner, data))| {
+                    *owner == program
+                        && filters.iter().all(|(offset, bytes)| {
+                            data.get(*offset..offset + bytes.len()) == Some(bytes.as_slice())
+                        })
+                })
+                .map(|(address, (owner, data))| {
+                    json!({ "pubkey": address.to_string(), "account": encode(owner, data) })
+                })
+                .collect();
+            json!(matches)
+        }
+        "getLatestBlockhash" => json!({
+            "context": { "slot": 1 },
+            "value": { "blockhash": Hash::new_unique().to_string(), "lastValidBlockHeight": 100 },
+        }),
+        "sendTransaction" => {
+            if let Outcome::Rejected = cluster.outcome {
+                return json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "error": {
+                        "code": -32002,
+                        "message": "Transaction simulation failed",
+                        "data": { "logs": ["Program log: AnchorError: CampaignNotActive"] },
+                    },
+                });
+            }
+            let wire = BASE64_STANDARD.decode(params[0].as_str().unwrap()).unwrap();
+            let transaction: Transaction = bincode::deserialize(&wire).unwrap();
+            let signature = transaction.signatures[0].to_string();
+            cluster.sent.push(transaction);
+            json!(signature)
+        }
+        "getSignatureStatuses" => {
+            let err = match cluster.outcome {
+                Outcome::Failed => json!({ "InstructionError": [0, { "Custom": 6003 }] }),
+                _ => Value::Null,
+            };
+            json!({
+                "context": { "slot": 1 },
+                "value": [{ "slot": 1, "err": err, "confirmationStatus": "confirmed" }],
+            })
+        }
+        method => panic!("unexpected RPC method {method}"),
+    };
+    json!({ "jsonrpc": "2.0", "id": 1, "result": result })
+}
+
+fn campaign_state(
+    creator: Pubkey,
+    campaign_id: u64,
+    asset: FundingAsset,
+    mint: Pubkey,
+) -> Campaign {
+    Campaign {
+        campaign_id,
+        creator,
+        title: format!("Campaign {campaign_id}"),
+        short_description: "Wells for the village".to_string(),
+        category: Category::Community,
+        cover_image_url: "ipfs://cover".to_string(),
+        story_url: "ipfs://story".to_string(),
+        funding_goal: 1_000,
+        deadline: 1_700_000_000 + campaign_id as i64,
+        amount_raised: 400,
+        backer_count: 2,
+        status: CampaignStatus::Active,
+        created_at: 1_699_000_000 + campaign_id as i64,
+        milestone_count: 0,
+        bump: 255,
+        released_amount: 0,
+        allocated_bps: 0,
+        released_bps: 0,
+        vote_quorum_bps: 0,
+        vote_approval_bps: 5_000,
+        failed_milestone: None,
+        mint,
+        asset,
+        fee_bps: 250,
+        moderation_reason: 0,
+        allow_early_close: false,
+        finalized_at: 0,
+        open_contributions: 0,
+        open_milestones: 0,
+        version: Campaign::VERSION,
+        reward_tier_count: 0,
+        open_reward_tiers: 0,
+        transferable_receipts: false,
+        unpledge_cutoff: 172_800,
+        total_refunded: 0,
+        stretch_goal_count: 0,
+        open_stretch_goals: 0,
+        unlocked_stretch_goals: 0,
+        suspended_from: None,
+    }
+}
+
+fn contribution_state(campaign: Pubkey, contributor: Pubkey, amount: u64) -> Contribution {
+    Contribution {
+        campaign,
+        contributor,
+        amount,
+        contributed_at: 1_699_500_000 + amount as i64,
+        refund_claimed: false,
+        bump: 254,
+        version: Contribution::VERSION,
+        reward_tier: None,
+        has_receipt: true,
+    }
+}
+
+#[test]
+fn lists_campaigns_and_contributions_with_filters() {
+    let cluster = MockCluster::start();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let first = pda::campaign(&alice, 0);
+    let second = pda::campaign(&bob, 1);
+    // Inserted newest first, listed oldest first
+    cluster.set_program_account(
+        second,
+        &campaign_state(bob, 1, FundingAsset::Sol, Pubkey::default()),
+    );
+    cluster.set_program_account(
+        first,
+        &campaign_state(alice, 0, FundingAsset::Sol, Pubkey::default()),
+    );
+    cluster.set_program_account(
+        pda::campaign_counter(),
+        &CampaignCounter {
+            count: 2,
+            authority: alice,
+            bump: 1,
+        },
+    );
+    for (campaign, contributor, amount) in
+        [(first, bob, 300), (first, alice, 100), (second, bob, 50)]
+    {
+        cluster.set_program_account(
+            pda::contribution(&campaign, &contributor),
+            &contribution_state(campaign, contributor, amount),
+        );
+    }
+    let rpc = cluster.rpc();
+
+    let campaigns = query::campaigns(&rpc, None).unwrap();
+    let addresses: Vec<_> = campaigns.iter().map(|(address, _)| *address).collect();
+    assert_eq!(addresses, vec![first, second]);
+    let by_bob = query::campaigns(&rpc, Some(&bob)).unwrap();
+    assert_eq!(by_bob.len(), 1);
+    assert_eq!(by_bob[0].1.title, "Campaign 1");
+    assert_eq!(query::next_campaign_id(&rpc).unwrap(), 2);
+
+    let to_first = query::contributions(&rpc, Some(&first), None).unwrap();
+    let amounts: Vec<_> = to_first.iter().map(|(_, c)| c.amount).collect();
+    assert_eq!(amounts, vec![100, 300]);
+    let from_bob = query::contributions(&rpc, None, Some(&bob)).unwrap();
+    assert_eq!(from_bob.len(), 2);
+    let one = query::contributions(&rpc, Some(&second), Some(&bob)).unwrap();
+    assert_eq!(one.len(), 1);
+    assert_eq!(one[0].0, pda::contribution(&second, &bob));
+
+    let list = CampaignList(
+        campaigns
+            .iter()
+            .map(|(address, campaign)| CampaignView::new(address, campaign))
+            .collect(),
+    );
+    let table = render(&list, OutputFormat::Table).unwrap();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("ADDRESS"));
+    assert!(lines[1].starts_with(&first.to_string()));
+    assert!(lines[1].contains("Campaign 0  Active  Sol"));
+    assert_eq!(
+        lines[0].find("STATUS"),
+        lines[2].find("Active"),
+        "columns line up"
+    );
+
+    let json: Value = serde_json::from_str(&render(&list, OutputFormat::Json).unwrap()).unwrap();
+    assert_eq!(json[1]["address"], second.to_string());
+    assert_eq!(json[1]["creator"], bob.to_string());
+    assert_eq!(json[1]["amountRaised"], 400);
+    assert_eq!(json[1]["mint"], Value::Null);
+    assert_eq!(json[1]["finalizedAt"], Value::Null);
+
+    let contributions = ContributionList(
+        from_bob
+            .iter()
+            .map(|(address, contribution)| ContributionView::new(address, contribution))
+            .collect(),
+    );
+    let json: Value =
+        serde_json::from_str(&render(&contributions, OutputFormat::Json).unwrap()).unwrap();
+    assert_eq!(json[0]["amount"], 50);
+    assert_eq!(json[0]["refundClaimed"], false);
+}
+
+#[test]
+fn inspect_shows_a_campaign_with_its_milestones() {
+    let cluster = MockCluster::start();
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let campaign = pda::campaign(&creator, 0);
+    let mut state = campaign_state(creator, 0, FundingAsset::Token, mint);
+    state.milestone_count = 2;
+    state.stretch_goal_count = 1;
+    cluster.set_program_account(campaign, &state);
+    for index in [1u8, 0] {
+        let milestone = Milestone {
+            campaign,
+            milestone_index: index,
+            title: format!("Step {index}"),
+            target_amount: 500,
+            is_completed: index == 0,
+            bump: 250,
+            payout_bps: 5_000,
+            is_released: false,
+            proof_url: String::new(),
+            voting_ends_at: if index == 0 { 1_700_100_000 } else { 0 },
+            yes_weight: 400,
+            no_weight: 0,
+            version: Milestone::VERSION,
+            stretch_goal: (index == 1).then_some(0),
+        };
+        cluster.set_program_account(pda::milestone(&campaign, index), &milestone);
+    }
+    // Another campaign's milestone is not listed
+    cluster.set_program_account(
+        pda::milestone(&Pubkey::new_unique(), 0),
+        &Milestone {
+            campaign: Pubkey::new_unique(),
+            milestone_index: 0,
+            title: "Elsewhere".to_string(),
+            target_amount: 1,
+            is_completed: false,
+            bump: 250,
+            payout_bps: 1,
+            is_released: false,
+            proof_url: String::new(),
+            voting_ends_at: 0,
+            yes_weight: 0,
+            no_weight: 0,
+            version: Milestone::VERSION,
+            stretch_goal: None,
+        },
+    );
+    let rpc = cluster.rpc();
+
+    let milestones = query::milestones(&rpc, &campaign).unwrap();
+    let titles: Vec<_> = milestones.iter().map(|(_, m)| m.title.as_str()).collect();
+    assert_eq!(titles, vec!["Step 0", "Step 1"]);
+
+    let details = CampaignDetails {
+        campaign: CampaignView::new(&campaign, &query::campaign(&rpc, &campaign).unwrap()),
+        milestones: milestones
+            .iter()
+            .map(|(address, milestone)| MilestoneView::new(address, milestone))
+            .collect(),
+    };
+    let table = render(&details, OutputFormat::Table).unwrap();
+    assert!(table.contains(&format!("Mint:                {mint}")));
+    assert!(table.contains("Finalized at:        -"));
+    assert!(table.lines().any(|line| line.starts_with("0  Step 0")));
+    assert!(table.contains("Stretch goals:       0/1 unlocked"));
+
+    let json: Value = serde_json::from_str(&render(&details, OutputFormat::Json).unwrap()).unwrap();
+    assert_eq!(json["campaign"]["mint"], mint.to_string());
+    assert_eq!(json["milestones"][0]["votingEndsAt"], 1_700_100_000);
+    assert_eq!(json["milestones"][1]["votingEndsAt"], Value::Null);
+    assert_eq!(json["milestones"][0]["completed"], true);
+    assert_eq!(json["milestones"][1]["stretchGoal"], 0);
+}
+
+#[test]
+fn campaign_keys_use_the_mint_owner_as_token_program() {
+    let cluster = MockCluster::start();
+    let creator = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_program = Pubkey::new_unique();
+    let token_campaign = pda::campaign(&creator, 0);
+    let sol_campaign = pda::campaign(&creator, 1);
+    cluster.set_program_account(
+        token_campaign,
+        &campaign_state(creator, 0, FundingAsset::Token, mint),
+    );
+    cluster.set_program_account(
+        sol_campaign,
+        &campaign_state(creator, 1, FundingAsset::Sol, Pubkey::default()),
+    );
+    cluster.set_account(mint, token_program, vec![0; 82]);
+    let rpc = cluster.rpc();
+
+    let (_, keys) = query::campaign_keys(&rpc, &token_campaign).unwrap();
+    assert_eq!(
+        keys.asset,
+        Asset::Token {
+            mint,
+            token_program
+        }
+    );
+    assert_eq!(keys.creator, creator);
+    let (_, keys) = query::campaign_keys(&rpc, &sol_campaign).unwrap();
+    assert_eq!(keys.asset, Asset::Sol);
+
+    let missing = Pubkey::new_unique();
+    assert!(matches!(
+        query::campaign(&rpc, &missing),
+        Err(CliError::AccountNotFound(address)) if address == missing
+    ));
+    // A contribution is not a campaign
+    let contribution = pda::contribution(&token_campaign, &creator);
+    cluster.set_program_account(
+        contribution,
+        &contribution_state(token_campaign, creator, 10),
+    );
+    assert!(matches!(
+        query::campaign(&rpc, &contribution),
+        Err(CliError::InvalidAccount {
+            expected: "campaign",
+            ..
+        })
+    ));
+    // Nor is a look-alike owned by another program
+    cluster.set_account(sol_campaign, token_program, {
+        let mut data = Vec::new();
+        campaign_state(creator, 1, FundingAsset::Sol, Pubkey::default())
+            .try_serialize(&mut data)
+            .unwrap();
+        data
+    });
+    assert!(matches!(
+        query::campaign(&rpc, &sol_campaign),
+        Err(CliError::InvalidAccount { .. })
+    ));
+}
+
+#[test]
+fn send_signs_and_waits_for_confirmation() {
+    let cluster = MockCluster::start();
+    let creator = Pubkey::new_unique();
+    let backer = Keypair::new();
+    let campaign = hope_rise_client::CampaignKeys::new(&creator, 0, Asset::Sol);
+    let instructions = [ix::fund_campaign(
+        &campaign,
+        &backer.pubkey(),
+        250,
+        None,
+        0..0,
+    )];
+    let rpc = cluster.rpc();
+
+    let signature = rpc.send(&instructions, &backer, &[]).unwrap();
+    let sent = cluster.sent();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].signatures[0], signature);
+    assert!(sent[0].verify().is_ok());
+    assert_eq!(sent[0].message.account_keys[0], backer.pubkey());
+    assert_eq!(sent[0].message.instructions[0].data, instructions[0].data);
+
+    let output = render(
+        &Sent {
+            signature: signature.to_string(),
+            account: None,
+        },
+        OutputFormat::Json,
+    )
+    .unwrap();
+    assert_eq!(
+        serde_json::from_str::<Value>(&output).unwrap(),
+        json!({ "signature": signature.to_string() })
+    );
+
+    cluster.set_outcome(Outcome::Failed);
+    let err = rpc.send(&instructions, &backer, &[]).unwrap_err();
+    assert!(
+        matches!(&err, CliError::TransactionFailed { reason, .. } if reason.contains("6003")),
+        "{err}"
+    );
+
+    cluster.set_outcome(Outcome::Rejected);
+    let err = rpc.send(&instructions, &backer, &[]).unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("Transaction simulation failed"),
+        "{message}"
+    );
+    assert!(message.contains("CampaignNotActive"), "{message}");
+    assert_eq!(cluster.sent().len(), 2);
+}
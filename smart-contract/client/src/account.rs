@@ -0,0 +1,62 @@
+//! Decoding of program accounts from raw account data
+
+use anchor_lang::{AccountDeserialize, Result};
+use hope_rise::state::{
+    Campaign, CampaignCounter, Contribution, Milestone, MilestoneVote, PlatformConfig, RewardTier,
+    StretchGoal,
+};
+
+/// Offset of `Campaign::creator`, for `getProgramAccounts` memcmp filters
+pub const CAMPAIGN_CREATOR_OFFSET: usize = 8 + 8;
+
+/// Offset of `Milestone::campaign`
+pub const MILESTONE_CAMPAIGN_OFFSET: usize = 8;
+
+/// Offset of `RewardTier::campaign`
+pub const REWARD_TIER_CAMPAIGN_OFFSET: usize = 8;
+
+/// Offset of `StretchGoal::campaign`
+pub const STRETCH_GOAL_CAMPAIGN_OFFSET: usize = 8;
+
+/// Offset of `Contribution::campaign`
+pub const CONTRIBUTION_CAMPAIGN_OFFSET: usize = 8;
+
+/// Offset of `Contribution::contributor`
+pub const CONTRIBUTION_CONTRIBUTOR_OFFSET: usize = 8 + 32;
+
+/// Decodes a `T` account, rejecting data with another account's discriminator
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn campaign(data: &[u8]) -> Result<Campaign> {
+    decode(data)
+}
+
+pub fn milestone(data: &[u8]) -> Result<Milestone> {
+    decode(data)
+}
+
+pub fn reward_tier(data: &[u8]) -> Result<RewardTier> {
+    decode(data)
+}
+
+pub fn stretch_goal(data: &[u8]) -> Result<StretchGoal> {
+    decode(data)
+}
+
+pub fn milestone_vote(data: &[u8]) -> Result<MilestoneVote> {
+    decode(data)
+}
+
+pub fn contribution(data: &[u8]) -> Result<Contribution> {
+    decode(data)
+}
+
+pub fn platform_config(data: &[u8]) -> Result<PlatformConfig> {
+    decode(data)
+}
+
+pub fn campaign_counter(data: &[u8]) -> Result<CampaignCounter> {
+    decode(data)
+}
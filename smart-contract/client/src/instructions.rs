@@ -0,0 +1,770 @@
+//! Instruction builders filling in every PDA and optional account
+
+use std::ops::Range;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+use anchor_spl::token_2022;
+use hope_rise::state::{Campaign, Category, FundingAsset};
+use hope_rise::{accounts, instruction};
+
+use crate::pda;
+
+/// Asset a campaign raises, with the mint and token program for tokens
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Asset {
+    Token { mint: Pubkey, token_program: Pubkey },
+    Sol,
+}
+
+impl Asset {
+    fn mint_and_program(self) -> (Option<Pubkey>, Option<Pubkey>) {
+        match self {
+            Asset::Token {
+                mint,
+                token_program,
+            } => (Some(mint), Some(token_program)),
+            Asset::Sol => (None, None),
+        }
+    }
+
+    /// Associated token account of `owner`, `None` for SOL
+    pub fn token_account(self, owner: &Pubkey) -> Option<Pubkey> {
+        match self {
+            Asset::Token {
+                mint,
+                token_program,
+            } => Some(get_associated_token_address_with_program_id(
+                owner,
+                &mint,
+                &token_program,
+            )),
+            Asset::Sol => None,
+        }
+    }
+
+    fn associated_token_program(self) -> Option<Pubkey> {
+        match self {
+            Asset::Token { .. } => Some(associated_token::ID),
+            Asset::Sol => None,
+        }
+    }
+}
+
+/// The contributor's associated token account for the receipt of their
+/// contribution to `campaign`
+pub fn receipt_token_account(campaign: &Pubkey, contributor: &Pubkey) -> Pubkey {
+    let receipt_mint = pda::receipt_mint(&pda::contribution(campaign, contributor));
+    get_associated_token_address_with_program_id(contributor, &receipt_mint, &token_2022::ID)
+}
+
+/// The accounts identifying a campaign to the instructions acting on it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CampaignKeys {
+    pub address: Pubkey,
+    pub creator: Pubkey,
+    pub asset: Asset,
+}
+
+impl CampaignKeys {
+    pub fn new(creator: &Pubkey, campaign_id: u64, asset: Asset) -> Self {
+        Self {
+            address: pda::campaign(creator, campaign_id),
+            creator: *creator,
+            asset,
+        }
+    }
+
+    /// Keys of a fetched campaign. The campaign does not record its mint's
+    /// token program, so token campaigns need the owner of the mint account.
+    pub fn from_account(address: &Pubkey, campaign: &Campaign, token_program: &Pubkey) -> Self {
+        let asset = match campaign.asset {
+            FundingAsset::Token => Asset::Token {
+                mint: campaign.mint,
+                token_program: *token_program,
+            },
+            FundingAsset::Sol => Asset::Sol,
+        };
+        Self {
+            address: *address,
+            creator: campaign.creator,
+            asset,
+        }
+    }
+
+    fn vaults(&self) -> (Option<Pubkey>, Option<Pubkey>) {
+        match self.asset {
+            Asset::Token { .. } => (Some(pda::campaign_vault(&self.address)), None),
+            Asset::Sol => (None, Some(pda::sol_vault(&self.address))),
+        }
+    }
+}
+
+/// Arguments to `create_campaign` other than the funding asset
+#[derive(Clone, Debug)]
+pub struct CampaignArgs {
+    pub title: String,
+    pub short_description: String,
+    pub category: Category,
+    pub cover_image_url: String,
+    pub story_url: String,
+    pub funding_goal: u64,
+    pub duration_days: u64,
+    pub vote_quorum_bps: u16,
+    pub vote_approval_bps: u16,
+    pub allow_early_close: bool,
+    pub transferable_receipts: bool,
+    pub unpledge_cutoff_hours: u32,
+}
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: hope_rise::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+pub fn initialize(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::Initialize {
+            campaign_counter: pda::campaign_counter(),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::Initialize {},
+    )
+}
+
+/// Creates the platform config, signed by the program's upgrade `authority`
+pub fn initialize_platform_config(authority: &Pubkey) -> Instruction {
+    build(
+        accounts::InitializePlatformConfig {
+            platform_config: pda::platform_config(),
+            program: hope_rise::ID,
+            program_data: pda::program_data(),
+            authority: *authority,
+            system_program: system_program::ID,
+        },
+        instruction::InitializePlatformConfig {},
+    )
+}
+
+fn update_platform_config(admin: &Pubkey) -> accounts::UpdatePlatformConfig {
+    accounts::UpdatePlatformConfig {
+        platform_config: pda::platform_config(),
+        admin: *admin,
+    }
+}
+
+pub fn propose_admin(admin: &Pubkey, new_admin: &Pubkey) -> Instruction {
+    build(
+        update_platform_config(admin),
+        instruction::ProposeAdmin {
+            new_admin: *new_admin,
+        },
+    )
+}
+
+pub fn accept_admin(new_admin: &Pubkey) -> Instruction {
+    build(
+        accounts::AcceptAdmin {
+            platform_config: pda::platform_config(),
+            new_admin: *new_admin,
+        },
+        instruction::AcceptAdmin {},
+    )
+}
+
+pub fn set_paused(admin: &Pubkey, paused: bool) -> Instruction {
+    build(
+        update_platform_config(admin),
+        instruction::SetPaused { paused },
+    )
+}
+
+pub fn set_platform_fee(admin: &Pubkey, fee_bps: u16, treasury: &Pubkey) -> Instruction {
+    build(
+        update_platform_config(admin),
+        instruction::SetPlatformFee {
+            fee_bps,
+            treasury: *treasury,
+        },
+    )
+}
+
+pub fn add_accepted_mint(admin: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateAcceptedMints {
+            platform_config: pda::platform_config(),
+            mint: *mint,
+            admin: *admin,
+        },
+        instruction::AddAcceptedMint {},
+    )
+}
+
+pub fn remove_accepted_mint(admin: &Pubkey, mint: &Pubkey) -> Instruction {
+    build(
+        accounts::UpdateAcceptedMints {
+            platform_config: pda::platform_config(),
+            mint: *mint,
+            admin: *admin,
+        },
+        instruction::RemoveAcceptedMint {},
+    )
+}
+
+/// Creates campaign `campaign_id`, which must be the campaign counter's
+/// current value
+pub fn create_campaign(
+    creator: &Pubkey,
+    campaign_id: u64,
+    asset: Asset,
+    args: CampaignArgs,
+) -> Instruction {
+    let campaign = pda::campaign(creator, campaign_id);
+    let (mint, token_program) = asset.mint_and_program();
+    let (funding_asset, campaign_vault, sol_vault) = match asset {
+        Asset::Token { .. } => (
+            FundingAsset::Token,
+            Some(pda::campaign_vault(&campaign)),
+            None,
+        ),
+        Asset::Sol => (FundingAsset::Sol, None, Some(pda::sol_vault(&campaign))),
+    };
+    build(
+        accounts::CreateCampaign {
+            campaign,
+            campaign_counter: pda::campaign_counter(),
+            platform_config: pda::platform_config(),
+            mint,
+            sol_vault,
+            creator: *creator,
+            system_program: system_program::ID,
+            campaign_vault,
+            token_program,
+        },
+        instruction::CreateCampaign {
+            title: args.title,
+            short_description: args.short_description,
+            category: args.category,
+            cover_image_url: args.cover_image_url,
+            story_url: args.story_url,
+            funding_goal: args.funding_goal,
+            duration_days: args.duration_days,
+            asset: funding_asset,
+            vote_quorum_bps: args.vote_quorum_bps,
+            vote_approval_bps: args.vote_approval_bps,
+            allow_early_close: args.allow_early_close,
+            transferable_receipts: args.transferable_receipts,
+            unpledge_cutoff_hours: args.unpledge_cutoff_hours,
+        },
+    )
+}
+
+/// Replaces a draft or active campaign's metadata, the creator pays for or
+/// is refunded the rent of the resized account
+pub fn update_campaign_metadata(
+    campaign: &CampaignKeys,
+    title: &str,
+    short_description: &str,
+    cover_image_url: &str,
+    story_url: &str,
+) -> Instruction {
+    build(
+        accounts::UpdateCampaignMetadata {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            creator: campaign.creator,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateCampaignMetadata {
+            title: title.to_string(),
+            short_description: short_description.to_string(),
+            cover_image_url: cover_image_url.to_string(),
+            story_url: story_url.to_string(),
+        },
+    )
+}
+
+pub fn launch_campaign(campaign: &CampaignKeys) -> Instruction {
+    build(
+        accounts::LaunchCampaign {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            creator: campaign.creator,
+        },
+        instruction::LaunchCampaign {},
+    )
+}
+
+/// Contributes `amount`, claiming reward tier `reward_tier` if given. The
+/// first contribution also mints the contributor's receipt. `stretch_goals`
+/// are the campaign's locked stretch goals to pass,
+/// `unlocked_stretch_goals..stretch_goal_count` or none, which the
+/// contribution unlocks once the amount raised crosses them.
+pub fn fund_campaign(
+    campaign: &CampaignKeys,
+    contributor: &Pubkey,
+    amount: u64,
+    reward_tier: Option<u8>,
+    stretch_goals: Range<u8>,
+) -> Instruction {
+    let (campaign_vault, sol_vault) = campaign.vaults();
+    let (mint, token_program) = campaign.asset.mint_and_program();
+    let mut instruction = build(
+        accounts::FundCampaign {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            contribution: pda::contribution(&campaign.address, contributor),
+            campaign_vault,
+            sol_vault,
+            contributor: *contributor,
+            contributor_token_account: campaign.asset.token_account(contributor),
+            mint,
+            token_program,
+            system_program: system_program::ID,
+            reward_tier: reward_tier.map(|index| pda::reward_tier(&campaign.address, index)),
+            receipt_mint: Some(pda::receipt_mint(&pda::contribution(
+                &campaign.address,
+                contributor,
+            ))),
+            receipt_token_account: Some(receipt_token_account(&campaign.address, contributor)),
+            receipt_token_program: Some(token_2022::ID),
+            associated_token_program: Some(associated_token::ID),
+        },
+        instruction::FundCampaign {
+            amount,
+            reward_tier,
+        },
+    );
+    instruction.accounts.extend(
+        stretch_goals
+            .map(|index| AccountMeta::new(pda::stretch_goal(&campaign.address, index), false)),
+    );
+    instruction
+}
+
+/// Withdraws `amount` of the contributor's pledge while the campaign is still
+/// funding. A contributor holding reward tier `reward_tier` has to pass it,
+/// and withdrawing everything burns their receipt.
+pub fn unpledge(
+    campaign: &CampaignKeys,
+    contributor: &Pubkey,
+    amount: u64,
+    reward_tier: Option<u8>,
+) -> Instruction {
+    let (campaign_vault, sol_vault) = campaign.vaults();
+    let (mint, token_program) = campaign.asset.mint_and_program();
+    build(
+        accounts::Unpledge {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            campaign_vault,
+            sol_vault,
+            contribution: pda::contribution(&campaign.address, contributor),
+            contributor: *contributor,
+            contributor_token_account: campaign.asset.token_account(contributor),
+            mint,
+            token_program,
+            associated_token_program: campaign.asset.associated_token_program(),
+            system_program: system_program::ID,
+            reward_tier: reward_tier.map(|index| pda::reward_tier(&campaign.address, index)),
+            receipt_mint: Some(pda::receipt_mint(&pda::contribution(
+                &campaign.address,
+                contributor,
+            ))),
+            receipt_token_account: Some(receipt_token_account(&campaign.address, contributor)),
+            receipt_token_program: Some(token_2022::ID),
+        },
+        instruction::Unpledge { amount },
+    )
+}
+
+/// Permissionless, settles a campaign past its deadline or one that may
+/// close early once funded. While any stretch goal is locked the program
+/// needs the campaign's milestones, `0..milestone_count`, and its locked
+/// stretch goals, `unlocked_stretch_goals..stretch_goal_count`: goals the
+/// amount raised crossed unlock, and shares gated on goals still locked move
+/// to the milestones still able to take proof.
+pub fn finalize_campaign(
+    campaign: &Pubkey,
+    milestones: Range<u8>,
+    stretch_goals: Range<u8>,
+) -> Instruction {
+    let mut instruction = build(
+        accounts::FinalizeCampaign {
+            campaign: *campaign,
+            platform_config: pda::platform_config(),
+        },
+        instruction::FinalizeCampaign {},
+    );
+    instruction
+        .accounts
+        .extend(milestones.map(|index| AccountMeta::new(pda::milestone(campaign, index), false)));
+    instruction.accounts.extend(
+        stretch_goals.map(|index| AccountMeta::new(pda::stretch_goal(campaign, index), false)),
+    );
+    instruction
+}
+
+pub fn withdraw_funds(campaign: &CampaignKeys, treasury: &Pubkey) -> Instruction {
+    let (campaign_vault, sol_vault) = campaign.vaults();
+    let (mint, token_program) = campaign.asset.mint_and_program();
+    build(
+        accounts::WithdrawFunds {
+            campaign: campaign.address,
+            campaign_vault,
+            sol_vault,
+            creator: campaign.creator,
+            creator_token_account: campaign.asset.token_account(&campaign.creator),
+            platform_config: pda::platform_config(),
+            treasury: *treasury,
+            treasury_token_account: campaign.asset.token_account(treasury),
+            mint,
+            token_program,
+            associated_token_program: campaign.asset.associated_token_program(),
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawFunds {},
+    )
+}
+
+/// Adds milestone `index`, which must be the campaign's current
+/// `milestone_count`, gated on the stretch goal at `stretch_goal` if given
+pub fn add_milestone(
+    campaign: &CampaignKeys,
+    index: u8,
+    title: &str,
+    target_amount: u64,
+    payout_bps: u16,
+    stretch_goal: Option<u8>,
+) -> Instruction {
+    build(
+        accounts::AddMilestone {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            milestone: pda::milestone(&campaign.address, index),
+            creator: campaign.creator,
+            system_program: system_program::ID,
+        },
+        instruction::AddMilestone {
+            title: title.to_string(),
+            target_amount,
+            payout_bps,
+            stretch_goal,
+        },
+    )
+}
+
+/// Adds reward tier `index`, which must be the campaign's current
+/// `reward_tier_count`
+pub fn add_reward_tier(
+    campaign: &CampaignKeys,
+    index: u8,
+    min_pledge: u64,
+    max_supply: u32,
+    metadata_url: &str,
+) -> Instruction {
+    build(
+        accounts::AddRewardTier {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            reward_tier: pda::reward_tier(&campaign.address, index),
+            creator: campaign.creator,
+            system_program: system_program::ID,
+        },
+        instruction::AddRewardTier {
+            min_pledge,
+            max_supply,
+            metadata_url: metadata_url.to_string(),
+        },
+    )
+}
+
+/// Adds stretch goal `index`, which must be the campaign's current
+/// `stretch_goal_count`
+pub fn add_stretch_goal(
+    campaign: &CampaignKeys,
+    index: u8,
+    threshold: u64,
+    metadata_url: &str,
+) -> Instruction {
+    build(
+        accounts::AddStretchGoal {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            stretch_goal: pda::stretch_goal(&campaign.address, index),
+            previous_stretch_goal: index
+                .checked_sub(1)
+                .map(|previous| pda::stretch_goal(&campaign.address, previous)),
+            creator: campaign.creator,
+            system_program: system_program::ID,
+        },
+        instruction::AddStretchGoal {
+            threshold,
+            metadata_url: metadata_url.to_string(),
+        },
+    )
+}
+
+pub fn submit_milestone_proof(campaign: &CampaignKeys, index: u8, proof_url: &str) -> Instruction {
+    build(
+        accounts::SubmitMilestoneProof {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            milestone: pda::milestone(&campaign.address, index),
+            creator: campaign.creator,
+        },
+        instruction::SubmitMilestoneProof {
+            proof_url: proof_url.to_string(),
+        },
+    )
+}
+
+pub fn vote_milestone(
+    campaign: &Pubkey,
+    index: u8,
+    contributor: &Pubkey,
+    approve: bool,
+) -> Instruction {
+    let milestone = pda::milestone(campaign, index);
+    build(
+        accounts::VoteMilestone {
+            campaign: *campaign,
+            platform_config: pda::platform_config(),
+            milestone,
+            contribution: pda::contribution(campaign, contributor),
+            milestone_vote: pda::milestone_vote(&milestone, contributor),
+            contributor: *contributor,
+            system_program: system_program::ID,
+        },
+        instruction::VoteMilestone { approve },
+    )
+}
+
+/// Permissionless, tallies a milestone vote once its voting window closed
+pub fn complete_milestone(campaign: &Pubkey, index: u8) -> Instruction {
+    build(
+        accounts::CompleteMilestone {
+            campaign: *campaign,
+            platform_config: pda::platform_config(),
+            milestone: pda::milestone(campaign, index),
+        },
+        instruction::CompleteMilestone {},
+    )
+}
+
+pub fn release_milestone(campaign: &CampaignKeys, index: u8, treasury: &Pubkey) -> Instruction {
+    let (campaign_vault, sol_vault) = campaign.vaults();
+    let (mint, token_program) = campaign.asset.mint_and_program();
+    build(
+        accounts::ReleaseMilestone {
+            campaign: campaign.address,
+            milestone: pda::milestone(&campaign.address, index),
+            campaign_vault,
+            sol_vault,
+            creator: campaign.creator,
+            creator_token_account: campaign.asset.token_account(&campaign.creator),
+            platform_config: pda::platform_config(),
+            treasury: *treasury,
+            treasury_token_account: campaign.asset.token_account(treasury),
+            mint,
+            token_program,
+            associated_token_program: campaign.asset.associated_token_program(),
+            system_program: system_program::ID,
+        },
+        instruction::ReleaseMilestone {},
+    )
+}
+
+fn moderate_campaign(campaign: &Pubkey, admin: &Pubkey) -> accounts::ModerateCampaign {
+    accounts::ModerateCampaign {
+        campaign: *campaign,
+        platform_config: pda::platform_config(),
+        admin: *admin,
+    }
+}
+
+pub fn suspend_campaign(campaign: &Pubkey, admin: &Pubkey, reason: u16) -> Instruction {
+    build(
+        moderate_campaign(campaign, admin),
+        instruction::SuspendCampaign { reason },
+    )
+}
+
+pub fn unsuspend_campaign(campaign: &Pubkey, admin: &Pubkey, reason: u16) -> Instruction {
+    build(
+        moderate_campaign(campaign, admin),
+        instruction::UnsuspendCampaign { reason },
+    )
+}
+
+pub fn force_fail_campaign(campaign: &Pubkey, admin: &Pubkey, reason: u16) -> Instruction {
+    build(
+        moderate_campaign(campaign, admin),
+        instruction::ForceFailCampaign { reason },
+    )
+}
+
+pub fn close_campaign(campaign: &CampaignKeys) -> Instruction {
+    build(
+        accounts::CloseCampaign {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            creator: campaign.creator,
+        },
+        instruction::CloseCampaign {},
+    )
+}
+
+/// Refunds the contributor and burns their receipt. A receipt transferred
+/// away needs its holder's token account in place of the contributor's.
+pub fn claim_refund(campaign: &CampaignKeys, contributor: &Pubkey) -> Instruction {
+    let (campaign_vault, sol_vault) = campaign.vaults();
+    let (mint, token_program) = campaign.asset.mint_and_program();
+    build(
+        accounts::ClaimRefund {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            campaign_vault,
+            sol_vault,
+            contribution: pda::contribution(&campaign.address, contributor),
+            contributor: *contributor,
+            contributor_token_account: campaign.asset.token_account(contributor),
+            mint,
+            token_program,
+            associated_token_program: campaign.asset.associated_token_program(),
+            system_program: system_program::ID,
+            receipt_mint: Some(pda::receipt_mint(&pda::contribution(
+                &campaign.address,
+                contributor,
+            ))),
+            receipt_token_account: Some(receipt_token_account(&campaign.address, contributor)),
+            receipt_token_program: Some(token_2022::ID),
+        },
+        instruction::ClaimRefund {},
+    )
+}
+
+/// Refunds the unreleased share of a contribution to a campaign that
+/// failed a milestone vote. Takes the same accounts as `claim_refund`.
+pub fn claim_partial_refund(campaign: &CampaignKeys, contributor: &Pubkey) -> Instruction {
+    let mut instruction = claim_refund(campaign, contributor);
+    instruction.data = instruction::ClaimPartialRefund {}.data();
+    instruction
+}
+
+/// Closes a refunded or completed contribution, returning its rent to the
+/// contributor. Anyone may send it.
+pub fn close_contribution(campaign: &Pubkey, contributor: &Pubkey) -> Instruction {
+    build(
+        accounts::CloseContribution {
+            campaign: *campaign,
+            platform_config: pda::platform_config(),
+            contribution: pda::contribution(campaign, contributor),
+            contributor: *contributor,
+        },
+        instruction::CloseContribution {},
+    )
+}
+
+/// Closes a milestone of a settled campaign, returning its rent to the creator
+pub fn close_milestone(campaign: &CampaignKeys, index: u8) -> Instruction {
+    build(
+        accounts::CloseMilestone {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            milestone: pda::milestone(&campaign.address, index),
+            creator: campaign.creator,
+        },
+        instruction::CloseMilestone {},
+    )
+}
+
+/// Closes `voter`'s vote on milestone `index` once the milestone is released
+/// or the campaign settled, returning its rent to the voter. Anyone may send
+/// it.
+pub fn close_milestone_vote(campaign: &Pubkey, index: u8, voter: &Pubkey) -> Instruction {
+    let milestone = pda::milestone(campaign, index);
+    build(
+        accounts::CloseMilestoneVote {
+            campaign: *campaign,
+            platform_config: pda::platform_config(),
+            milestone,
+            milestone_vote: pda::milestone_vote(&milestone, voter),
+            voter: *voter,
+        },
+        instruction::CloseMilestoneVote {
+            milestone_index: index,
+        },
+    )
+}
+
+/// Closes a reward tier of a settled campaign, returning its rent to the
+/// creator
+pub fn close_reward_tier(campaign: &CampaignKeys, index: u8) -> Instruction {
+    build(
+        accounts::CloseRewardTier {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            reward_tier: pda::reward_tier(&campaign.address, index),
+            creator: campaign.creator,
+        },
+        instruction::CloseRewardTier {},
+    )
+}
+
+/// Closes a stretch goal of a settled campaign, returning its rent to the
+/// creator
+pub fn close_stretch_goal(campaign: &CampaignKeys, index: u8) -> Instruction {
+    build(
+        accounts::CloseStretchGoal {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            stretch_goal: pda::stretch_goal(&campaign.address, index),
+            creator: campaign.creator,
+        },
+        instruction::CloseStretchGoal {},
+    )
+}
+
+/// Closes a settled campaign and its vault once its contributions,
+/// milestones, reward tiers and stretch goals are closed. Token-2022 vaults
+/// holding withheld transfer fees need them harvested to the mint first.
+pub fn close_campaign_accounts(campaign: &CampaignKeys) -> Instruction {
+    let (campaign_vault, sol_vault) = campaign.vaults();
+    let (mint, token_program) = campaign.asset.mint_and_program();
+    build(
+        accounts::CloseCampaignAccounts {
+            campaign: campaign.address,
+            platform_config: pda::platform_config(),
+            campaign_vault,
+            sol_vault,
+            creator: campaign.creator,
+            creator_token_account: campaign.asset.token_account(&campaign.creator),
+            mint,
+            token_program,
+            associated_token_program: campaign.asset.associated_token_program(),
+            system_program: system_program::ID,
+        },
+        instruction::CloseCampaignAccounts {},
+    )
+}
+
+/// Upgrades a campaign, milestone, contribution, reward tier or stretch goal
+/// account written by an older program version to the current layout,
+/// `payer` covering any rent the layout adds
+pub fn migrate_account(account: &Pubkey, payer: &Pubkey) -> Instruction {
+    build(
+        accounts::MigrateAccount {
+            account: *account,
+            payer: *payer,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateAccount {},
+    )
+}
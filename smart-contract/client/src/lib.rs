@@ -0,0 +1,18 @@
+//! Rust client for the hope_rise program.
+//!
+//! One place for everything an off-chain integration needs to talk to the
+//! program: [`pda`] derives every program address from the seeds in
+//! `hope_rise::constants`, [`instructions`] builds each instruction with its
+//! PDAs and optional accounts filled in, and [`account`] decodes the
+//! program's accounts from raw account data.
+
+pub mod account;
+pub mod instructions;
+pub mod pda;
+
+pub use hope_rise::state::{
+    Campaign, CampaignCounter, CampaignStatus, Category, Contribution, FundingAsset, Milestone,
+    MilestoneVote, PlatformConfig,
+};
+pub use hope_rise::ID as PROGRAM_ID;
+pub use instructions::{Asset, CampaignArgs, CampaignKeys};
@@ -0,0 +1,71 @@
+//! Program derived addresses, one function per seed in `hope_rise::constants`,
+//! plus the program data account the upgradeable loader derives
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use hope_rise::constants::*;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &hope_rise::ID).0
+}
+
+/// Singleton counter that numbers campaigns
+pub fn campaign_counter() -> Pubkey {
+    find(&[CAMPAIGN_COUNTER_SEED])
+}
+
+/// Singleton platform configuration
+pub fn platform_config() -> Pubkey {
+    find(&[PLATFORM_CONFIG_SEED])
+}
+
+/// Program data account holding the program's upgrade authority
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[hope_rise::ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+/// Campaign number `campaign_id` of `creator`, the counter value when it was
+/// created
+pub fn campaign(creator: &Pubkey, campaign_id: u64) -> Pubkey {
+    find(&[CAMPAIGN_SEED, creator.as_ref(), &campaign_id.to_le_bytes()])
+}
+
+/// Milestone at `index` in the campaign's milestone list
+pub fn milestone(campaign: &Pubkey, index: u8) -> Pubkey {
+    find(&[MILESTONE_SEED, campaign.as_ref(), &[index]])
+}
+
+/// Reward tier at `index` in the campaign's tier list
+pub fn reward_tier(campaign: &Pubkey, index: u8) -> Pubkey {
+    find(&[REWARD_TIER_SEED, campaign.as_ref(), &[index]])
+}
+
+/// Stretch goal at `index` in the campaign's stretch goal list
+pub fn stretch_goal(campaign: &Pubkey, index: u8) -> Pubkey {
+    find(&[STRETCH_GOAL_SEED, campaign.as_ref(), &[index]])
+}
+
+/// A backer's vote on a milestone
+pub fn milestone_vote(milestone: &Pubkey, voter: &Pubkey) -> Pubkey {
+    find(&[MILESTONE_VOTE_SEED, milestone.as_ref(), voter.as_ref()])
+}
+
+/// A backer's running contribution to a campaign
+pub fn contribution(campaign: &Pubkey, contributor: &Pubkey) -> Pubkey {
+    find(&[CONTRIBUTION_SEED, campaign.as_ref(), contributor.as_ref()])
+}
+
+/// Token-2022 mint of the receipt issued for a contribution
+pub fn receipt_mint(contribution: &Pubkey) -> Pubkey {
+    find(&[RECEIPT_MINT_SEED, contribution.as_ref()])
+}
+
+/// Token account holding the funds of a token campaign
+pub fn campaign_vault(campaign: &Pubkey) -> Pubkey {
+    find(&[CAMPAIGN_VAULT_SEED, campaign.as_ref()])
+}
+
+/// System account holding the lamports of a SOL campaign
+pub fn sol_vault(campaign: &Pubkey) -> Pubkey {
+    find(&[SOL_VAULT_SEED, campaign.as_ref()])
+}
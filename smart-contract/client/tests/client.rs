@@ -0,0 +1,565 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::{AccountSerialize, AnchorDeserialize, Discriminator};
+use hope_rise::constants::*;
+use hope_rise::instruction;
+use hope_rise_client::{account, instructions as ix, pda};
+use hope_rise_client::{
+    Asset, Campaign, CampaignArgs, CampaignKeys, CampaignStatus, Category, Contribution,
+    FundingAsset,
+};
+
+fn token_asset() -> Asset {
+    Asset::Token {
+        mint: Pubkey::new_unique(),
+        token_program: Pubkey::new_unique(),
+    }
+}
+
+fn campaign_args() -> CampaignArgs {
+    CampaignArgs {
+        title: "Clean water".to_string(),
+        short_description: "Wells for the village".to_string(),
+        category: Category::Community,
+        cover_image_url: "ipfs://cover".to_string(),
+        story_url: "ipfs://story".to_string(),
+        funding_goal: 1_000,
+        duration_days: 30,
+        vote_quorum_bps: 2_000,
+        vote_approval_bps: 6_000,
+        allow_early_close: false,
+        transferable_receipts: false,
+        unpledge_cutoff_hours: 48,
+    }
+}
+
+fn campaign_state(creator: Pubkey, asset: FundingAsset, mint: Pubkey) -> Campaign {
+    Campaign {
+        campaign_id: 3,
+        creator,
+        title: "Clean water".to_string(),
+        short_description: "Wells for the village".to_string(),
+        category: Category::Community,
+        cover_image_url: "ipfs://cover".to_string(),
+        story_url: "ipfs://story".to_string(),
+        funding_goal: 1_000,
+        deadline: 1_700_000_000,
+        amount_raised: 400,
+        backer_count: 2,
+        status: CampaignStatus::Active,
+        created_at: 1_699_000_000,
+        milestone_count: 1,
+        bump: 255,
+        released_amount: 0,
+        allocated_bps: 5_000,
+        released_bps: 0,
+        vote_quorum_bps: 2_000,
+        vote_approval_bps: 6_000,
+        failed_milestone: None,
+        mint,
+        asset,
+        fee_bps: 250,
+        moderation_reason: 0,
+        allow_early_close: false,
+        finalized_at: 0,
+        open_contributions: 0,
+        open_milestones: 0,
+        version: Campaign::VERSION,
+        reward_tier_count: 0,
+        open_reward_tiers: 0,
+        transferable_receipts: false,
+        unpledge_cutoff: 172_800,
+        total_refunded: 0,
+        stretch_goal_count: 0,
+        open_stretch_goals: 0,
+        unlocked_stretch_goals: 0,
+        suspended_from: None,
+    }
+}
+
+fn serialize(account: &impl AccountSerialize) -> Vec<u8> {
+    let mut data = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+    data
+}
+
+fn meta(instruction: &anchor_lang::solana_program::instruction::Instruction) -> Vec<Pubkey> {
+    instruction
+        .accounts
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect()
+}
+
+/// Decodes the arguments of `instruction` as `T`, checking its discriminator
+fn args<T: Discriminator + AnchorDeserialize>(
+    instruction: &anchor_lang::solana_program::instruction::Instruction,
+) -> T {
+    let data = instruction
+        .data
+        .strip_prefix(T::DISCRIMINATOR)
+        .expect("instruction discriminator");
+    T::try_from_slice(data).unwrap()
+}
+
+#[test]
+fn pdas_follow_program_seeds() {
+    let creator = Pubkey::new_unique();
+    let contributor = Pubkey::new_unique();
+
+    let (counter, _) = Pubkey::find_program_address(&[b"campaign_counter"], &hope_rise::ID);
+    assert_eq!(pda::campaign_counter(), counter);
+    let (config, _) = Pubkey::find_program_address(&[b"platform_config"], &hope_rise::ID);
+    assert_eq!(pda::platform_config(), config);
+    let (program_data, _) = Pubkey::find_program_address(
+        &[hope_rise::ID.as_ref()],
+        &anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+    );
+    assert_eq!(pda::program_data(), program_data);
+
+    let campaign = pda::campaign(&creator, 258);
+    let (expected, _) = Pubkey::find_program_address(
+        &[CAMPAIGN_SEED, creator.as_ref(), &[2, 1, 0, 0, 0, 0, 0, 0]],
+        &hope_rise::ID,
+    );
+    assert_eq!(campaign, expected);
+    assert_ne!(campaign, pda::campaign(&creator, 259));
+
+    let milestones: Vec<_> = (0..3)
+        .map(|index| pda::milestone(&campaign, index))
+        .collect();
+    assert_ne!(milestones[0], milestones[1]);
+    assert_ne!(milestones[1], milestones[2]);
+    assert_ne!(
+        pda::milestone_vote(&milestones[0], &contributor),
+        pda::milestone_vote(&milestones[1], &contributor)
+    );
+    assert_ne!(
+        pda::contribution(&campaign, &contributor),
+        pda::contribution(&campaign, &creator)
+    );
+    assert_ne!(pda::campaign_vault(&campaign), pda::sol_vault(&campaign));
+
+    for address in [
+        pda::contribution(&campaign, &contributor),
+        pda::sol_vault(&campaign),
+    ] {
+        assert!(!address.is_on_curve());
+    }
+}
+
+#[test]
+fn sol_campaigns_leave_token_accounts_out() {
+    let creator = Pubkey::new_unique();
+    let contributor = Pubkey::new_unique();
+    let campaign = CampaignKeys::new(&creator, 0, Asset::Sol);
+
+    let create = ix::create_campaign(&creator, 0, Asset::Sol, campaign_args());
+    // campaign, counter, config, mint, sol_vault, creator, system program,
+    // campaign_vault, token program
+    assert_eq!(
+        meta(&create),
+        vec![
+            campaign.address,
+            pda::campaign_counter(),
+            pda::platform_config(),
+            hope_rise::ID,
+            pda::sol_vault(&campaign.address),
+            creator,
+            anchor_lang::system_program::ID,
+            hope_rise::ID,
+            hope_rise::ID,
+        ]
+    );
+    assert!(create.accounts[5].is_signer);
+
+    let fund = ix::fund_campaign(&campaign, &contributor, 500, None, 0..0);
+    let accounts = meta(&fund);
+    assert_eq!(
+        accounts[2],
+        pda::contribution(&campaign.address, &contributor)
+    );
+    assert_eq!(accounts[3], hope_rise::ID);
+    assert_eq!(accounts[4], pda::sol_vault(&campaign.address));
+    assert_eq!(&accounts[6..9], &[hope_rise::ID; 3]);
+    assert_eq!(accounts[10], hope_rise::ID);
+    assert_eq!(
+        fund.accounts[5],
+        AccountMeta::new(contributor, true),
+        "the contributor signs and pays"
+    );
+}
+
+#[test]
+fn token_campaigns_use_the_vault_and_associated_token_accounts() {
+    let creator = Pubkey::new_unique();
+    let contributor = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let asset = token_asset();
+    let Asset::Token {
+        mint,
+        token_program,
+    } = asset
+    else {
+        unreachable!()
+    };
+    let campaign = CampaignKeys::new(&creator, 4, asset);
+
+    // The creator pays for the vault when creating the campaign
+    let create = ix::create_campaign(&creator, 4, asset, campaign_args());
+    assert_eq!(
+        &meta(&create)[7..9],
+        &[pda::campaign_vault(&campaign.address), token_program]
+    );
+
+    let fund = ix::fund_campaign(&campaign, &contributor, 500, Some(2), 1..3);
+    let accounts = meta(&fund);
+    assert_eq!(accounts[3], pda::campaign_vault(&campaign.address));
+    assert_eq!(accounts[4], hope_rise::ID);
+    assert_eq!(accounts[6], asset.token_account(&contributor).unwrap());
+    assert_eq!(&accounts[7..9], &[mint, token_program]);
+    assert_eq!(accounts[10], pda::reward_tier(&campaign.address, 2));
+    // The receipt is a Token-2022 mint whatever token the campaign raises
+    let receipt_mint = pda::receipt_mint(&pda::contribution(&campaign.address, &contributor));
+    assert_eq!(
+        &accounts[11..],
+        &[
+            receipt_mint,
+            ix::receipt_token_account(&campaign.address, &contributor),
+            anchor_spl::token_2022::ID,
+            anchor_spl::associated_token::ID,
+            // Locked stretch goals follow as writable remaining accounts
+            pda::stretch_goal(&campaign.address, 1),
+            pda::stretch_goal(&campaign.address, 2),
+        ]
+    );
+    assert!(fund.accounts[15..].iter().all(|meta| meta.is_writable));
+    assert_ne!(
+        ix::receipt_token_account(&campaign.address, &contributor),
+        asset.token_account(&contributor).unwrap()
+    );
+
+    let withdraw = ix::withdraw_funds(&campaign, &treasury);
+    let accounts = meta(&withdraw);
+    assert_eq!(accounts[4], asset.token_account(&creator).unwrap());
+    assert_eq!(accounts[6], treasury);
+    assert_eq!(accounts[7], asset.token_account(&treasury).unwrap());
+    assert_eq!(accounts[10], anchor_spl::associated_token::ID);
+    assert_ne!(
+        asset.token_account(&creator),
+        asset.token_account(&treasury)
+    );
+    assert_eq!(Asset::Sol.token_account(&creator), None);
+
+    let refund = ix::claim_partial_refund(&campaign, &contributor);
+    assert_eq!(
+        refund.accounts[4].pubkey,
+        pda::contribution(&campaign.address, &contributor)
+    );
+    assert_eq!(
+        refund.accounts[6].pubkey,
+        asset.token_account(&contributor).unwrap()
+    );
+    assert_eq!(refund.accounts[11].pubkey, receipt_mint);
+    assert!(refund.accounts[12].is_writable);
+
+    // Unpledging releases the held tier and burns the receipt when emptied
+    let unpledge = ix::unpledge(&campaign, &contributor, 500, Some(2));
+    let accounts = meta(&unpledge);
+    assert_eq!(accounts[6], asset.token_account(&contributor).unwrap());
+    assert_eq!(accounts[11], pda::reward_tier(&campaign.address, 2));
+    assert_eq!(accounts[12], receipt_mint);
+    assert!(unpledge.accounts[11].is_writable);
+
+    // Closing contributions is permissionless, the contributor only receives rent
+    let close = ix::close_contribution(&campaign.address, &contributor);
+    assert_eq!(close.accounts[3], AccountMeta::new(contributor, false));
+    let close = ix::close_campaign_accounts(&campaign);
+    assert_eq!(close.accounts[4], AccountMeta::new(creator, true));
+    assert_eq!(
+        close.accounts[5].pubkey,
+        asset.token_account(&creator).unwrap()
+    );
+}
+
+#[test]
+fn milestone_builders_target_the_indexed_milestone() {
+    let creator = Pubkey::new_unique();
+    let contributor = Pubkey::new_unique();
+    let campaign = CampaignKeys::new(&creator, 0, Asset::Sol);
+    let milestone = pda::milestone(&campaign.address, 2);
+
+    let add = ix::add_milestone(&campaign, 2, "Drill", 500, 4_000, Some(1));
+    assert_eq!(add.accounts[2].pubkey, milestone);
+    let args = args::<instruction::AddMilestone>(&add);
+    assert_eq!(args.title, "Drill");
+    assert_eq!((args.target_amount, args.payout_bps), (500, 4_000));
+    assert_eq!(args.stretch_goal, Some(1));
+
+    let vote = ix::vote_milestone(&campaign.address, 2, &contributor, true);
+    assert_eq!(vote.accounts[2].pubkey, milestone);
+    assert_eq!(
+        vote.accounts[4].pubkey,
+        pda::milestone_vote(&milestone, &contributor)
+    );
+    assert!(vote.accounts[5].is_signer);
+
+    let close = ix::close_milestone_vote(&campaign.address, 2, &contributor);
+    assert_eq!(close.accounts[2].pubkey, milestone);
+    assert_eq!(
+        close.accounts[3].pubkey,
+        pda::milestone_vote(&milestone, &contributor)
+    );
+    assert_eq!(close.accounts[4].pubkey, contributor);
+    assert!(close.accounts.iter().all(|meta| !meta.is_signer));
+    assert_eq!(
+        crate::args::<instruction::CloseMilestoneVote>(&close).milestone_index,
+        2
+    );
+
+    // Completing is permissionless, nobody signs
+    let complete = ix::complete_milestone(&campaign.address, 2);
+    assert!(complete.accounts.iter().all(|meta| !meta.is_signer));
+    assert_eq!(complete.accounts[2].pubkey, milestone);
+
+    let release = ix::release_milestone(&campaign, 2, &Pubkey::new_unique());
+    assert_eq!(release.accounts[1].pubkey, milestone);
+
+    // Finalizing with locked stretch goals passes the milestones, then the goals
+    let finalize = ix::finalize_campaign(&campaign.address, 0..3, 1..2);
+    assert_eq!(
+        &meta(&finalize)[2..],
+        &[
+            pda::milestone(&campaign.address, 0),
+            pda::milestone(&campaign.address, 1),
+            milestone,
+            pda::stretch_goal(&campaign.address, 1),
+        ]
+    );
+    assert!(finalize.accounts[2..].iter().all(|meta| meta.is_writable));
+}
+
+#[test]
+fn stretch_goals_pass_the_goal_before_them() {
+    let creator = Pubkey::new_unique();
+    let campaign = CampaignKeys::new(&creator, 0, Asset::Sol);
+
+    let first = ix::add_stretch_goal(&campaign, 0, 2_000, "ipfs://solar");
+    assert_eq!(
+        first.accounts[2].pubkey,
+        pda::stretch_goal(&campaign.address, 0)
+    );
+    assert_eq!(first.accounts[3].pubkey, hope_rise::ID);
+
+    let second = ix::add_stretch_goal(&campaign, 1, 3_000, "ipfs://pump");
+    assert_eq!(
+        &meta(&second)[2..4],
+        &[
+            pda::stretch_goal(&campaign.address, 1),
+            pda::stretch_goal(&campaign.address, 0),
+        ]
+    );
+    assert!(second.accounts[4].is_signer);
+}
+
+#[test]
+fn campaign_keys_come_from_fetched_accounts() {
+    let creator = Pubkey::new_unique();
+    let address = pda::campaign(&creator, 3);
+    let mint = Pubkey::new_unique();
+    let token_program = Pubkey::new_unique();
+
+    let token = campaign_state(creator, FundingAsset::Token, mint);
+    assert_eq!(
+        CampaignKeys::from_account(&address, &token, &token_program),
+        CampaignKeys::new(
+            &creator,
+            3,
+            Asset::Token {
+                mint,
+                token_program
+            }
+        )
+    );
+
+    let sol = campaign_state(creator, FundingAsset::Sol, Pubkey::default());
+    assert_eq!(
+        CampaignKeys::from_account(&address, &sol, &token_program).asset,
+        Asset::Sol
+    );
+}
+
+#[test]
+fn accounts_decode_and_check_discriminators() {
+    let creator = Pubkey::new_unique();
+    let state = campaign_state(creator, FundingAsset::Token, Pubkey::new_unique());
+    let data = serialize(&state);
+
+    let decoded = account::campaign(&data).unwrap();
+    assert_eq!(decoded.creator, creator);
+    assert_eq!(decoded.title, "Clean water");
+    assert_eq!(decoded.status, CampaignStatus::Active);
+    assert_eq!(decoded.allocated_bps, 5_000);
+    assert_eq!(
+        &data[account::CAMPAIGN_CREATOR_OFFSET..account::CAMPAIGN_CREATOR_OFFSET + 32],
+        creator.as_ref()
+    );
+
+    let contribution = Contribution {
+        campaign: pda::campaign(&creator, 3),
+        contributor: Pubkey::new_unique(),
+        amount: 400,
+        contributed_at: 1_699_500_000,
+        refund_claimed: false,
+        bump: 254,
+        version: Contribution::VERSION,
+        reward_tier: Some(1),
+        has_receipt: true,
+    };
+    let data = serialize(&contribution);
+    let decoded = account::contribution(&data).unwrap();
+    assert_eq!(decoded.amount, 400);
+    let offset = account::CONTRIBUTION_CAMPAIGN_OFFSET;
+    assert_eq!(&data[offset..offset + 32], contribution.campaign.as_ref());
+    let offset = account::CONTRIBUTION_CONTRIBUTOR_OFFSET;
+    assert_eq!(
+        &data[offset..offset + 32],
+        contribution.contributor.as_ref()
+    );
+
+    assert!(account::campaign(&data).is_err());
+    assert!(account::milestone(&data).is_err());
+    assert!(account::contribution(&data[..20]).is_err());
+}
+
+#[test]
+fn instruction_data_round_trips_through_the_program() {
+    let creator = Pubkey::new_unique();
+    let contributor = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let campaign = CampaignKeys::new(&creator, 7, token_asset());
+
+    let create = args::<instruction::CreateCampaign>(&ix::create_campaign(
+        &creator,
+        7,
+        campaign.asset,
+        campaign_args(),
+    ));
+    assert_eq!(create.title, "Clean water");
+    assert_eq!(create.category, Category::Community);
+    assert_eq!((create.funding_goal, create.duration_days), (1_000, 30));
+    assert_eq!(create.asset, FundingAsset::Token);
+    assert_eq!(
+        (create.vote_quorum_bps, create.vote_approval_bps),
+        (2_000, 6_000)
+    );
+    assert_eq!(create.unpledge_cutoff_hours, 48);
+    let create = args::<instruction::CreateCampaign>(&ix::create_campaign(
+        &creator,
+        7,
+        Asset::Sol,
+        campaign_args(),
+    ));
+    assert_eq!(create.asset, FundingAsset::Sol);
+
+    let update = args::<instruction::UpdateCampaignMetadata>(&ix::update_campaign_metadata(
+        &campaign,
+        "Cleaner water",
+        "Three wells",
+        "ipfs://cover2",
+        "ipfs://story2",
+    ));
+    assert_eq!(update.title, "Cleaner water");
+    assert_eq!(update.story_url, "ipfs://story2");
+
+    let fund = args::<instruction::FundCampaign>(&ix::fund_campaign(
+        &campaign,
+        &contributor,
+        750,
+        Some(3),
+        0..2,
+    ));
+    assert_eq!((fund.amount, fund.reward_tier), (750, Some(3)));
+    let unpledge = args::<instruction::Unpledge>(&ix::unpledge(&campaign, &contributor, 250, None));
+    assert_eq!(unpledge.amount, 250);
+
+    let tier = args::<instruction::AddRewardTier>(&ix::add_reward_tier(
+        &campaign,
+        0,
+        100,
+        50,
+        "ipfs://tier",
+    ));
+    assert_eq!((tier.min_pledge, tier.max_supply), (100, 50));
+    assert_eq!(tier.metadata_url, "ipfs://tier");
+    let goal = args::<instruction::AddStretchGoal>(&ix::add_stretch_goal(
+        &campaign,
+        0,
+        2_000,
+        "ipfs://solar",
+    ));
+    assert_eq!(
+        (goal.threshold, goal.metadata_url.as_str()),
+        (2_000, "ipfs://solar")
+    );
+
+    let proof = args::<instruction::SubmitMilestoneProof>(&ix::submit_milestone_proof(
+        &campaign,
+        0,
+        "ipfs://proof",
+    ));
+    assert_eq!(proof.proof_url, "ipfs://proof");
+    let vote = args::<instruction::VoteMilestone>(&ix::vote_milestone(
+        &campaign.address,
+        0,
+        &contributor,
+        false,
+    ));
+    assert!(!vote.approve);
+
+    let fee = args::<instruction::SetPlatformFee>(&ix::set_platform_fee(&admin, 300, &treasury));
+    assert_eq!((fee.fee_bps, fee.treasury), (300, treasury));
+    let propose = args::<instruction::ProposeAdmin>(&ix::propose_admin(&admin, &treasury));
+    assert_eq!(propose.new_admin, treasury);
+    assert!(args::<instruction::SetPaused>(&ix::set_paused(&admin, true)).paused);
+    let suspend =
+        args::<instruction::SuspendCampaign>(&ix::suspend_campaign(&campaign.address, &admin, 4));
+    assert_eq!(suspend.reason, 4);
+    let unsuspend = args::<instruction::UnsuspendCampaign>(&ix::unsuspend_campaign(
+        &campaign.address,
+        &admin,
+        5,
+    ));
+    assert_eq!(unsuspend.reason, 5);
+    let fail = args::<instruction::ForceFailCampaign>(&ix::force_fail_campaign(
+        &campaign.address,
+        &admin,
+        6,
+    ));
+    assert_eq!(fail.reason, 6);
+
+    // Instructions without arguments still carry their own discriminator
+    args::<instruction::Initialize>(&ix::initialize(&admin));
+    args::<instruction::InitializePlatformConfig>(&ix::initialize_platform_config(&admin));
+    args::<instruction::LaunchCampaign>(&ix::launch_campaign(&campaign));
+    args::<instruction::FinalizeCampaign>(&ix::finalize_campaign(&campaign.address, 0..0, 0..0));
+    args::<instruction::WithdrawFunds>(&ix::withdraw_funds(&campaign, &treasury));
+    args::<instruction::CompleteMilestone>(&ix::complete_milestone(&campaign.address, 0));
+    args::<instruction::ReleaseMilestone>(&ix::release_milestone(&campaign, 0, &treasury));
+    args::<instruction::CloseCampaign>(&ix::close_campaign(&campaign));
+    args::<instruction::ClaimRefund>(&ix::claim_refund(&campaign, &contributor));
+    args::<instruction::ClaimPartialRefund>(&ix::claim_partial_refund(&campaign, &contributor));
+    args::<instruction::CloseContribution>(&ix::close_contribution(
+        &campaign.address,
+        &contributor,
+    ));
+    args::<instruction::CloseMilestone>(&ix::close_milestone(&campaign, 0));
+    args::<instruction::CloseRewardTier>(&ix::close_reward_tier(&campaign, 0));
+    args::<instruction::CloseStretchGoal>(&ix::close_stretch_goal(&campaign, 0));
+    args::<instruction::CloseCampaignAccounts>(&ix::close_campaign_accounts(&campaign));
+    args::<instruction::MigrateAccount>(&ix::migrate_account(&campaign.address, &creator));
+    args::<instruction::AcceptAdmin>(&ix::accept_admin(&treasury));
+    args::<instruction::AddAcceptedMint>(&ix::add_accepted_mint(&admin, &treasury));
+    args::<instruction::RemoveAcceptedMint>(&ix::remove_accepted_mint(&admin, &treasury));
+}
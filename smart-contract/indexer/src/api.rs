@@ -0,0 +1,139 @@
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tiny_http::{Header, Server};
+
+use crate::error::{IndexerError, Result};
+use crate::store::Store;
+
+/// Activity entries returned when the request sets no `limit`
+const DEFAULT_ACTIVITY_LIMIT: u32 = 50;
+
+/// Largest `limit` an activity request may ask for
+const MAX_ACTIVITY_LIMIT: u32 = 500;
+
+/// JSON response to a query
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn ok(body: impl Serialize) -> Result<Self> {
+        Ok(Self {
+            status: 200,
+            body: serde_json::to_value(body)?,
+        })
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// Answers a read-only query:
+///
+/// - `GET /campaigns[?creator=<pubkey>]`
+/// - `GET /campaigns/<pubkey>`
+/// - `GET /campaigns/<pubkey>/milestones`
+/// - `GET /campaigns/<pubkey>/rewards`
+/// - `GET /campaigns/<pubkey>/stretch-goals`
+/// - `GET /campaigns/<pubkey>/contributions`
+/// - `GET /campaigns/<pubkey>/activity[?limit=<n>]`
+/// - `GET /contributors/<pubkey>/contributions`
+pub fn route(store: &Store, method: &str, url: &str) -> Response {
+    if method != "GET" {
+        return Response::error(405, "only GET requests are supported");
+    }
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    handle(store, &segments, query).unwrap_or_else(|response| response)
+}
+
+fn handle(
+    store: &Store,
+    segments: &[&str],
+    query: &str,
+) -> std::result::Result<Response, Response> {
+    match *segments {
+        ["campaigns"] => match param(query, "creator") {
+            Some(creator) => json(store.campaigns_by_creator(&pubkey(creator)?)),
+            None => json(store.campaigns()),
+        },
+        ["campaigns", address] => {
+            let campaign = store.campaign(&pubkey(address)?).map_err(Response::from)?;
+            json(campaign.ok_or_else(|| Response::error(404, "campaign not found")))
+        }
+        ["campaigns", address, "milestones"] => json(store.milestones(&pubkey(address)?)),
+        ["campaigns", address, "rewards"] => json(store.reward_tiers(&pubkey(address)?)),
+        ["campaigns", address, "stretch-goals"] => json(store.stretch_goals(&pubkey(address)?)),
+        ["campaigns", address, "contributions"] => json(store.contributions(&pubkey(address)?)),
+        ["campaigns", address, "activity"] => {
+            let limit = match param(query, "limit") {
+                Some(limit) => limit
+                    .parse::<u32>()
+                    .map_err(|_| Response::error(400, "limit must be a positive integer"))?,
+                None => DEFAULT_ACTIVITY_LIMIT,
+            };
+            json(store.activity(&pubkey(address)?, limit.min(MAX_ACTIVITY_LIMIT)))
+        }
+        ["contributors", address, "contributions"] => {
+            json(store.contributions_by(&pubkey(address)?))
+        }
+        _ => Err(Response::error(404, "no such route")),
+    }
+}
+
+fn json<T: Serialize, E: Into<Response>>(
+    result: std::result::Result<T, E>,
+) -> std::result::Result<Response, Response> {
+    let body = result.map_err(Into::into)?;
+    Response::ok(body).map_err(Response::from)
+}
+
+impl From<IndexerError> for Response {
+    fn from(err: IndexerError) -> Self {
+        Response::error(500, err.to_string())
+    }
+}
+
+fn param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value)
+}
+
+fn pubkey(value: &str) -> std::result::Result<Pubkey, Response> {
+    Pubkey::from_str(value)
+        .map_err(|_| Response::error(400, format!("invalid public key: {value}")))
+}
+
+/// Serves `route` over HTTP until the process exits
+pub fn serve(store: &Store, address: &str) -> Result<()> {
+    let server = Server::http(address).map_err(std::io::Error::other)?;
+    let headers = [
+        Header::from_bytes("Content-Type", "application/json").unwrap(),
+        // The UI runs on its own origin
+        Header::from_bytes("Access-Control-Allow-Origin", "*").unwrap(),
+    ];
+
+    for request in server.incoming_requests() {
+        let response = route(store, request.method().as_str(), request.url());
+        let mut reply = tiny_http::Response::from_string(response.body.to_string())
+            .with_status_code(response.status);
+        for header in &headers {
+            reply.add_header(header.clone());
+        }
+        request.respond(reply)?;
+    }
+    Ok(())
+}
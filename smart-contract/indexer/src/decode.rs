@@ -0,0 +1,157 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use hope_rise::events::*;
+use hope_rise::instruction;
+use hope_rise::state::{Category, FundingAsset};
+
+use crate::source::ResolvedInstruction;
+
+/// `hope_rise` instructions whose arguments or outcome are not carried by an
+/// event, with the accounts the indexer needs
+#[derive(Debug, Clone)]
+pub enum ProgramInstruction {
+    CreateCampaign {
+        campaign: Pubkey,
+        title: String,
+        short_description: String,
+        category: Category,
+        cover_image_url: String,
+        story_url: String,
+        asset: FundingAsset,
+        vote_quorum_bps: u16,
+        vote_approval_bps: u16,
+        allow_early_close: bool,
+    },
+    AddMilestone {
+        milestone: Pubkey,
+        title: String,
+    },
+}
+
+/// Events emitted by the program
+pub enum ProgramEvent {
+    CampaignCreated(CampaignCreated),
+    CampaignMetadataUpdated(CampaignMetadataUpdated),
+    CampaignLaunched(CampaignLaunched),
+    ContributionMade(ContributionMade),
+    PledgeWithdrawn(PledgeWithdrawn),
+    CampaignFinalized(CampaignFinalized),
+    FundsWithdrawn(FundsWithdrawn),
+    MilestoneAdded(MilestoneAdded),
+    MilestonePayoutReassigned(MilestonePayoutReassigned),
+    MilestoneProofSubmitted(MilestoneProofSubmitted),
+    MilestoneVoted(MilestoneVoted),
+    MilestoneCompleted(MilestoneCompleted),
+    MilestoneQuorumMissed(MilestoneQuorumMissed),
+    MilestoneReleased(MilestoneReleased),
+    CampaignFailed(CampaignFailed),
+    CampaignCompleted(CampaignCompleted),
+    CampaignModerated(CampaignModerated),
+    RewardTierAdded(RewardTierAdded),
+    StretchGoalAdded(StretchGoalAdded),
+    StretchGoalUnlocked(StretchGoalUnlocked),
+    CampaignClosed(CampaignClosed),
+    RefundClaimed(RefundClaimed),
+}
+
+/// Strips `T`'s discriminator from `data` and deserializes the rest
+fn decode<T: Discriminator + AnchorDeserialize>(data: &[u8]) -> Option<T> {
+    let mut payload = data.strip_prefix(T::DISCRIMINATOR)?;
+    T::deserialize(&mut payload).ok()
+}
+
+/// Decodes a `hope_rise` instruction, ignoring other programs and
+/// instructions the indexer learns about from events
+pub fn decode_instruction(ix: &ResolvedInstruction) -> Option<ProgramInstruction> {
+    if ix.program_id != hope_rise::ID {
+        return None;
+    }
+    let account = |index: usize| ix.accounts.get(index).copied();
+    let data = ix.data.as_slice();
+
+    if let Some(args) = decode::<instruction::CreateCampaign>(data) {
+        return Some(ProgramInstruction::CreateCampaign {
+            campaign: account(0)?,
+            title: args.title,
+            short_description: args.short_description,
+            category: args.category,
+            cover_image_url: args.cover_image_url,
+            story_url: args.story_url,
+            asset: args.asset,
+            vote_quorum_bps: args.vote_quorum_bps,
+            vote_approval_bps: args.vote_approval_bps,
+            allow_early_close: args.allow_early_close,
+        });
+    }
+    if let Some(args) = decode::<instruction::AddMilestone>(data) {
+        return Some(ProgramInstruction::AddMilestone {
+            milestone: account(2)?,
+            title: args.title,
+        });
+    }
+    None
+}
+
+/// Decodes the payload of a `Program data:` log line
+pub fn decode_event(data: &[u8]) -> Option<ProgramEvent> {
+    decode(data)
+        .map(ProgramEvent::CampaignCreated)
+        .or_else(|| decode(data).map(ProgramEvent::CampaignMetadataUpdated))
+        .or_else(|| decode(data).map(ProgramEvent::CampaignLaunched))
+        .or_else(|| decode(data).map(ProgramEvent::ContributionMade))
+        .or_else(|| decode(data).map(ProgramEvent::PledgeWithdrawn))
+        .or_else(|| decode(data).map(ProgramEvent::CampaignFinalized))
+        .or_else(|| decode(data).map(ProgramEvent::FundsWithdrawn))
+        .or_else(|| decode(data).map(ProgramEvent::MilestoneAdded))
+        .or_else(|| decode(data).map(ProgramEvent::MilestonePayoutReassigned))
+        .or_else(|| decode(data).map(ProgramEvent::MilestoneProofSubmitted))
+        .or_else(|| decode(data).map(ProgramEvent::MilestoneVoted))
+        .or_else(|| decode(data).map(ProgramEvent::MilestoneCompleted))
+        .or_else(|| decode(data).map(ProgramEvent::MilestoneQuorumMissed))
+        .or_else(|| decode(data).map(ProgramEvent::MilestoneReleased))
+        .or_else(|| decode(data).map(ProgramEvent::CampaignFailed))
+        .or_else(|| decode(data).map(ProgramEvent::CampaignCompleted))
+        .or_else(|| decode(data).map(ProgramEvent::CampaignModerated))
+        .or_else(|| decode(data).map(ProgramEvent::RewardTierAdded))
+        .or_else(|| decode(data).map(ProgramEvent::StretchGoalAdded))
+        .or_else(|| decode(data).map(ProgramEvent::StretchGoalUnlocked))
+        .or_else(|| decode(data).map(ProgramEvent::CampaignClosed))
+        .or_else(|| decode(data).map(ProgramEvent::RefundClaimed))
+}
+
+/// Events logged by `hope_rise` in a transaction's log messages. Tracks the
+/// invocation stack so data logged by other programs, including ones
+/// `hope_rise` calls into, is never mistaken for an event.
+pub fn program_events(logs: &[String]) -> Vec<ProgramEvent> {
+    let program_id = hope_rise::ID.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            if stack.last() != Some(&program_id.as_str()) {
+                continue;
+            }
+            let event = data
+                .split(' ')
+                .next()
+                .and_then(|field| BASE64_STANDARD.decode(field).ok())
+                .and_then(|payload| decode_event(&payload));
+            events.extend(event);
+        } else if let Some(rest) = log.strip_prefix("Program ") {
+            let mut words = rest.split(' ');
+            let (Some(program), Some(action)) = (words.next(), words.next()) else {
+                continue;
+            };
+            match action {
+                "invoke" => stack.push(program),
+                "success" | "failed:" => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+    events
+}
@@ -0,0 +1,25 @@
+use thiserror::Error;
+
+/// Errors raised while fetching, decoding or storing transactions
+#[derive(Debug, Error)]
+pub enum IndexerError {
+    #[error("database error: {0}")]
+    Database(#[from] rusqlite::Error),
+
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("RPC request failed: {0}")]
+    Rpc(String),
+
+    #[error("malformed transaction {signature}: {reason}")]
+    MalformedTransaction { signature: String, reason: String },
+
+    #[error("{column} value {value} does not fit a SQLite integer")]
+    IntegerOutOfRange { column: &'static str, value: u64 },
+}
+
+pub type Result<T> = std::result::Result<T, IndexerError>;
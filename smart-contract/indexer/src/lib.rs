@@ -0,0 +1,30 @@
+//! Off-chain indexer for the hope_rise program.
+//!
+//! Reads program transactions from a JSON RPC node (a local
+//! `solana-test-validator` or any cluster) or from an exported JSON file,
+//! decodes the Anchor instructions and events they carry and keeps a SQLite
+//! database of campaigns, milestones, contributions, withdrawals and refunds.
+//! [`api::route`] answers the UI's read queries from that database.
+
+pub mod api;
+pub mod decode;
+pub mod error;
+pub mod source;
+pub mod store;
+
+pub use error::{IndexerError, Result};
+pub use source::{ConfirmedTransaction, FileSource, RpcSource, TransactionSource};
+pub use store::Store;
+
+/// Indexes every transaction `source` has after the store's cursor and
+/// returns how many were new
+pub fn sync(store: &mut Store, source: &mut dyn TransactionSource) -> Result<usize> {
+    let after = store.last_signature()?;
+    let mut indexed = 0;
+    for transaction in source.transactions_after(after.as_deref())? {
+        if store.apply(&transaction)? {
+            indexed += 1;
+        }
+    }
+    Ok(indexed)
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use hope_rise_indexer::{api, sync, FileSource, Result, RpcSource, Store, TransactionSource};
+
+#[derive(Parser)]
+#[command(about = "Index hope_rise transactions into SQLite and serve them to the UI")]
+struct Cli {
+    /// SQLite database file
+    #[arg(long, default_value = "hope_rise.db")]
+    db: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Index new program transactions
+    Sync {
+        /// JSON RPC endpoint to read from
+        #[arg(long, default_value = "http://127.0.0.1:8899", conflicts_with = "file")]
+        rpc: String,
+
+        /// JSON array of `getTransaction` results to read instead of RPC
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Keep polling, waiting this many seconds between syncs
+        #[arg(long)]
+        follow: Option<u64>,
+    },
+    /// Serve the query API over HTTP
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8787")]
+        address: String,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut store = Store::open(&cli.db)?;
+
+    match cli.command {
+        Command::Sync { rpc, file, follow } => {
+            let mut source: Box<dyn TransactionSource> = match file {
+                Some(file) => Box::new(FileSource::new(file)),
+                None => Box::new(RpcSource::new(rpc, hope_rise::ID)),
+            };
+            loop {
+                let indexed = sync(&mut store, source.as_mut())?;
+                println!("indexed {indexed} transactions");
+                match follow {
+                    Some(seconds) => thread::sleep(Duration::from_secs(seconds)),
+                    None => return Ok(()),
+                }
+            }
+        }
+        Command::Serve { address } => {
+            println!("serving on http://{address}");
+            api::serve(&store, &address)
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::error::{IndexerError, Result};
+
+/// Signatures requested per `getSignaturesForAddress` page (the RPC maximum)
+const SIGNATURE_PAGE_SIZE: usize = 1_000;
+
+/// A confirmed transaction as returned by the `getTransaction` RPC method with
+/// `json` encoding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfirmedTransaction {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub meta: Option<TransactionMeta>,
+    pub transaction: EncodedTransaction,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+    /// Error the transaction failed with, `None` when it succeeded
+    pub err: Option<Value>,
+    #[serde(default)]
+    pub log_messages: Option<Vec<String>>,
+    #[serde(default)]
+    pub inner_instructions: Option<Vec<InnerInstructions>>,
+    /// Accounts loaded from address lookup tables by v0 transactions
+    #[serde(default)]
+    pub loaded_addresses: Option<LoadedAddresses>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InnerInstructions {
+    /// Index of the top-level instruction that made these calls
+    pub index: u8,
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoadedAddresses {
+    pub writable: Vec<String>,
+    pub readonly: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncodedTransaction {
+    pub signatures: Vec<String>,
+    pub message: EncodedMessage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodedMessage {
+    pub account_keys: Vec<String>,
+    pub instructions: Vec<CompiledInstruction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompiledInstruction {
+    pub program_id_index: u8,
+    pub accounts: Vec<u8>,
+    /// Base58 encoded instruction data
+    pub data: String,
+}
+
+/// An instruction with its account indexes resolved against the transaction
+pub struct ResolvedInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+impl ConfirmedTransaction {
+    pub fn signature(&self) -> &str {
+        self.transaction
+            .signatures
+            .first()
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.meta.as_ref().is_some_and(|meta| meta.err.is_none())
+    }
+
+    pub fn logs(&self) -> &[String] {
+        self.meta
+            .as_ref()
+            .and_then(|meta| meta.log_messages.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Top-level and inner instructions in execution order
+    pub fn instructions(&self) -> Result<Vec<ResolvedInstruction>> {
+        let keys = self.account_keys()?;
+        let inner = self
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.inner_instructions.as_deref())
+            .unwrap_or_default();
+
+        let mut resolved = Vec::new();
+        for (index, instruction) in self.transaction.message.instructions.iter().enumerate() {
+            resolved.push(self.resolve(&keys, instruction)?);
+            for calls in inner.iter().filter(|calls| calls.index as usize == index) {
+                for instruction in &calls.instructions {
+                    resolved.push(self.resolve(&keys, instruction)?);
+                }
+            }
+        }
+        Ok(resolved)
+    }
+
+    fn account_keys(&self) -> Result<Vec<Pubkey>> {
+        let loaded = self
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.loaded_addresses.as_ref());
+        self.transaction
+            .message
+            .account_keys
+            .iter()
+            .chain(loaded.into_iter().flat_map(|loaded| &loaded.writable))
+            .chain(loaded.into_iter().flat_map(|loaded| &loaded.readonly))
+            .map(|key| {
+                Pubkey::from_str(key).map_err(|_| self.malformed(format!("invalid account {key}")))
+            })
+            .collect()
+    }
+
+    fn resolve(
+        &self,
+        keys: &[Pubkey],
+        instruction: &CompiledInstruction,
+    ) -> Result<ResolvedInstruction> {
+        let key = |index: u8| {
+            keys.get(index as usize)
+                .copied()
+                .ok_or_else(|| self.malformed(format!("account index {index} out of range")))
+        };
+        let data = bs58::decode(&instruction.data)
+            .into_vec()
+            .map_err(|_| self.malformed("instruction data is not base58".to_string()))?;
+
+        Ok(ResolvedInstruction {
+            program_id: key(instruction.program_id_index)?,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|index| key(*index))
+                .collect::<Result<_>>()?,
+            data,
+        })
+    }
+
+    fn malformed(&self, reason: String) -> IndexerError {
+        IndexerError::MalformedTransaction {
+            signature: self.signature().to_string(),
+            reason,
+        }
+    }
+}
+
+/// Where the indexer reads program transactions from
+pub trait TransactionSource {
+    /// Transactions involving the program that landed after the `after`
+    /// signature (everything when `None`), oldest first
+    fn transactions_after(&mut self, after: Option<&str>) -> Result<Vec<ConfirmedTransaction>>;
+}
+
+/// Reads a JSON array of `getTransaction` results, oldest first. Stands in for
+/// an RPC node in tests and when replaying an exported ledger.
+pub struct FileSource {
+    path: PathBuf,
+}
+
+impl FileSource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl TransactionSource for FileSource {
+    fn transactions_after(&mut self, after: Option<&str>) -> Result<Vec<ConfirmedTransaction>> {
+        let file = std::fs::File::open(&self.path)?;
+        let transactions: Vec<ConfirmedTransaction> =
+            serde_json::from_reader(std::io::BufReader::new(file))?;
+
+        let start = after
+            .and_then(|after| {
+                transactions
+                    .iter()
+                    .position(|transaction| transaction.signature() == after)
+            })
+            .map_or(0, |position| position + 1);
+        Ok(transactions.into_iter().skip(start).collect())
+    }
+}
+
+/// Fetches program transactions from a JSON RPC node, e.g. a local
+/// `solana-test-validator` at `http://127.0.0.1:8899`
+pub struct RpcSource {
+    url: String,
+    program_id: Pubkey,
+    agent: ureq::Agent,
+}
+
+impl RpcSource {
+    pub fn new(url: impl Into<String>, program_id: Pubkey) -> Self {
+        Self {
+            url: url.into(),
+            program_id,
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let mut response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|err| IndexerError::Rpc(err.to_string()))?
+            .into_json()?;
+
+        if let Some(error) = response.get("error") {
+            return Err(IndexerError::Rpc(format!("{method}: {error}")));
+        }
+        Ok(response["result"].take())
+    }
+
+    /// Signatures newer than `until`, newest first
+    fn signatures_since(&self, until: Option<&str>) -> Result<Vec<String>> {
+        let mut signatures = Vec::new();
+        let mut before: Option<String> = None;
+        loop {
+            let params = json!([
+                self.program_id.to_string(),
+                {
+                    "limit": SIGNATURE_PAGE_SIZE,
+                    "before": before,
+                    "until": until,
+                    "commitment": "confirmed",
+                },
+            ]);
+            let page = self.call("getSignaturesForAddress", params)?;
+            let page: Vec<String> = page
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry["signature"].as_str().map(str::to_string))
+                .collect();
+
+            let full = page.len() == SIGNATURE_PAGE_SIZE;
+            before = page.last().cloned();
+            signatures.extend(page);
+            if !full {
+                return Ok(signatures);
+            }
+        }
+    }
+}
+
+impl TransactionSource for RpcSource {
+    fn transactions_after(&mut self, after: Option<&str>) -> Result<Vec<ConfirmedTransaction>> {
+        let mut transactions = Vec::new();
+        for signature in self.signatures_since(after)?.iter().rev() {
+            let params = json!([
+                signature,
+                {
+                    "encoding": "json",
+                    "commitment": "confirmed",
+                    "maxSupportedTransactionVersion": 0,
+                },
+            ]);
+            let transaction = self.call("getTransaction", params)?;
+            if !transaction.is_null() {
+                transactions.push(serde_json::from_value(transaction)?);
+            }
+        }
+        Ok(transactions)
+    }
+}
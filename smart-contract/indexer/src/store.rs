@@ -0,0 +1,959 @@
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use hope_rise::state::CampaignStatus;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::Serialize;
+
+use crate::decode::{decode_instruction, program_events, ProgramEvent, ProgramInstruction};
+use crate::error::{IndexerError, Result};
+use crate::source::ConfirmedTransaction;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    succeeded INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS campaigns (
+    address TEXT PRIMARY KEY,
+    campaign_id INTEGER NOT NULL,
+    creator TEXT NOT NULL,
+    title TEXT NOT NULL DEFAULT '',
+    short_description TEXT NOT NULL DEFAULT '',
+    category TEXT NOT NULL DEFAULT '',
+    cover_image_url TEXT NOT NULL DEFAULT '',
+    story_url TEXT NOT NULL DEFAULT '',
+    asset TEXT NOT NULL,
+    mint TEXT NOT NULL,
+    funding_goal INTEGER NOT NULL,
+    deadline INTEGER NOT NULL,
+    created_at INTEGER NOT NULL,
+    fee_bps INTEGER NOT NULL,
+    vote_quorum_bps INTEGER NOT NULL DEFAULT 0,
+    vote_approval_bps INTEGER NOT NULL DEFAULT 0,
+    allow_early_close INTEGER NOT NULL DEFAULT 0,
+    status TEXT NOT NULL,
+    amount_raised INTEGER NOT NULL DEFAULT 0,
+    backer_count INTEGER NOT NULL DEFAULT 0,
+    released_amount INTEGER NOT NULL DEFAULT 0,
+    refunded_amount INTEGER NOT NULL DEFAULT 0,
+    moderation_reason INTEGER NOT NULL DEFAULT 0,
+    finalized_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS campaigns_by_creator ON campaigns (creator);
+
+CREATE TABLE IF NOT EXISTS milestones (
+    address TEXT PRIMARY KEY,
+    campaign TEXT NOT NULL,
+    milestone_index INTEGER NOT NULL,
+    title TEXT NOT NULL DEFAULT '',
+    target_amount INTEGER NOT NULL,
+    payout_bps INTEGER NOT NULL,
+    proof_url TEXT NOT NULL DEFAULT '',
+    status TEXT NOT NULL,
+    yes_weight INTEGER,
+    no_weight INTEGER,
+    completed_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS milestones_by_campaign ON milestones (campaign, milestone_index);
+
+CREATE TABLE IF NOT EXISTS reward_tiers (
+    address TEXT PRIMARY KEY,
+    campaign TEXT NOT NULL,
+    tier_index INTEGER NOT NULL,
+    min_pledge INTEGER NOT NULL,
+    max_supply INTEGER NOT NULL,
+    metadata_url TEXT NOT NULL DEFAULT ''
+);
+CREATE INDEX IF NOT EXISTS reward_tiers_by_campaign ON reward_tiers (campaign, tier_index);
+
+CREATE TABLE IF NOT EXISTS stretch_goals (
+    address TEXT PRIMARY KEY,
+    campaign TEXT NOT NULL,
+    goal_index INTEGER NOT NULL,
+    threshold INTEGER NOT NULL,
+    metadata_url TEXT NOT NULL DEFAULT '',
+    unlocked_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS stretch_goals_by_campaign ON stretch_goals (campaign, goal_index);
+
+CREATE TABLE IF NOT EXISTS reward_claims (
+    campaign TEXT NOT NULL,
+    contributor TEXT NOT NULL,
+    tier_index INTEGER NOT NULL,
+    claimed_at INTEGER NOT NULL,
+    PRIMARY KEY (campaign, contributor)
+);
+
+CREATE TABLE IF NOT EXISTS votes (
+    milestone TEXT NOT NULL,
+    voter TEXT NOT NULL,
+    approve INTEGER NOT NULL,
+    voted_at INTEGER,
+    PRIMARY KEY (milestone, voter)
+);
+
+CREATE TABLE IF NOT EXISTS contributions (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    campaign TEXT NOT NULL,
+    contributor TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    contribution_total INTEGER NOT NULL,
+    amount_raised INTEGER NOT NULL,
+    backer_count INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS contributions_by_campaign ON contributions (campaign, timestamp);
+CREATE INDEX IF NOT EXISTS contributions_by_contributor ON contributions (contributor, timestamp);
+
+CREATE TABLE IF NOT EXISTS unpledges (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    campaign TEXT NOT NULL,
+    contributor TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    contribution_total INTEGER NOT NULL,
+    amount_raised INTEGER NOT NULL,
+    backer_count INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS unpledges_by_campaign ON unpledges (campaign, timestamp);
+
+CREATE TABLE IF NOT EXISTS withdrawals (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    campaign TEXT NOT NULL,
+    creator TEXT NOT NULL,
+    milestone_index INTEGER,
+    amount INTEGER NOT NULL,
+    creator_amount INTEGER NOT NULL,
+    fee INTEGER NOT NULL,
+    released_amount INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS withdrawals_by_campaign ON withdrawals (campaign, timestamp);
+
+CREATE TABLE IF NOT EXISTS refunds (
+    signature TEXT NOT NULL,
+    event_index INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    campaign TEXT NOT NULL,
+    contributor TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    contribution_amount INTEGER NOT NULL,
+    partial INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    PRIMARY KEY (signature, event_index)
+);
+CREATE INDEX IF NOT EXISTS refunds_by_campaign ON refunds (campaign, timestamp);
+CREATE INDEX IF NOT EXISTS refunds_by_contributor ON refunds (contributor, timestamp);
+";
+
+/// Indexer-side progress of a milestone, derived from its instructions and events
+pub mod milestone_status {
+    pub const PENDING: &str = "Pending";
+    pub const VOTING: &str = "Voting";
+    pub const APPROVED: &str = "Approved";
+    pub const REJECTED: &str = "Rejected";
+    pub const RELEASED: &str = "Released";
+}
+
+fn status_name(status: CampaignStatus) -> String {
+    format!("{status:?}")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignRecord {
+    pub address: String,
+    pub campaign_id: u64,
+    pub creator: String,
+    pub title: String,
+    pub short_description: String,
+    pub category: String,
+    pub cover_image_url: String,
+    pub story_url: String,
+    pub asset: String,
+    pub mint: String,
+    pub funding_goal: u64,
+    pub deadline: i64,
+    pub created_at: i64,
+    pub fee_bps: u16,
+    pub vote_quorum_bps: u16,
+    pub vote_approval_bps: u16,
+    pub allow_early_close: bool,
+    pub status: String,
+    pub amount_raised: u64,
+    pub backer_count: u64,
+    pub released_amount: u64,
+    pub refunded_amount: u64,
+    pub moderation_reason: u16,
+    pub finalized_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneRecord {
+    pub address: String,
+    pub campaign: String,
+    pub milestone_index: u8,
+    pub title: String,
+    pub target_amount: u64,
+    pub payout_bps: u16,
+    pub proof_url: String,
+    pub status: String,
+    pub yes_votes: u64,
+    pub no_votes: u64,
+    pub yes_weight: Option<u64>,
+    pub no_weight: Option<u64>,
+    pub completed_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardTierRecord {
+    pub address: String,
+    pub campaign: String,
+    pub tier_index: u8,
+    pub min_pledge: u64,
+    pub max_supply: u32,
+    pub claimed: u32,
+    pub metadata_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StretchGoalRecord {
+    pub address: String,
+    pub campaign: String,
+    pub goal_index: u8,
+    pub threshold: u64,
+    pub metadata_url: String,
+    /// `None` while the goal is locked
+    pub unlocked_at: Option<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContributionRecord {
+    pub signature: String,
+    pub slot: u64,
+    pub campaign: String,
+    pub contributor: String,
+    pub amount: u64,
+    pub contribution_total: u64,
+    pub amount_raised: u64,
+    pub backer_count: u64,
+    pub timestamp: i64,
+}
+
+/// Kind of vault movement shown in an activity feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActivityKind {
+    Contribution,
+    Withdrawal,
+    Refund,
+    Unpledge,
+}
+
+/// A single vault movement of a campaign, newest first in feeds
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRecord {
+    pub kind: ActivityKind,
+    pub signature: String,
+    pub slot: u64,
+    pub campaign: String,
+    /// Creator for withdrawals, contributor for everything else
+    pub account: String,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// SQLite database of indexed campaigns
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Signature of the most recently indexed transaction, the sync cursor
+    pub fn last_signature(&self) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT signature FROM transactions ORDER BY rowid DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Applies a transaction's events and instructions. Returns `false` when the
+    /// transaction was already indexed. Failed transactions only advance the
+    /// sync cursor.
+    pub fn apply(&mut self, tx: &ConfirmedTransaction) -> Result<bool> {
+        let db = self.conn.transaction()?;
+        let inserted = db.execute(
+            "INSERT OR IGNORE INTO transactions (signature, slot, block_time, succeeded)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![
+                tx.signature(),
+                integer("slot", tx.slot)?,
+                tx.block_time,
+                tx.succeeded()
+            ],
+        )?;
+        if inserted == 0 {
+            return Ok(false);
+        }
+
+        if tx.succeeded() {
+            // Events first: they create the rows instructions fill in
+            for (index, event) in program_events(tx.logs()).into_iter().enumerate() {
+                apply_event(&db, tx, index, event)?;
+            }
+            for instruction in tx.instructions()? {
+                if let Some(instruction) = decode_instruction(&instruction) {
+                    apply_instruction(&db, instruction)?;
+                }
+            }
+        }
+
+        db.commit()?;
+        Ok(true)
+    }
+
+    pub fn campaigns(&self) -> Result<Vec<CampaignRecord>> {
+        let mut statement = self.conn.prepare(&format!(
+            "{CAMPAIGN_COLUMNS} FROM campaigns ORDER BY created_at DESC, campaign_id DESC"
+        ))?;
+        let rows = statement.query_map([], campaign_record)?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub fn campaigns_by_creator(&self, creator: &Pubkey) -> Result<Vec<CampaignRecord>> {
+        let mut statement = self.conn.prepare(&format!(
+            "{CAMPAIGN_COLUMNS} FROM campaigns WHERE creator = ?1
+             ORDER BY created_at DESC, campaign_id DESC"
+        ))?;
+        let rows = statement.query_map([creator.to_string()], campaign_record)?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    pub fn campaign(&self, address: &Pubkey) -> Result<Option<CampaignRecord>> {
+        Ok(self
+            .conn
+            .query_row(
+                &format!("{CAMPAIGN_COLUMNS} FROM campaigns WHERE address = ?1"),
+                [address.to_string()],
+                campaign_record,
+            )
+            .optional()?)
+    }
+
+    pub fn milestones(&self, campaign: &Pubkey) -> Result<Vec<MilestoneRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT address, campaign, milestone_index, title, target_amount, payout_bps,
+                    proof_url, status,
+                    (SELECT COUNT(*) FROM votes WHERE milestone = address AND approve = 1),
+                    (SELECT COUNT(*) FROM votes WHERE milestone = address AND approve = 0),
+                    yes_weight, no_weight, completed_at
+             FROM milestones WHERE campaign = ?1 ORDER BY milestone_index",
+        )?;
+        let rows = statement.query_map([campaign.to_string()], |row| {
+            Ok(MilestoneRecord {
+                address: row.get(0)?,
+                campaign: row.get(1)?,
+                milestone_index: row.get(2)?,
+                title: row.get(3)?,
+                target_amount: row.get(4)?,
+                payout_bps: row.get(5)?,
+                proof_url: row.get(6)?,
+                status: row.get(7)?,
+                yes_votes: row.get(8)?,
+                no_votes: row.get(9)?,
+                yes_weight: row.get(10)?,
+                no_weight: row.get(11)?,
+                completed_at: row.get(12)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Reward tiers of a campaign with the number of backers that claimed each
+    pub fn reward_tiers(&self, campaign: &Pubkey) -> Result<Vec<RewardTierRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT address, campaign, tier_index, min_pledge, max_supply,
+                    (SELECT COUNT(*) FROM reward_claims
+                     WHERE reward_claims.campaign = reward_tiers.campaign
+                       AND reward_claims.tier_index = reward_tiers.tier_index),
+                    metadata_url
+             FROM reward_tiers WHERE campaign = ?1 ORDER BY tier_index",
+        )?;
+        let rows = statement.query_map([campaign.to_string()], |row| {
+            Ok(RewardTierRecord {
+                address: row.get(0)?,
+                campaign: row.get(1)?,
+                tier_index: row.get(2)?,
+                min_pledge: row.get(3)?,
+                max_supply: row.get(4)?,
+                claimed: row.get(5)?,
+                metadata_url: row.get(6)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Stretch goals of a campaign in the order they unlock
+    pub fn stretch_goals(&self, campaign: &Pubkey) -> Result<Vec<StretchGoalRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT address, campaign, goal_index, threshold, metadata_url, unlocked_at
+             FROM stretch_goals WHERE campaign = ?1 ORDER BY goal_index",
+        )?;
+        let rows = statement.query_map([campaign.to_string()], |row| {
+            Ok(StretchGoalRecord {
+                address: row.get(0)?,
+                campaign: row.get(1)?,
+                goal_index: row.get(2)?,
+                threshold: row.get(3)?,
+                metadata_url: row.get(4)?,
+                unlocked_at: row.get(5)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Contributions to a campaign, newest first
+    pub fn contributions(&self, campaign: &Pubkey) -> Result<Vec<ContributionRecord>> {
+        self.query_contributions("campaign", campaign)
+    }
+
+    /// Contributions made by a wallet across campaigns, newest first
+    pub fn contributions_by(&self, contributor: &Pubkey) -> Result<Vec<ContributionRecord>> {
+        self.query_contributions("contributor", contributor)
+    }
+
+    fn query_contributions(&self, column: &str, key: &Pubkey) -> Result<Vec<ContributionRecord>> {
+        let mut statement = self.conn.prepare(&format!(
+            "SELECT signature, slot, campaign, contributor, amount, contribution_total,
+                    amount_raised, backer_count, timestamp
+             FROM contributions WHERE {column} = ?1
+             ORDER BY timestamp DESC, slot DESC, event_index DESC"
+        ))?;
+        let rows = statement.query_map([key.to_string()], |row| {
+            Ok(ContributionRecord {
+                signature: row.get(0)?,
+                slot: row.get(1)?,
+                campaign: row.get(2)?,
+                contributor: row.get(3)?,
+                amount: row.get(4)?,
+                contribution_total: row.get(5)?,
+                amount_raised: row.get(6)?,
+                backer_count: row.get(7)?,
+                timestamp: row.get(8)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Contributions, withdrawals, refunds and unpledges of a campaign, newest
+    /// first
+    pub fn activity(&self, campaign: &Pubkey, limit: u32) -> Result<Vec<ActivityRecord>> {
+        let mut statement = self.conn.prepare(
+            "SELECT 0, signature, slot, event_index, campaign, contributor, amount, timestamp
+                 FROM contributions WHERE campaign = ?1
+             UNION ALL
+             SELECT 1, signature, slot, event_index, campaign, creator, amount, timestamp
+                 FROM withdrawals WHERE campaign = ?1
+             UNION ALL
+             SELECT 2, signature, slot, event_index, campaign, contributor, amount, timestamp
+                 FROM refunds WHERE campaign = ?1
+             UNION ALL
+             SELECT 3, signature, slot, event_index, campaign, contributor, amount, timestamp
+                 FROM unpledges WHERE campaign = ?1
+             ORDER BY timestamp DESC, slot DESC, event_index DESC
+             LIMIT ?2",
+        )?;
+        let rows = statement.query_map(params![campaign.to_string(), limit], |row| {
+            let kind = match row.get::<_, u8>(0)? {
+                0 => ActivityKind::Contribution,
+                1 => ActivityKind::Withdrawal,
+                2 => ActivityKind::Refund,
+                _ => ActivityKind::Unpledge,
+            };
+            Ok(ActivityRecord {
+                kind,
+                signature: row.get(1)?,
+                slot: row.get(2)?,
+                campaign: row.get(4)?,
+                account: row.get(5)?,
+                amount: row.get(6)?,
+                timestamp: row.get(7)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+}
+
+const CAMPAIGN_COLUMNS: &str = "SELECT address, campaign_id, creator, title, short_description,
+    category, cover_image_url, story_url, asset, mint, funding_goal, deadline, created_at,
+    fee_bps, vote_quorum_bps, vote_approval_bps, allow_early_close, status, amount_raised,
+    backer_count, released_amount, refunded_amount, moderation_reason, finalized_at";
+
+fn campaign_record(row: &Row) -> rusqlite::Result<CampaignRecord> {
+    Ok(CampaignRecord {
+        address: row.get(0)?,
+        campaign_id: row.get(1)?,
+        creator: row.get(2)?,
+        title: row.get(3)?,
+        short_description: row.get(4)?,
+        category: row.get(5)?,
+        cover_image_url: row.get(6)?,
+        story_url: row.get(7)?,
+        asset: row.get(8)?,
+        mint: row.get(9)?,
+        funding_goal: row.get(10)?,
+        deadline: row.get(11)?,
+        created_at: row.get(12)?,
+        fee_bps: row.get(13)?,
+        vote_quorum_bps: row.get(14)?,
+        vote_approval_bps: row.get(15)?,
+        allow_early_close: row.get(16)?,
+        status: row.get(17)?,
+        amount_raised: row.get(18)?,
+        backer_count: row.get(19)?,
+        released_amount: row.get(20)?,
+        refunded_amount: row.get(21)?,
+        moderation_reason: row.get(22)?,
+        finalized_at: row.get(23)?,
+    })
+}
+
+fn apply_event(
+    db: &Connection,
+    tx: &ConfirmedTransaction,
+    index: usize,
+    event: ProgramEvent,
+) -> Result<()> {
+    let signature = tx.signature();
+    match event {
+        ProgramEvent::CampaignCreated(event) => {
+            db.execute(
+                "INSERT OR REPLACE INTO campaigns
+                    (address, campaign_id, creator, asset, mint, funding_goal, deadline,
+                     created_at, fee_bps, status)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    event.campaign.to_string(),
+                    integer("campaign_id", event.campaign_id)?,
+                    event.creator.to_string(),
+                    format!("{:?}", event.asset),
+                    event.mint.to_string(),
+                    integer("funding_goal", event.funding_goal)?,
+                    event.deadline,
+                    event.timestamp,
+                    event.fee_bps,
+                    status_name(CampaignStatus::Draft),
+                ],
+            )?;
+        }
+        ProgramEvent::CampaignMetadataUpdated(event) => {
+            db.execute(
+                "UPDATE campaigns SET title = ?2, short_description = ?3, cover_image_url = ?4,
+                    story_url = ?5
+                 WHERE address = ?1",
+                params![
+                    event.campaign.to_string(),
+                    event.title,
+                    event.short_description,
+                    event.cover_image_url,
+                    event.story_url,
+                ],
+            )?;
+        }
+        ProgramEvent::CampaignLaunched(event) => {
+            db.execute(
+                "UPDATE campaigns SET deadline = ?2 WHERE address = ?1",
+                params![event.campaign.to_string(), event.deadline],
+            )?;
+            set_status(db, &event.campaign, CampaignStatus::Active)?;
+        }
+        ProgramEvent::ContributionMade(event) => {
+            db.execute(
+                "INSERT INTO contributions
+                    (signature, event_index, slot, campaign, contributor, amount,
+                     contribution_total, amount_raised, backer_count, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    signature,
+                    index,
+                    integer("slot", tx.slot)?,
+                    event.campaign.to_string(),
+                    event.contributor.to_string(),
+                    integer("amount", event.amount)?,
+                    integer("contribution_total", event.contribution_total)?,
+                    integer("amount_raised", event.amount_raised)?,
+                    integer("backer_count", event.backer_count)?,
+                    event.timestamp,
+                ],
+            )?;
+            db.execute(
+                "UPDATE campaigns SET amount_raised = ?2, backer_count = ?3 WHERE address = ?1",
+                params![
+                    event.campaign.to_string(),
+                    integer("amount_raised", event.amount_raised)?,
+                    integer("backer_count", event.backer_count)?
+                ],
+            )?;
+            // A backer claims a tier once, later contributions repeat it
+            if let Some(tier_index) = event.reward_tier {
+                db.execute(
+                    "INSERT OR IGNORE INTO reward_claims
+                        (campaign, contributor, tier_index, claimed_at)
+                     VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        event.campaign.to_string(),
+                        event.contributor.to_string(),
+                        tier_index,
+                        event.timestamp,
+                    ],
+                )?;
+            }
+        }
+        ProgramEvent::PledgeWithdrawn(event) => {
+            db.execute(
+                "INSERT INTO unpledges
+                    (signature, event_index, slot, campaign, contributor, amount,
+                     contribution_total, amount_raised, backer_count, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    signature,
+                    index,
+                    integer("slot", tx.slot)?,
+                    event.campaign.to_string(),
+                    event.contributor.to_string(),
+                    integer("amount", event.amount)?,
+                    integer("contribution_total", event.contribution_total)?,
+                    integer("amount_raised", event.amount_raised)?,
+                    integer("backer_count", event.backer_count)?,
+                    event.timestamp,
+                ],
+            )?;
+            db.execute(
+                "UPDATE campaigns SET amount_raised = ?2, backer_count = ?3 WHERE address = ?1",
+                params![
+                    event.campaign.to_string(),
+                    integer("amount_raised", event.amount_raised)?,
+                    integer("backer_count", event.backer_count)?
+                ],
+            )?;
+            // Dropping below the tier's minimum gives the reward back
+            if event.reward_tier.is_none() {
+                db.execute(
+                    "DELETE FROM reward_claims WHERE campaign = ?1 AND contributor = ?2",
+                    params![event.campaign.to_string(), event.contributor.to_string()],
+                )?;
+            }
+        }
+        ProgramEvent::CampaignFinalized(event) => {
+            db.execute(
+                "UPDATE campaigns SET status = ?2, finalized_at = ?3 WHERE address = ?1",
+                params![
+                    event.campaign.to_string(),
+                    status_name(event.status),
+                    event.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::FundsWithdrawn(event) => {
+            db.execute(
+                "INSERT INTO withdrawals
+                    (signature, event_index, slot, campaign, creator, milestone_index, amount,
+                     creator_amount, fee, released_amount, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                params![
+                    signature,
+                    index,
+                    integer("slot", tx.slot)?,
+                    event.campaign.to_string(),
+                    event.creator.to_string(),
+                    event.milestone_index,
+                    integer("amount", event.amount)?,
+                    integer("creator_amount", event.creator_amount)?,
+                    integer("fee", event.fee)?,
+                    integer("released_amount", event.released_amount)?,
+                    event.timestamp,
+                ],
+            )?;
+            db.execute(
+                "UPDATE campaigns SET released_amount = ?2 WHERE address = ?1",
+                params![
+                    event.campaign.to_string(),
+                    integer("released_amount", event.released_amount)?
+                ],
+            )?;
+        }
+        ProgramEvent::MilestoneAdded(event) => {
+            db.execute(
+                "INSERT OR REPLACE INTO milestones
+                    (address, campaign, milestone_index, target_amount, payout_bps, status)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    event.milestone.to_string(),
+                    event.campaign.to_string(),
+                    event.milestone_index,
+                    integer("target_amount", event.target_amount)?,
+                    event.payout_bps,
+                    milestone_status::PENDING,
+                ],
+            )?;
+        }
+        ProgramEvent::MilestonePayoutReassigned(event) => {
+            db.execute(
+                "UPDATE milestones SET payout_bps = ?2 WHERE address = ?1",
+                params![event.milestone.to_string(), event.payout_bps],
+            )?;
+        }
+        ProgramEvent::MilestoneProofSubmitted(event) => {
+            db.execute(
+                "UPDATE milestones SET proof_url = ?2, status = ?3 WHERE address = ?1",
+                params![
+                    event.milestone.to_string(),
+                    event.proof_url,
+                    milestone_status::VOTING,
+                ],
+            )?;
+        }
+        ProgramEvent::MilestoneVoted(event) => {
+            db.execute(
+                "INSERT OR REPLACE INTO votes (milestone, voter, approve, voted_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    event.milestone.to_string(),
+                    event.voter.to_string(),
+                    event.approve,
+                    event.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::MilestoneCompleted(event) => {
+            db.execute(
+                "UPDATE milestones SET status = ?2, yes_weight = ?3, no_weight = ?4,
+                    completed_at = ?5
+                 WHERE address = ?1",
+                params![
+                    event.milestone.to_string(),
+                    milestone_status::APPROVED,
+                    integer("yes_weight", event.yes_weight)?,
+                    integer("no_weight", event.no_weight)?,
+                    event.timestamp,
+                ],
+            )?;
+        }
+        ProgramEvent::MilestoneQuorumMissed(event) => {
+            // Back to pending until the creator submits proof again
+            db.execute(
+                "UPDATE milestones SET status = ?2 WHERE address = ?1",
+                params![event.milestone.to_string(), milestone_status::PENDING],
+            )?;
+        }
+        ProgramEvent::MilestoneReleased(event) => {
+            db.execute(
+                "UPDATE milestones SET status = ?2 WHERE address = ?1",
+                params![event.milestone.to_string(), milestone_status::RELEASED],
+            )?;
+        }
+        ProgramEvent::CampaignFailed(event) => {
+            db.execute(
+                "UPDATE milestones SET status = ?2, yes_weight = ?3, no_weight = ?4,
+                    completed_at = ?5
+                 WHERE address = ?1",
+                params![
+                    event.milestone.to_string(),
+                    milestone_status::REJECTED,
+                    integer("yes_weight", event.yes_weight)?,
+                    integer("no_weight", event.no_weight)?,
+                    event.timestamp,
+                ],
+            )?;
+            set_status(db, &event.campaign, CampaignStatus::Failed)?;
+        }
+        ProgramEvent::CampaignCompleted(event) => {
+            set_status(db, &event.campaign, CampaignStatus::Completed)?;
+        }
+        ProgramEvent::CampaignModerated(event) => {
+            set_status(db, &event.campaign, event.status)?;
+            set_moderation_reason(db, &event.campaign, event.reason)?;
+        }
+        ProgramEvent::RewardTierAdded(event) => {
+            db.execute(
+                "INSERT OR REPLACE INTO reward_tiers
+                    (address, campaign, tier_index, min_pledge, max_supply, metadata_url)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    event.reward_tier.to_string(),
+                    event.campaign.to_string(),
+                    event.tier_index,
+                    integer("min_pledge", event.min_pledge)?,
+                    event.max_supply,
+                    event.metadata_url,
+                ],
+            )?;
+        }
+        ProgramEvent::StretchGoalAdded(event) => {
+            db.execute(
+                "INSERT OR REPLACE INTO stretch_goals
+                    (address, campaign, goal_index, threshold, metadata_url)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    event.stretch_goal.to_string(),
+                    event.campaign.to_string(),
+                    event.goal_index,
+                    integer("threshold", event.threshold)?,
+                    event.metadata_url,
+                ],
+            )?;
+        }
+        ProgramEvent::StretchGoalUnlocked(event) => {
+            db.execute(
+                "UPDATE stretch_goals SET unlocked_at = ?2 WHERE address = ?1",
+                params![event.stretch_goal.to_string(), event.timestamp],
+            )?;
+        }
+        ProgramEvent::CampaignClosed(event) => {
+            set_status(db, &event.campaign, CampaignStatus::Cancelled)?;
+        }
+        ProgramEvent::RefundClaimed(event) => {
+            db.execute(
+                "INSERT INTO refunds
+                    (signature, event_index, slot, campaign, contributor, amount,
+                     contribution_amount, partial, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                params![
+                    signature,
+                    index,
+                    integer("slot", tx.slot)?,
+                    event.campaign.to_string(),
+                    event.contributor.to_string(),
+                    integer("amount", event.amount)?,
+                    integer("contribution_amount", event.contribution_amount)?,
+                    event.partial,
+                    event.timestamp,
+                ],
+            )?;
+            // Summed here rather than in SQL, which turns an overflowing
+            // integer into a float
+            let refunded_amount: Option<u64> = db
+                .query_row(
+                    "SELECT refunded_amount FROM campaigns WHERE address = ?1",
+                    [event.campaign.to_string()],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            if let Some(refunded_amount) = refunded_amount {
+                db.execute(
+                    "UPDATE campaigns SET refunded_amount = ?2, amount_raised = ?3, backer_count = ?4
+                     WHERE address = ?1",
+                    params![
+                        event.campaign.to_string(),
+                        integer(
+                            "refunded_amount",
+                            refunded_amount.saturating_add(event.amount)
+                        )?,
+                        integer("amount_raised", event.amount_raised)?,
+                        integer("backer_count", event.backer_count)?
+                    ],
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn apply_instruction(db: &Connection, instruction: ProgramInstruction) -> Result<()> {
+    match instruction {
+        ProgramInstruction::CreateCampaign {
+            campaign,
+            title,
+            short_description,
+            category,
+            cover_image_url,
+            story_url,
+            asset: _,
+            vote_quorum_bps,
+            vote_approval_bps,
+            allow_early_close,
+        } => {
+            db.execute(
+                "UPDATE campaigns SET title = ?2, short_description = ?3, category = ?4,
+                    cover_image_url = ?5, story_url = ?6, vote_quorum_bps = ?7,
+                    vote_approval_bps = ?8, allow_early_close = ?9
+                 WHERE address = ?1",
+                params![
+                    campaign.to_string(),
+                    title,
+                    short_description,
+                    format!("{category:?}"),
+                    cover_image_url,
+                    story_url,
+                    vote_quorum_bps,
+                    vote_approval_bps,
+                    allow_early_close,
+                ],
+            )?;
+        }
+        ProgramInstruction::AddMilestone { milestone, title } => {
+            db.execute(
+                "UPDATE milestones SET title = ?2 WHERE address = ?1",
+                params![milestone.to_string(), title],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Converts a `u64` for an INTEGER column. SQLite integers are signed, so
+/// values past `i64::MAX` are rejected rather than stored wrapped.
+fn integer(column: &'static str, value: u64) -> Result<i64> {
+    i64::try_from(value).map_err(|_| IndexerError::IntegerOutOfRange { column, value })
+}
+
+fn set_status(db: &Connection, campaign: &Pubkey, status: CampaignStatus) -> Result<()> {
+    db.execute(
+        "UPDATE campaigns SET status = ?2 WHERE address = ?1",
+        params![campaign.to_string(), status_name(status)],
+    )?;
+    Ok(())
+}
+
+fn set_moderation_reason(db: &Connection, campaign: &Pubkey, reason: u16) -> Result<()> {
+    db.execute(
+        "UPDATE campaigns SET moderation_reason = ?2 WHERE address = ?1",
+        params![campaign.to_string(), reason],
+    )?;
+    Ok(())
+}
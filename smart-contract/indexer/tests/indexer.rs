@@ -0,0 +1,1117 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{Event, InstructionData};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use hope_rise::events::*;
+use hope_rise::instruction;
+use hope_rise::state::{CampaignStatus, Category, FundingAsset};
+use hope_rise_indexer::source::{
+    CompiledInstruction, EncodedMessage, EncodedTransaction, TransactionMeta,
+};
+use hope_rise_indexer::store::{milestone_status, ActivityKind};
+use hope_rise_indexer::{api, sync, ConfirmedTransaction, FileSource, IndexerError, Store};
+use serde_json::json;
+
+const START: i64 = 1_700_000_000;
+const DAY: i64 = 86_400;
+
+/// Builds `getTransaction`-shaped transactions, one slot and one minute apart
+struct Ledger {
+    transactions: Vec<ConfirmedTransaction>,
+    slot: u64,
+    time: i64,
+}
+
+impl Ledger {
+    fn new() -> Self {
+        Self {
+            transactions: Vec::new(),
+            slot: 100,
+            time: START,
+        }
+    }
+
+    /// Records a successful transaction whose `hope_rise` invocation logged `events`
+    fn push(&mut self, instructions: &[Instruction], events: &[Vec<u8>]) -> String {
+        let mut logs = vec![format!("Program {} invoke [1]", hope_rise::ID)];
+        logs.extend(
+            events
+                .iter()
+                .map(|event| format!("Program data: {}", BASE64_STANDARD.encode(event))),
+        );
+        logs.push(format!("Program {} success", hope_rise::ID));
+        self.push_with(instructions, logs, None)
+    }
+
+    fn push_with(
+        &mut self,
+        instructions: &[Instruction],
+        logs: Vec<String>,
+        err: Option<serde_json::Value>,
+    ) -> String {
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut index = |key: Pubkey| match keys.iter().position(|k| *k == key) {
+            Some(position) => position as u8,
+            None => {
+                keys.push(key);
+                (keys.len() - 1) as u8
+            }
+        };
+        let compiled = instructions
+            .iter()
+            .map(|ix| CompiledInstruction {
+                program_id_index: index(ix.program_id),
+                accounts: ix.accounts.iter().map(|meta| index(meta.pubkey)).collect(),
+                data: bs58::encode(&ix.data).into_string(),
+            })
+            .collect();
+
+        self.slot += 1;
+        self.time += 60;
+        let signature = bs58::encode([self.transactions.len() as u8 + 1; 64]).into_string();
+        self.transactions.push(ConfirmedTransaction {
+            slot: self.slot,
+            block_time: Some(self.time),
+            meta: Some(TransactionMeta {
+                err,
+                log_messages: Some(logs),
+                ..TransactionMeta::default()
+            }),
+            transaction: EncodedTransaction {
+                signatures: vec![signature.clone()],
+                message: EncodedMessage {
+                    account_keys: keys.iter().map(Pubkey::to_string).collect(),
+                    instructions: compiled,
+                },
+            },
+        });
+        signature
+    }
+
+    fn advance(&mut self, seconds: i64) {
+        self.time += seconds;
+    }
+
+    fn index(&self) -> Store {
+        let mut store = Store::open_in_memory().unwrap();
+        for transaction in &self.transactions {
+            store.apply(transaction).unwrap();
+        }
+        store
+    }
+}
+
+/// A `hope_rise` instruction; the indexer only reads the accounts it needs, by position
+fn ix(data: impl InstructionData, accounts: &[Pubkey]) -> Instruction {
+    Instruction {
+        program_id: hope_rise::ID,
+        accounts: accounts
+            .iter()
+            .map(|key| AccountMeta::new(*key, false))
+            .collect(),
+        data: data.data(),
+    }
+}
+
+fn event(event: impl Event) -> Vec<u8> {
+    event.data()
+}
+
+fn filler() -> Pubkey {
+    Pubkey::new_unique()
+}
+
+struct Fixture {
+    campaign: Pubkey,
+    creator: Pubkey,
+    mint: Pubkey,
+}
+
+impl Fixture {
+    fn new() -> Self {
+        Self {
+            campaign: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+        }
+    }
+
+    /// Creates the campaign with a 30 day funding period
+    fn create(&self, ledger: &mut Ledger, funding_goal: u64) {
+        let args = instruction::CreateCampaign {
+            title: "Clean water".to_string(),
+            short_description: "Wells for the village".to_string(),
+            category: Category::Community,
+            cover_image_url: "ipfs://cover".to_string(),
+            story_url: "ipfs://story".to_string(),
+            funding_goal,
+            duration_days: 30,
+            asset: FundingAsset::Token,
+            vote_quorum_bps: 2_000,
+            vote_approval_bps: 6_000,
+            allow_early_close: true,
+            transferable_receipts: false,
+            unpledge_cutoff_hours: 48,
+        };
+        let created = CampaignCreated {
+            campaign: self.campaign,
+            creator: self.creator,
+            campaign_id: 7,
+            asset: FundingAsset::Token,
+            mint: self.mint,
+            funding_goal,
+            deadline: ledger.time + 60 + 30 * DAY,
+            fee_bps: 250,
+            timestamp: ledger.time + 60,
+        };
+        ledger.push(&[ix(args, &[self.campaign])], &[event(created)]);
+    }
+
+    /// Launches the campaign, restarting its 30 day funding period
+    fn launch(&self, ledger: &mut Ledger) {
+        let launched = CampaignLaunched {
+            campaign: self.campaign,
+            deadline: ledger.time + 60 + 30 * DAY,
+            timestamp: ledger.time + 60,
+        };
+        let instruction = ix(instruction::LaunchCampaign {}, &[self.campaign]);
+        ledger.push(&[instruction], &[event(launched)]);
+    }
+
+    fn fund(
+        &self,
+        ledger: &mut Ledger,
+        contributor: &Pubkey,
+        amount: u64,
+        totals: (u64, u64, u64),
+    ) -> String {
+        self.fund_tier(ledger, contributor, amount, totals, None)
+    }
+
+    /// Funds the campaign, the contribution holding `reward_tier` afterwards
+    fn fund_tier(
+        &self,
+        ledger: &mut Ledger,
+        contributor: &Pubkey,
+        amount: u64,
+        totals: (u64, u64, u64),
+        reward_tier: Option<u8>,
+    ) -> String {
+        let (contribution_total, amount_raised, backer_count) = totals;
+        let made = ContributionMade {
+            campaign: self.campaign,
+            contributor: *contributor,
+            amount,
+            contribution_total,
+            amount_raised,
+            backer_count,
+            reward_tier,
+            timestamp: ledger.time + 60,
+        };
+        let args = instruction::FundCampaign {
+            amount,
+            reward_tier,
+        };
+        ledger.push(&[ix(args, &[self.campaign])], &[event(made)])
+    }
+
+    /// Withdraws part of a pledge, the contribution holding `reward_tier`
+    /// afterwards
+    fn unpledge(
+        &self,
+        ledger: &mut Ledger,
+        contributor: &Pubkey,
+        amount: u64,
+        totals: (u64, u64, u64),
+        reward_tier: Option<u8>,
+    ) -> String {
+        let (contribution_total, amount_raised, backer_count) = totals;
+        let withdrawn = PledgeWithdrawn {
+            campaign: self.campaign,
+            contributor: *contributor,
+            amount,
+            contribution_total,
+            amount_raised,
+            backer_count,
+            reward_tier,
+            timestamp: ledger.time + 60,
+        };
+        let args = instruction::Unpledge { amount };
+        ledger.push(&[ix(args, &[self.campaign])], &[event(withdrawn)])
+    }
+
+    fn finalize(&self, ledger: &mut Ledger, status: CampaignStatus, totals: (u64, u64)) {
+        let (amount_raised, backer_count) = totals;
+        let finalized = CampaignFinalized {
+            campaign: self.campaign,
+            status,
+            amount_raised,
+            backer_count,
+            timestamp: ledger.time + 60,
+        };
+        let instruction = ix(instruction::FinalizeCampaign {}, &[self.campaign]);
+        ledger.push(&[instruction], &[event(finalized)]);
+    }
+
+    fn withdrawn(&self, ledger: &Ledger, milestone_index: Option<u8>, amount: u64) -> Vec<u8> {
+        event(FundsWithdrawn {
+            campaign: self.campaign,
+            creator: self.creator,
+            milestone_index,
+            amount,
+            creator_amount: amount,
+            fee: 0,
+            released_amount: amount,
+            timestamp: ledger.time + 60,
+        })
+    }
+
+    fn submit_proof(&self, ledger: &mut Ledger, index: u8, proof_url: &str) {
+        let milestone = self.milestone(index);
+        let submitted = MilestoneProofSubmitted {
+            campaign: self.campaign,
+            milestone,
+            milestone_index: index,
+            proof_url: proof_url.to_string(),
+            voting_ends_at: ledger.time + 60 + 3 * DAY,
+            timestamp: ledger.time + 60,
+        };
+        let proof = instruction::SubmitMilestoneProof {
+            proof_url: proof_url.to_string(),
+        };
+        let accounts = [self.campaign, filler(), milestone];
+        ledger.push(&[ix(proof, &accounts)], &[event(submitted)]);
+    }
+
+    /// Casts `voter`'s vote, the milestone holding `tally` (yes, no) afterwards
+    fn vote(
+        &self,
+        ledger: &mut Ledger,
+        index: u8,
+        voter: &Pubkey,
+        approve: bool,
+        weight: u64,
+        tally: (u64, u64),
+    ) {
+        let milestone = self.milestone(index);
+        let (yes_weight, no_weight) = tally;
+        let voted = MilestoneVoted {
+            campaign: self.campaign,
+            milestone,
+            milestone_index: index,
+            voter: *voter,
+            approve,
+            weight,
+            yes_weight,
+            no_weight,
+            timestamp: ledger.time + 60,
+        };
+        let accounts = [
+            self.campaign,
+            filler(),
+            milestone,
+            filler(),
+            filler(),
+            *voter,
+        ];
+        let vote = ix(instruction::VoteMilestone { approve }, &accounts);
+        ledger.push(&[vote], &[event(voted)]);
+    }
+
+    fn moderated(&self, ledger: &Ledger, status: CampaignStatus, reason: u16) -> Vec<u8> {
+        event(CampaignModerated {
+            campaign: self.campaign,
+            status,
+            reason,
+            timestamp: ledger.time + 60,
+        })
+    }
+
+    fn milestone(&self, index: u8) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"milestone", self.campaign.as_ref(), &[index]],
+            &hope_rise::ID,
+        )
+        .0
+    }
+}
+
+#[test]
+fn campaign_lifecycle_builds_records_and_activity() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    fixture.create(&mut ledger, 3_000);
+    let created_at = ledger.time;
+    ledger.advance(DAY);
+    fixture.launch(&mut ledger);
+    let launched_at = ledger.time;
+    fixture.fund(&mut ledger, &alice, 1_000, (1_000, 1_000, 1));
+    fixture.fund(&mut ledger, &bob, 500, (500, 1_500, 2));
+    let last = fixture.fund(&mut ledger, &alice, 1_500, (2_500, 3_000, 2));
+    fixture.finalize(&mut ledger, CampaignStatus::Succeeded, (3_000, 2));
+    let finalized_at = ledger.time;
+
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.campaign_id, 7);
+    assert_eq!(campaign.creator, fixture.creator.to_string());
+    assert_eq!(campaign.title, "Clean water");
+    assert_eq!(campaign.short_description, "Wells for the village");
+    assert_eq!(campaign.category, "Community");
+    assert_eq!(campaign.cover_image_url, "ipfs://cover");
+    assert_eq!(campaign.story_url, "ipfs://story");
+    assert_eq!(campaign.asset, "Token");
+    assert_eq!(campaign.mint, fixture.mint.to_string());
+    assert_eq!(campaign.funding_goal, 3_000);
+    assert_eq!(campaign.created_at, created_at);
+    assert_eq!(campaign.deadline, launched_at + 30 * DAY);
+    assert_eq!(campaign.fee_bps, 250);
+    assert_eq!(campaign.vote_quorum_bps, 2_000);
+    assert_eq!(campaign.vote_approval_bps, 6_000);
+    assert!(campaign.allow_early_close);
+    assert_eq!(campaign.status, "Succeeded");
+    assert_eq!(campaign.amount_raised, 3_000);
+    assert_eq!(campaign.backer_count, 2);
+    assert_eq!(campaign.finalized_at, Some(finalized_at));
+
+    let contributions = store.contributions(&fixture.campaign).unwrap();
+    let amounts: Vec<_> = contributions.iter().map(|c| c.amount).collect();
+    assert_eq!(amounts, vec![1_500, 500, 1_000]);
+    assert_eq!(contributions[0].signature, last);
+    assert_eq!(contributions[0].contribution_total, 2_500);
+    assert_eq!(store.contributions_by(&alice).unwrap().len(), 2);
+
+    let payout = fixture.withdrawn(&ledger, None, 3_000);
+    let completed = event(CampaignCompleted {
+        campaign: fixture.campaign,
+        released_amount: 3_000,
+        timestamp: ledger.time + 60,
+    });
+    let withdraw = ix(instruction::WithdrawFunds {}, &[fixture.campaign]);
+    ledger.push(&[withdraw], &[payout, completed]);
+
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.status, "Completed");
+    assert_eq!(campaign.released_amount, 3_000);
+
+    let feed: Vec<_> = store
+        .activity(&fixture.campaign, 10)
+        .unwrap()
+        .into_iter()
+        .map(|entry| (entry.kind, entry.account, entry.amount))
+        .collect();
+    assert_eq!(
+        feed,
+        vec![
+            (ActivityKind::Withdrawal, fixture.creator.to_string(), 3_000),
+            (ActivityKind::Contribution, alice.to_string(), 1_500),
+            (ActivityKind::Contribution, bob.to_string(), 500),
+            (ActivityKind::Contribution, alice.to_string(), 1_000),
+        ]
+    );
+    assert_eq!(store.activity(&fixture.campaign, 2).unwrap().len(), 2);
+
+    let args = instruction::UpdateCampaignMetadata {
+        title: "Clean water, phase 2".to_string(),
+        short_description: "A third well".to_string(),
+        cover_image_url: "ipfs://cover-2".to_string(),
+        story_url: "ipfs://story-2".to_string(),
+    };
+    let updated = CampaignMetadataUpdated {
+        campaign: fixture.campaign,
+        title: args.title.clone(),
+        short_description: args.short_description.clone(),
+        cover_image_url: args.cover_image_url.clone(),
+        story_url: args.story_url.clone(),
+        timestamp: ledger.time + 60,
+    };
+    ledger.push(&[ix(args, &[fixture.campaign])], &[event(updated)]);
+
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.title, "Clean water, phase 2");
+    assert_eq!(campaign.short_description, "A third well");
+    assert_eq!(campaign.cover_image_url, "ipfs://cover-2");
+    assert_eq!(campaign.story_url, "ipfs://story-2");
+    assert_eq!(campaign.category, "Community");
+}
+
+#[test]
+fn milestone_votes_releases_and_rejections() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    fixture.create(&mut ledger, 1_000);
+    for (index, title, payout_bps) in [(0, "Drill", 6_000), (1, "Pump", 4_000)] {
+        let milestone = fixture.milestone(index);
+        let args = instruction::AddMilestone {
+            title: title.to_string(),
+            target_amount: 500,
+            payout_bps,
+            stretch_goal: None,
+        };
+        let added = MilestoneAdded {
+            campaign: fixture.campaign,
+            milestone,
+            milestone_index: index,
+            target_amount: 500,
+            payout_bps,
+            allocated_bps: 6_000 + (index as u16) * payout_bps,
+            stretch_goal: None,
+            timestamp: ledger.time + 60,
+        };
+        let accounts = [fixture.campaign, filler(), milestone];
+        ledger.push(&[ix(args, &accounts)], &[event(added)]);
+    }
+    fixture.launch(&mut ledger);
+    fixture.fund(&mut ledger, &alice, 600, (600, 600, 1));
+    fixture.fund(&mut ledger, &bob, 400, (400, 1_000, 2));
+    fixture.finalize(&mut ledger, CampaignStatus::Succeeded, (1_000, 2));
+
+    let (first, second) = (fixture.milestone(0), fixture.milestone(1));
+    fixture.submit_proof(&mut ledger, 0, "ipfs://drilled");
+    fixture.vote(&mut ledger, 0, &alice, true, 600, (600, 0));
+    fixture.vote(&mut ledger, 0, &bob, false, 400, (600, 400));
+    let completed = MilestoneCompleted {
+        campaign: fixture.campaign,
+        milestone: first,
+        milestone_index: 0,
+        yes_weight: 600,
+        no_weight: 400,
+        timestamp: ledger.time + 60,
+    };
+    let complete = ix(
+        instruction::CompleteMilestone {},
+        &[fixture.campaign, filler(), first],
+    );
+    ledger.push(&[complete], &[event(completed)]);
+    let completed_at = ledger.time;
+
+    let released = event(MilestoneReleased {
+        campaign: fixture.campaign,
+        milestone: first,
+        milestone_index: 0,
+        released_bps: 6_000,
+        timestamp: ledger.time + 60,
+    });
+    let payout = fixture.withdrawn(&ledger, Some(0), 600);
+    let release = ix(instruction::ReleaseMilestone {}, &[fixture.campaign, first]);
+    ledger.push(&[release], &[released, payout]);
+
+    let store = ledger.index();
+    let milestones = store.milestones(&fixture.campaign).unwrap();
+    assert_eq!(milestones.len(), 2);
+    assert_eq!(milestones[0].address, first.to_string());
+    assert_eq!(milestones[0].title, "Drill");
+    assert_eq!(milestones[0].payout_bps, 6_000);
+    assert_eq!(milestones[0].proof_url, "ipfs://drilled");
+    assert_eq!(milestones[0].status, milestone_status::RELEASED);
+    assert_eq!((milestones[0].yes_votes, milestones[0].no_votes), (1, 1));
+    assert_eq!(milestones[0].yes_weight, Some(600));
+    assert_eq!(milestones[0].no_weight, Some(400));
+    assert_eq!(milestones[0].completed_at, Some(completed_at));
+    assert_eq!(milestones[1].title, "Pump");
+    assert_eq!(milestones[1].status, milestone_status::PENDING);
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    // The second tranche is still locked
+    assert_eq!(campaign.status, "Succeeded");
+    assert_eq!(campaign.released_amount, 600);
+
+    fixture.submit_proof(&mut ledger, 1, "ipfs://pumped");
+    let missed = MilestoneQuorumMissed {
+        campaign: fixture.campaign,
+        milestone: second,
+        milestone_index: 1,
+        yes_weight: 0,
+        no_weight: 0,
+        timestamp: ledger.time + 60,
+    };
+    let complete = ix(
+        instruction::CompleteMilestone {},
+        &[fixture.campaign, filler(), second],
+    );
+    ledger.push(&[complete], &[event(missed)]);
+    let store = ledger.index();
+    let milestones = store.milestones(&fixture.campaign).unwrap();
+    // Nobody voted, so the creator gets to submit proof again
+    assert_eq!(milestones[1].status, milestone_status::PENDING);
+
+    fixture.submit_proof(&mut ledger, 1, "ipfs://pumped-again");
+    fixture.vote(&mut ledger, 1, &bob, false, 400, (0, 400));
+    let failed = CampaignFailed {
+        campaign: fixture.campaign,
+        milestone: second,
+        milestone_index: 1,
+        yes_weight: 0,
+        no_weight: 400,
+        timestamp: ledger.time + 60,
+    };
+    let complete = ix(
+        instruction::CompleteMilestone {},
+        &[fixture.campaign, filler(), second],
+    );
+    ledger.push(&[complete], &[event(failed)]);
+    let rejected_at = ledger.time;
+    let refund = RefundClaimed {
+        campaign: fixture.campaign,
+        contributor: alice,
+        amount: 240,
+        contribution_amount: 600,
+        partial: true,
+        amount_raised: 400,
+        backer_count: 1,
+        total_refunded: 600,
+        timestamp: ledger.time + 60,
+    };
+    let claim = ix(instruction::ClaimPartialRefund {}, &[fixture.campaign]);
+    ledger.push(&[claim], &[event(refund)]);
+
+    let store = ledger.index();
+    let milestones = store.milestones(&fixture.campaign).unwrap();
+    assert_eq!(milestones[1].status, milestone_status::REJECTED);
+    assert_eq!(milestones[1].proof_url, "ipfs://pumped-again");
+    assert_eq!((milestones[1].yes_votes, milestones[1].no_votes), (0, 1));
+    assert_eq!(milestones[1].yes_weight, Some(0));
+    assert_eq!(milestones[1].no_weight, Some(400));
+    assert_eq!(milestones[1].completed_at, Some(rejected_at));
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.status, "Failed");
+    assert_eq!(campaign.refunded_amount, 240);
+    assert_eq!((campaign.amount_raised, campaign.backer_count), (400, 1));
+    let latest = &store.activity(&fixture.campaign, 1).unwrap()[0];
+    assert_eq!(latest.kind, ActivityKind::Refund);
+    assert_eq!(latest.account, alice.to_string());
+    assert_eq!(latest.amount, 240);
+}
+
+#[test]
+fn reward_tiers_count_each_backer_once() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    fixture.create(&mut ledger, 3_000);
+    for (index, min_pledge, max_supply) in [(0, 100, 50), (1, 1_000, 5)] {
+        let reward_tier = Pubkey::new_unique();
+        let args = instruction::AddRewardTier {
+            min_pledge,
+            max_supply,
+            metadata_url: format!("ipfs://tier-{index}"),
+        };
+        let added = RewardTierAdded {
+            campaign: fixture.campaign,
+            reward_tier,
+            tier_index: index,
+            min_pledge,
+            max_supply,
+            metadata_url: args.metadata_url.clone(),
+            timestamp: ledger.time + 60,
+        };
+        ledger.push(&[ix(args, &[fixture.campaign])], &[event(added)]);
+    }
+    fixture.launch(&mut ledger);
+    fixture.fund(&mut ledger, &alice, 500, (500, 500, 1));
+    fixture.fund_tier(&mut ledger, &alice, 500, (1_000, 1_000, 1), Some(1));
+    fixture.fund_tier(&mut ledger, &alice, 200, (1_200, 1_200, 1), Some(1));
+    fixture.fund_tier(&mut ledger, &bob, 100, (100, 1_300, 2), Some(0));
+
+    let store = ledger.index();
+    let tiers: Vec<_> = store
+        .reward_tiers(&fixture.campaign)
+        .unwrap()
+        .into_iter()
+        .map(|tier| {
+            (
+                tier.tier_index,
+                tier.min_pledge,
+                tier.max_supply,
+                tier.claimed,
+            )
+        })
+        .collect();
+    assert_eq!(tiers, vec![(0, 100, 50, 1), (1, 1_000, 5, 1)]);
+
+    let url = format!("/campaigns/{}/rewards", fixture.campaign);
+    let response = api::route(&store, "GET", &url);
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body[1]["metadataUrl"], "ipfs://tier-1");
+    assert_eq!(response.body[1]["claimed"], 1);
+}
+
+#[test]
+fn unpledges_lower_totals_and_release_rewards() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+    fixture.create(&mut ledger, 3_000);
+    let args = instruction::AddRewardTier {
+        min_pledge: 1_000,
+        max_supply: 5,
+        metadata_url: "ipfs://tier".to_string(),
+    };
+    let added = RewardTierAdded {
+        campaign: fixture.campaign,
+        reward_tier: Pubkey::new_unique(),
+        tier_index: 0,
+        min_pledge: 1_000,
+        max_supply: 5,
+        metadata_url: args.metadata_url.clone(),
+        timestamp: ledger.time + 60,
+    };
+    ledger.push(&[ix(args, &[fixture.campaign])], &[event(added)]);
+    fixture.launch(&mut ledger);
+    fixture.fund_tier(&mut ledger, &alice, 1_500, (1_500, 1_500, 1), Some(0));
+    fixture.fund(&mut ledger, &bob, 500, (500, 2_000, 2));
+
+    fixture.unpledge(&mut ledger, &alice, 200, (1_300, 1_800, 2), Some(0));
+    let store = ledger.index();
+    assert_eq!(store.reward_tiers(&fixture.campaign).unwrap()[0].claimed, 1);
+
+    fixture.unpledge(&mut ledger, &alice, 1_000, (300, 800, 2), None);
+    fixture.unpledge(&mut ledger, &bob, 500, (0, 300, 1), None);
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.amount_raised, 300);
+    assert_eq!(campaign.backer_count, 1);
+    assert_eq!(store.reward_tiers(&fixture.campaign).unwrap()[0].claimed, 0);
+
+    let feed: Vec<_> = store
+        .activity(&fixture.campaign, 3)
+        .unwrap()
+        .into_iter()
+        .map(|entry| (entry.kind, entry.account, entry.amount))
+        .collect();
+    assert_eq!(
+        feed,
+        vec![
+            (ActivityKind::Unpledge, bob.to_string(), 500),
+            (ActivityKind::Unpledge, alice.to_string(), 1_000),
+            (ActivityKind::Unpledge, alice.to_string(), 200),
+        ]
+    );
+}
+
+#[test]
+fn stretch_goals_record_their_unlocks() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let alice = Pubkey::new_unique();
+    fixture.create(&mut ledger, 1_000);
+    let goals: Vec<_> = [(0, 1_500), (1, 2_000), (2, 5_000)]
+        .into_iter()
+        .map(|(index, threshold)| {
+            let stretch_goal = Pubkey::new_unique();
+            let args = instruction::AddStretchGoal {
+                threshold,
+                metadata_url: format!("ipfs://goal-{index}"),
+            };
+            let added = StretchGoalAdded {
+                campaign: fixture.campaign,
+                stretch_goal,
+                goal_index: index,
+                threshold,
+                metadata_url: args.metadata_url.clone(),
+                timestamp: ledger.time + 60,
+            };
+            ledger.push(&[ix(args, &[fixture.campaign])], &[event(added)]);
+            (stretch_goal, index, threshold)
+        })
+        .collect();
+    for (index, payout_bps, stretch_goal) in [(0, 4_000, None), (1, 6_000, Some(2))] {
+        let args = instruction::AddMilestone {
+            title: format!("Milestone {index}"),
+            target_amount: 0,
+            payout_bps,
+            stretch_goal,
+        };
+        let added = MilestoneAdded {
+            campaign: fixture.campaign,
+            milestone: fixture.milestone(index),
+            milestone_index: index,
+            target_amount: 0,
+            payout_bps,
+            allocated_bps: 4_000 + index as u16 * payout_bps,
+            stretch_goal,
+            timestamp: ledger.time + 60,
+        };
+        let accounts = [fixture.campaign, filler(), fixture.milestone(index)];
+        ledger.push(&[ix(args, &accounts)], &[event(added)]);
+    }
+    fixture.launch(&mut ledger);
+
+    // One pledge passing two thresholds unlocks both goals
+    let made = ContributionMade {
+        campaign: fixture.campaign,
+        contributor: alice,
+        amount: 2_500,
+        contribution_total: 2_500,
+        amount_raised: 2_500,
+        backer_count: 1,
+        reward_tier: None,
+        timestamp: ledger.time + 60,
+    };
+    let mut events = vec![event(made)];
+    for &(stretch_goal, goal_index, threshold) in &goals[..2] {
+        events.push(event(StretchGoalUnlocked {
+            campaign: fixture.campaign,
+            stretch_goal,
+            goal_index,
+            threshold,
+            amount_raised: 2_500,
+            timestamp: ledger.time + 60,
+        }));
+    }
+    let args = instruction::FundCampaign {
+        amount: 2_500,
+        reward_tier: None,
+    };
+    ledger.push(&[ix(args, &[fixture.campaign])], &events);
+    let unlocked_at = ledger.time;
+
+    let store = ledger.index();
+    let records: Vec<_> = store
+        .stretch_goals(&fixture.campaign)
+        .unwrap()
+        .into_iter()
+        .map(|goal| (goal.goal_index, goal.threshold, goal.unlocked_at))
+        .collect();
+    assert_eq!(
+        records,
+        vec![
+            (0, 1_500, Some(unlocked_at)),
+            (1, 2_000, Some(unlocked_at)),
+            (2, 5_000, None),
+        ]
+    );
+
+    let url = format!("/campaigns/{}/stretch-goals", fixture.campaign);
+    let response = api::route(&store, "GET", &url);
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body[2]["metadataUrl"], "ipfs://goal-2");
+    assert_eq!(response.body[2]["unlockedAt"], serde_json::Value::Null);
+
+    // The share gated on the goal still locked moves at finalization
+    let finalized = CampaignFinalized {
+        campaign: fixture.campaign,
+        status: CampaignStatus::Succeeded,
+        amount_raised: 2_500,
+        backer_count: 1,
+        timestamp: ledger.time + 60,
+    };
+    let mut events = vec![event(finalized)];
+    for (index, payout_bps) in [(0, 10_000), (1, 0)] {
+        events.push(event(MilestonePayoutReassigned {
+            campaign: fixture.campaign,
+            milestone: fixture.milestone(index),
+            milestone_index: index,
+            payout_bps,
+            stretch_goal: (index == 1).then_some(2),
+            timestamp: ledger.time + 60,
+        }));
+    }
+    let accounts = [
+        fixture.campaign,
+        filler(),
+        fixture.milestone(0),
+        fixture.milestone(1),
+    ];
+    ledger.push(&[ix(instruction::FinalizeCampaign {}, &accounts)], &events);
+    let store = ledger.index();
+    let payouts: Vec<_> = store
+        .milestones(&fixture.campaign)
+        .unwrap()
+        .into_iter()
+        .map(|milestone| milestone.payout_bps)
+        .collect();
+    assert_eq!(payouts, vec![10_000, 0]);
+}
+
+#[test]
+fn cancellation_and_moderation_update_status() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let alice = Pubkey::new_unique();
+    fixture.create(&mut ledger, 1_000);
+    fixture.launch(&mut ledger);
+    fixture.fund(&mut ledger, &alice, 400, (400, 400, 1));
+
+    let suspend = ix(
+        instruction::SuspendCampaign { reason: 3 },
+        &[fixture.campaign],
+    );
+    let suspended = fixture.moderated(&ledger, CampaignStatus::Suspended, 3);
+    ledger.push(&[suspend], &[suspended]);
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.status, "Suspended");
+    assert_eq!(campaign.moderation_reason, 3);
+
+    let unsuspend = ix(
+        instruction::UnsuspendCampaign { reason: 0 },
+        &[fixture.campaign],
+    );
+    let restored = fixture.moderated(&ledger, CampaignStatus::Active, 0);
+    ledger.push(&[unsuspend], &[restored]);
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.status, "Active");
+    assert_eq!(campaign.moderation_reason, 0);
+
+    let force_fail = ix(
+        instruction::ForceFailCampaign { reason: 4 },
+        &[fixture.campaign],
+    );
+    let failed = fixture.moderated(&ledger, CampaignStatus::Failed, 4);
+    ledger.push(&[force_fail], &[failed]);
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.status, "Failed");
+    assert_eq!(campaign.moderation_reason, 4);
+
+    let mut ledger = Ledger::new();
+    fixture.create(&mut ledger, 1_000);
+    fixture.launch(&mut ledger);
+    fixture.fund(&mut ledger, &alice, 400, (400, 400, 1));
+    let closed = CampaignClosed {
+        campaign: fixture.campaign,
+        creator: fixture.creator,
+        amount_raised: 400,
+        backer_count: 1,
+        timestamp: ledger.time + 60,
+    };
+    let close = ix(instruction::CloseCampaign {}, &[fixture.campaign]);
+    ledger.push(&[close], &[event(closed)]);
+    let refund = RefundClaimed {
+        campaign: fixture.campaign,
+        contributor: alice,
+        amount: 400,
+        contribution_amount: 400,
+        partial: false,
+        amount_raised: 0,
+        backer_count: 0,
+        total_refunded: 400,
+        timestamp: ledger.time + 60,
+    };
+    let claim = ix(instruction::ClaimRefund {}, &[fixture.campaign]);
+    ledger.push(&[claim], &[event(refund)]);
+
+    let store = ledger.index();
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.status, "Cancelled");
+    assert_eq!(campaign.refunded_amount, 400);
+    assert_eq!((campaign.amount_raised, campaign.backer_count), (0, 0));
+}
+
+#[test]
+fn data_logged_by_other_programs_is_not_an_event() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let alice = Pubkey::new_unique();
+    fixture.create(&mut ledger, 1_000);
+    fixture.launch(&mut ledger);
+
+    let other = Pubkey::new_unique();
+    let spoofed = BASE64_STANDARD.encode(event(ContributionMade {
+        campaign: fixture.campaign,
+        contributor: alice,
+        amount: 1_000,
+        contribution_total: 1_000,
+        amount_raised: 1_000,
+        backer_count: 1,
+        reward_tier: None,
+        timestamp: ledger.time,
+    }));
+    let logs = vec![
+        format!("Program {other} invoke [1]"),
+        format!("Program data: {spoofed}"),
+        format!("Program {} invoke [2]", hope_rise::ID),
+        format!("Program log: Instruction: FundCampaign"),
+        format!("Program {} success", hope_rise::ID),
+        format!("Program data: {spoofed}"),
+        format!("Program {other} success"),
+    ];
+    let outer = Instruction {
+        program_id: other,
+        accounts: vec![],
+        data: vec![],
+    };
+    ledger.push_with(&[outer], logs, None);
+
+    let store = ledger.index();
+    assert!(store.contributions(&fixture.campaign).unwrap().is_empty());
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.amount_raised, 0);
+}
+
+#[test]
+fn sync_is_incremental_and_skips_failed_transactions() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let alice = Pubkey::new_unique();
+    fixture.create(&mut ledger, 1_000);
+    fixture.launch(&mut ledger);
+
+    // A failed transaction keeps its logs but changed nothing
+    let made = event(ContributionMade {
+        campaign: fixture.campaign,
+        contributor: alice,
+        amount: 100,
+        contribution_total: 100,
+        amount_raised: 100,
+        backer_count: 1,
+        reward_tier: None,
+        timestamp: ledger.time,
+    });
+    let logs = vec![format!("Program data: {}", BASE64_STANDARD.encode(&made))];
+    let failed = ix(
+        instruction::FundCampaign {
+            amount: 100,
+            reward_tier: None,
+        },
+        &[fixture.campaign],
+    );
+    let failed_signature = ledger.push_with(
+        &[failed],
+        logs,
+        Some(json!({"InstructionError": [0, {"Custom": 6003}]})),
+    );
+
+    let path = std::env::temp_dir().join(format!(
+        "hope_rise-indexer-{}-sync.json",
+        std::process::id()
+    ));
+    let write = |ledger: &Ledger| {
+        std::fs::write(&path, serde_json::to_vec(&ledger.transactions).unwrap()).unwrap();
+    };
+    write(&ledger);
+
+    let mut store = Store::open_in_memory().unwrap();
+    let mut source = FileSource::new(&path);
+    assert_eq!(sync(&mut store, &mut source).unwrap(), 3);
+    assert_eq!(store.last_signature().unwrap(), Some(failed_signature));
+    assert!(store.contributions(&fixture.campaign).unwrap().is_empty());
+    assert_eq!(sync(&mut store, &mut source).unwrap(), 0);
+
+    fixture.fund(&mut ledger, &alice, 700, (700, 700, 1));
+    write(&ledger);
+    assert_eq!(sync(&mut store, &mut source).unwrap(), 1);
+    let contributions = store.contributions(&fixture.campaign).unwrap();
+    assert_eq!(contributions.len(), 1);
+    assert_eq!(contributions[0].amount, 700);
+
+    // Replaying the whole ledger adds nothing twice
+    for transaction in &ledger.transactions {
+        assert!(!store.apply(transaction).unwrap());
+    }
+    assert_eq!(store.contributions(&fixture.campaign).unwrap().len(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn amounts_past_the_sqlite_integer_range_are_rejected() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let alice = Pubkey::new_unique();
+    fixture.create(&mut ledger, 1_000);
+    fixture.launch(&mut ledger);
+    let amount = i64::MAX as u64 + 1;
+    fixture.fund(&mut ledger, &alice, amount, (amount, amount, 1));
+
+    let mut store = Store::open_in_memory().unwrap();
+    let (indexed, rejected) = ledger.transactions.split_at(2);
+    for transaction in indexed {
+        store.apply(transaction).unwrap();
+    }
+    let err = store.apply(&rejected[0]).unwrap_err();
+    assert!(matches!(
+        err,
+        IndexerError::IntegerOutOfRange {
+            column: "amount",
+            value,
+        } if value == amount
+    ));
+
+    // Nothing of the transaction is kept, so the cursor stays before it
+    assert_eq!(
+        store.last_signature().unwrap(),
+        Some(indexed[1].signature().to_string())
+    );
+    assert!(store.contributions(&fixture.campaign).unwrap().is_empty());
+    let campaign = store.campaign(&fixture.campaign).unwrap().unwrap();
+    assert_eq!(campaign.amount_raised, 0);
+}
+
+#[test]
+fn api_serves_campaign_queries() {
+    let mut ledger = Ledger::new();
+    let fixture = Fixture::new();
+    let alice = Pubkey::new_unique();
+    fixture.create(&mut ledger, 1_000);
+    fixture.launch(&mut ledger);
+    fixture.fund(&mut ledger, &alice, 300, (300, 300, 1));
+    fixture.fund(&mut ledger, &alice, 200, (500, 500, 1));
+    let store = ledger.index();
+    let campaign = fixture.campaign.to_string();
+
+    let response = api::route(&store, "GET", "/campaigns");
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body[0]["address"], campaign);
+    assert_eq!(response.body[0]["amountRaised"], 500);
+    assert_eq!(response.body[0]["status"], "Active");
+
+    let url = format!("/campaigns?creator={}", fixture.creator);
+    assert_eq!(
+        api::route(&store, "GET", &url).body[0]["title"],
+        "Clean water"
+    );
+    let url = format!("/campaigns?creator={}", Pubkey::new_unique());
+    assert_eq!(api::route(&store, "GET", &url).body, json!([]));
+
+    let response = api::route(&store, "GET", &format!("/campaigns/{campaign}"));
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body["backerCount"], 1);
+
+    let url = format!("/campaigns/{campaign}/activity?limit=1");
+    let response = api::route(&store, "GET", &url);
+    assert_eq!(response.status, 200);
+    assert_eq!(
+        response.body,
+        json!([{
+            "kind": "contribution",
+            "signature": ledger.transactions[3].signature(),
+            "slot": ledger.transactions[3].slot,
+            "campaign": campaign,
+            "account": alice.to_string(),
+            "amount": 200,
+            "timestamp": ledger.transactions[3].block_time,
+        }])
+    );
+
+    let url = format!("/campaigns/{campaign}/contributions");
+    assert_eq!(
+        api::route(&store, "GET", &url).body[1]["contributionTotal"],
+        300
+    );
+    let url = format!("/contributors/{alice}/contributions");
+    assert_eq!(
+        api::route(&store, "GET", &url)
+            .body
+            .as_array()
+            .unwrap()
+            .len(),
+        2
+    );
+    let url = format!("/campaigns/{campaign}/milestones");
+    assert_eq!(api::route(&store, "GET", &url).body, json!([]));
+
+    let unknown = format!("/campaigns/{}", Pubkey::new_unique());
+    assert_eq!(api::route(&store, "GET", &unknown).status, 404);
+    assert_eq!(api::route(&store, "GET", "/campaigns/nope").status, 400);
+    let url = format!("/campaigns/{campaign}/activity?limit=-1");
+    assert_eq!(api::route(&store, "GET", &url).status, 400);
+    assert_eq!(api::route(&store, "GET", "/backers").status, 404);
+    assert_eq!(api::route(&store, "POST", "/campaigns").status, 405);
+}
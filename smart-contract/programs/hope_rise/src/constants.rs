@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 /// Maximum length for campaign title
 pub const MAX_TITLE_LENGTH: usize = 80;
 
@@ -13,6 +15,21 @@ pub const MAX_MILESTONE_TITLE_LENGTH: usize = 100;
 /// Maximum number of milestones per campaign
 pub const MAX_MILESTONES_PER_CAMPAIGN: u8 = 10;
 
+/// Maximum number of reward tiers per campaign
+pub const MAX_REWARD_TIERS_PER_CAMPAIGN: u8 = 10;
+
+/// Maximum number of stretch goals per campaign
+pub const MAX_STRETCH_GOALS_PER_CAMPAIGN: u8 = 10;
+
+/// Basis point denominator for milestone payout shares (100%)
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Maximum platform fee the config may charge on payouts (10%)
+pub const MAX_PLATFORM_FEE_BPS: u64 = 1_000;
+
+/// Minimum approval threshold a campaign may configure for milestone votes (50%)
+pub const MIN_APPROVAL_THRESHOLD_BPS: u64 = 5_000;
+
 /// Minimum campaign duration in days
 pub const MIN_CAMPAIGN_DURATION_DAYS: u64 = 1;
 
@@ -22,6 +39,32 @@ pub const MAX_CAMPAIGN_DURATION_DAYS: u64 = 90;
 /// Seconds per day for deadline calculation
 pub const SECONDS_PER_DAY: i64 = 86400;
 
+/// Seconds per hour for the unpledge cutoff
+pub const SECONDS_PER_HOUR: i64 = 3600;
+
+/// Length of the backer voting window on a milestone (3 days)
+pub const MILESTONE_VOTING_PERIOD: i64 = 3 * SECONDS_PER_DAY;
+
+/// Devnet USDC, the only mint campaigns raised funds in before they could
+/// choose their asset
+pub const LEGACY_USDC_MINT: Pubkey = pubkey!("Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr");
+
+/// Maximum number of token mints accepted by the platform
+pub const MAX_ACCEPTED_MINTS: usize = 8;
+
+/// Symbol of contribution receipt tokens
+pub const RECEIPT_SYMBOL: &str = "HOPE";
+
+/// Receipt metadata field holding the address of the backed campaign
+pub const RECEIPT_CAMPAIGN_FIELD: &str = "campaign";
+
+/// Zeroed bytes kept past the fields of campaign accounts, so fields a later
+/// layout appends still decode from campaigns that were not migrated yet
+pub const LAYOUT_RESERVE: usize = 64;
+
+/// PDA seed for platform configuration
+pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+
 /// PDA seed for campaign counter
 pub const CAMPAIGN_COUNTER_SEED: &[u8] = b"campaign_counter";
 
@@ -31,15 +74,23 @@ pub const CAMPAIGN_SEED: &[u8] = b"campaign";
 /// PDA seed for milestone accounts
 pub const MILESTONE_SEED: &[u8] = b"milestone";
 
+/// PDA seed for milestone vote accounts
+pub const MILESTONE_VOTE_SEED: &[u8] = b"milestone_vote";
+
+/// PDA seed for reward tier accounts
+pub const REWARD_TIER_SEED: &[u8] = b"reward_tier";
+
+/// PDA seed for stretch goal accounts
+pub const STRETCH_GOAL_SEED: &[u8] = b"stretch_goal";
+
 /// PDA seed for contribution accounts
 pub const CONTRIBUTION_SEED: &[u8] = b"contribution";
 
+/// PDA seed for contribution receipt mints
+pub const RECEIPT_MINT_SEED: &[u8] = b"receipt_mint";
+
 /// PDA seed for campaign vault (token account)
 pub const CAMPAIGN_VAULT_SEED: &[u8] = b"campaign_vault";
 
-/// Circle's official USDC mint address on Solana devnet
-/// Faucet: https://faucet.circle.com/
-pub const USDC_MINT: &str = "Gh9ZwEmdLJ8DscKNTkTqPbNwLNNBjuSzaG9Vp2KGtKJr";
-
-/// USDC decimals (6 instead of SOL's 9)
-pub const USDC_DECIMALS: u8 = 6;
+/// PDA seed for SOL campaign vault (system account holding lamports)
+pub const SOL_VAULT_SEED: &[u8] = b"sol_vault";
@@ -73,4 +73,136 @@ pub enum HopeRiseError {
 
     #[msg("Invalid token account")]
     InvalidTokenAccount,
+
+    #[msg("Milestone has not been completed")]
+    MilestoneNotCompleted,
+
+    #[msg("Milestone funds have already been released")]
+    MilestoneAlreadyReleased,
+
+    #[msg("Milestone payout shares cannot exceed 100% of funds raised")]
+    InvalidPayoutShare,
+
+    #[msg("Milestones cannot be added after funds have been released")]
+    FundsAlreadyReleased,
+
+    #[msg("Vote quorum must be at most 100% and approval between 50% and 100%")]
+    InvalidVoteThreshold,
+
+    #[msg("Milestone proof has already been submitted")]
+    MilestoneProofAlreadySubmitted,
+
+    #[msg("Milestone voting has not started")]
+    VotingNotOpen,
+
+    #[msg("Milestone voting period has ended")]
+    VotingClosed,
+
+    #[msg("Milestone voting period has not ended yet")]
+    VotingStillOpen,
+
+    #[msg("Milestone was not approved by backers")]
+    MilestoneVoteFailed,
+
+    #[msg("Campaign has not failed at a milestone")]
+    CampaignNotFailed,
+
+    #[msg("Token mint is already accepted")]
+    MintAlreadyAccepted,
+
+    #[msg("Maximum number of accepted mints reached")]
+    MaxAcceptedMintsReached,
+
+    #[msg("Vault accounts for the campaign's funding asset were not provided")]
+    MissingVaultAccount,
+
+    #[msg("Platform fee exceeds the maximum of 10%")]
+    InvalidPlatformFee,
+
+    #[msg("Program is paused")]
+    ProgramPaused,
+
+    #[msg("Campaign has been suspended by the platform")]
+    CampaignSuspended,
+
+    #[msg("Illegal campaign status transition")]
+    IllegalStatusTransition,
+
+    #[msg("Action is not allowed in the campaign's current status")]
+    InvalidCampaignStatus,
+
+    #[msg("Contribution is still needed: claim its refund or wait for the campaign to complete")]
+    ContributionNotSettled,
+
+    #[msg("Campaign must be completed, failed or cancelled before its accounts are closed")]
+    CampaignNotSettled,
+
+    #[msg("Contribution, milestone, reward tier and stretch goal accounts of the campaign must be closed first")]
+    CampaignAccountsStillOpen,
+
+    #[msg("Account uses an outdated layout, upgrade it with migrate_account first")]
+    AccountNotMigrated,
+
+    #[msg("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+
+    #[msg("Only campaign, milestone, contribution, reward tier and stretch goal accounts can be migrated")]
+    NotVersionedAccount,
+
+    #[msg("Maximum number of reward tiers (10) reached")]
+    MaxRewardTiersReached,
+
+    #[msg("Reward tier needs a minimum pledge and a supply above zero")]
+    InvalidRewardTier,
+
+    #[msg("Reward tier account does not match the requested tier")]
+    RewardTierMismatch,
+
+    #[msg("Contribution total is below the reward tier's minimum pledge")]
+    PledgeBelowTierMinimum,
+
+    #[msg("Reward tier has no rewards left")]
+    RewardTierSoldOut,
+
+    #[msg("Contributor already claimed another reward tier")]
+    RewardTierAlreadyClaimed,
+
+    #[msg("Receipt mint, token program and associated token program are required")]
+    MissingReceiptAccount,
+
+    #[msg("Token account does not hold the contribution's receipt")]
+    InvalidReceiptAccount,
+
+    #[msg("Unpledge cutoff cannot exceed the campaign duration")]
+    InvalidUnpledgeCutoff,
+
+    #[msg("Pledges can no longer be withdrawn from this campaign")]
+    UnpledgeWindowClosed,
+
+    #[msg("Cannot withdraw more than the contribution")]
+    UnpledgeExceedsContribution,
+
+    #[msg("Maximum number of stretch goals (10) reached")]
+    MaxStretchGoalsReached,
+
+    #[msg("Stretch goal threshold must exceed the funding goal, the amount raised and the previous stretch goal")]
+    InvalidStretchGoalThreshold,
+
+    #[msg("Stretch goal account does not match the expected stretch goal")]
+    StretchGoalMismatch,
+
+    #[msg("Campaign has no stretch goal at that index")]
+    StretchGoalNotFound,
+
+    #[msg("Milestone's stretch goal has not been unlocked")]
+    StretchGoalLocked,
+
+    #[msg("Campaign is not suspended")]
+    CampaignNotSuspended,
+
+    #[msg("Milestone account does not match the expected milestone")]
+    MilestoneMismatch,
+
+    #[msg("Milestone vote is still needed: wait for the milestone to be released or the campaign to settle")]
+    MilestoneVoteStillNeeded,
 }
@@ -0,0 +1,395 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CampaignStatus, FundingAsset};
+
+/// Emitted when a creator sets up a new campaign (still in draft)
+#[event]
+pub struct CampaignCreated {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Campaign creator's wallet address
+    pub creator: Pubkey,
+    /// Unique campaign identifier
+    pub campaign_id: u64,
+    /// Asset the campaign raises funds in
+    pub asset: FundingAsset,
+    /// Token mint (default pubkey for SOL campaigns)
+    pub mint: Pubkey,
+    /// Target funding goal in base units of the campaign asset
+    pub funding_goal: u64,
+    /// Campaign deadline (Unix timestamp), moved forward again at launch
+    pub deadline: i64,
+    /// Platform fee on payouts (basis points), fixed at creation
+    pub fee_bps: u16,
+    /// Creation timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator edits a campaign's metadata
+#[event]
+pub struct CampaignMetadataUpdated {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Campaign title
+    pub title: String,
+    /// Short description
+    pub short_description: String,
+    /// IPFS hash for cover image
+    pub cover_image_url: String,
+    /// IPFS hash for long story content
+    pub story_url: String,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator launches a draft campaign, opening contributions
+#[event]
+pub struct CampaignLaunched {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Campaign deadline (Unix timestamp), counted from launch
+    pub deadline: i64,
+    /// Launch timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted for every contribution landing in the campaign vault
+#[event]
+pub struct ContributionMade {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Contributor's wallet address
+    pub contributor: Pubkey,
+    /// Amount received by the vault, net of any Token-2022 transfer fee
+    pub amount: u64,
+    /// Contributor's running total on the campaign
+    pub contribution_total: u64,
+    /// Campaign's running total raised
+    pub amount_raised: u64,
+    /// Number of unique backers after this contribution
+    pub backer_count: u64,
+    /// Reward tier claimed by the contributor, if any
+    pub reward_tier: Option<u8>,
+    /// Contribution timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a contributor withdraws part or all of their pledge
+#[event]
+pub struct PledgeWithdrawn {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Contributor's wallet address
+    pub contributor: Pubkey,
+    /// Amount paid back out of the vault
+    pub amount: u64,
+    /// Contributor's running total on the campaign
+    pub contribution_total: u64,
+    /// Campaign's running total raised
+    pub amount_raised: u64,
+    /// Number of unique backers after the withdrawal
+    pub backer_count: u64,
+    /// Reward tier still claimed by the contributor, if any
+    pub reward_tier: Option<u8>,
+    /// Withdrawal timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the funding period ends, as succeeded or failed
+#[event]
+pub struct CampaignFinalized {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Status the campaign moved to, `Succeeded` or `Failed`
+    pub status: CampaignStatus,
+    /// Total amount raised by the end of funding
+    pub amount_raised: u64,
+    /// Number of backers by the end of funding
+    pub backer_count: u64,
+    /// Finalization timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when funds leave the vault for the creator, either through
+/// `withdraw_funds` or a milestone release
+#[event]
+pub struct FundsWithdrawn {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Campaign creator's wallet address
+    pub creator: Pubkey,
+    /// Milestone whose release paid out the funds, if any
+    pub milestone_index: Option<u8>,
+    /// Total amount taken out of the vault
+    pub amount: u64,
+    /// Part of `amount` paid to the creator
+    pub creator_amount: u64,
+    /// Part of `amount` paid to the platform treasury
+    pub fee: u64,
+    /// Campaign's running total released from the vault
+    pub released_amount: u64,
+    /// Withdrawal timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator adds a milestone to a draft campaign
+#[event]
+pub struct MilestoneAdded {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// Target amount for this milestone (in base units of the campaign asset)
+    pub target_amount: u64,
+    /// Share of funds raised paid out for this milestone (basis points)
+    pub payout_bps: u16,
+    /// Campaign's running sum of milestone payout shares (basis points)
+    pub allocated_bps: u16,
+    /// Stretch goal the milestone is gated on, if any
+    pub stretch_goal: Option<u8>,
+    /// Timestamp the milestone was added
+    pub timestamp: i64,
+}
+
+/// Emitted when finalizing a campaign moves the shares of milestones gated
+/// on stretch goals that never unlocked
+#[event]
+pub struct MilestonePayoutReassigned {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// Milestone's new share of funds raised (basis points)
+    pub payout_bps: u16,
+    /// Stretch goal the milestone is still gated on, if any
+    pub stretch_goal: Option<u8>,
+    /// Finalization timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator adds a reward tier to a campaign
+#[event]
+pub struct RewardTierAdded {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Reward tier account
+    pub reward_tier: Pubkey,
+    /// Tier index (0-based)
+    pub tier_index: u8,
+    /// Minimum total contribution to claim the tier (in base units of the campaign asset)
+    pub min_pledge: u64,
+    /// Number of backers that can claim the tier
+    pub max_supply: u32,
+    /// IPFS hash of the reward description
+    pub metadata_url: String,
+    /// Timestamp the tier was added
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator adds a stretch goal to a campaign
+#[event]
+pub struct StretchGoalAdded {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Stretch goal account
+    pub stretch_goal: Pubkey,
+    /// Stretch goal index (0-based)
+    pub goal_index: u8,
+    /// Amount raised that unlocks the goal (in base units of the campaign asset)
+    pub threshold: u64,
+    /// IPFS hash of the stretch goal description
+    pub metadata_url: String,
+    /// Timestamp the goal was added
+    pub timestamp: i64,
+}
+
+/// Emitted when a contribution takes the amount raised past a stretch goal
+#[event]
+pub struct StretchGoalUnlocked {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Stretch goal account
+    pub stretch_goal: Pubkey,
+    /// Stretch goal index (0-based)
+    pub goal_index: u8,
+    /// Amount raised that unlocked the goal (in base units of the campaign asset)
+    pub threshold: u64,
+    /// Campaign's amount raised after the contribution
+    pub amount_raised: u64,
+    /// Timestamp the goal was unlocked
+    pub timestamp: i64,
+}
+
+/// Emitted when backers approve a milestone and its tranche can be released
+#[event]
+pub struct MilestoneCompleted {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// Contribution weight that voted to approve
+    pub yes_weight: u64,
+    /// Contribution weight that voted to reject
+    pub no_weight: u64,
+    /// Completion timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator submits proof of a milestone, opening the backer vote
+#[event]
+pub struct MilestoneProofSubmitted {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// IPFS hash of the creator's proof of delivery
+    pub proof_url: String,
+    /// End of the backer voting window (Unix timestamp)
+    pub voting_ends_at: i64,
+    /// Submission timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted for every backer vote on a milestone
+#[event]
+pub struct MilestoneVoted {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// Contributor who cast the vote
+    pub voter: Pubkey,
+    /// Whether the vote approves the milestone
+    pub approve: bool,
+    /// Vote weight (contribution amount at the time of voting)
+    pub weight: u64,
+    /// Milestone's running contribution weight voting to approve
+    pub yes_weight: u64,
+    /// Milestone's running contribution weight voting to reject
+    pub no_weight: u64,
+    /// Vote timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a milestone vote closes without reaching quorum, reopening
+/// the milestone for a new proof submission
+#[event]
+pub struct MilestoneQuorumMissed {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// Contribution weight that voted to approve
+    pub yes_weight: u64,
+    /// Contribution weight that voted to reject
+    pub no_weight: u64,
+    /// Timestamp the vote was closed
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator releases an approved milestone's tranche. The
+/// payout itself is reported by `FundsWithdrawn`.
+#[event]
+pub struct MilestoneReleased {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Milestone index (0-based)
+    pub milestone_index: u8,
+    /// Campaign's running sum of released payout shares (basis points)
+    pub released_bps: u16,
+    /// Release timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when backers reject a milestone, failing the campaign
+#[event]
+pub struct CampaignFailed {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Milestone account
+    pub milestone: Pubkey,
+    /// Index of the rejected milestone
+    pub milestone_index: u8,
+    /// Contribution weight that voted to approve
+    pub yes_weight: u64,
+    /// Contribution weight that voted to reject
+    pub no_weight: u64,
+    /// Failure timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when everything raised has been paid out to the creator
+#[event]
+pub struct CampaignCompleted {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Total amount released from the vault
+    pub released_amount: u64,
+    /// Completion timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the platform admin suspends or fails a campaign
+#[event]
+pub struct CampaignModerated {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Status the campaign moved to
+    pub status: CampaignStatus,
+    /// Platform reason code given for the action
+    pub reason: u16,
+    /// Moderation timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when the creator cancels a campaign, opening refunds
+#[event]
+pub struct CampaignClosed {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Campaign creator's wallet address
+    pub creator: Pubkey,
+    /// Total amount raised before cancellation
+    pub amount_raised: u64,
+    /// Number of unique backers before cancellation
+    pub backer_count: u64,
+    /// Cancellation timestamp
+    pub timestamp: i64,
+}
+
+/// Emitted when a contributor is refunded from the vault
+#[event]
+pub struct RefundClaimed {
+    /// Campaign account
+    pub campaign: Pubkey,
+    /// Contributor's wallet address
+    pub contributor: Pubkey,
+    /// Amount refunded
+    pub amount: u64,
+    /// Contribution the refund was computed from
+    pub contribution_amount: u64,
+    /// Whether the refund is a pro-rata share after a rejected milestone
+    pub partial: bool,
+    /// Campaign's amount raised after the refunded pledge was taken out
+    pub amount_raised: u64,
+    /// Number of backers left after the refund
+    pub backer_count: u64,
+    /// Campaign's total of refunded pledges
+    pub total_refunded: u64,
+    /// Refund timestamp
+    pub timestamp: i64,
+}
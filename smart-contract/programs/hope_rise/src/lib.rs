@@ -1,16 +1,25 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-mod constants;
-mod errors;
-mod state;
+pub mod constants;
+pub mod errors;
+pub mod events;
+pub mod migration;
+pub mod receipt;
+pub mod state;
+pub mod vault;
 
 use constants::*;
 use errors::HopeRiseError;
+use events::*;
+use receipt::ContributionReceipt;
 use state::*;
+use vault::CampaignVault;
 
 declare_id!("BAaDjLVffrtNzgKLoUjmM9t1tWBHxMF6UFdnL1NYmQ3J");
 
@@ -18,7 +27,6 @@ declare_id!("BAaDjLVffrtNzgKLoUjmM9t1tWBHxMF6UFdnL1NYmQ3J");
 pub mod hope_rise {
     use super::*;
 
-
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         let counter = &mut ctx.accounts.campaign_counter;
         counter.count = 0;
@@ -27,7 +35,88 @@ pub mod hope_rise {
         Ok(())
     }
 
+    /// Creates the platform config with the program's upgrade authority as
+    /// admin and treasury. Separate from `initialize`, so deployments whose
+    /// campaign counter predates the config can add it.
+    pub fn initialize_platform_config(ctx: Context<InitializePlatformConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.admin = ctx.accounts.authority.key();
+        config.accepted_mints = Vec::new();
+        config.bump = ctx.bumps.platform_config;
+        config.fee_bps = 0;
+        config.treasury = ctx.accounts.authority.key();
+        config.pending_admin = None;
+        config.is_paused = false;
+        Ok(())
+    }
+
+    pub fn propose_admin(ctx: Context<UpdatePlatformConfig>, new_admin: Pubkey) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.pending_admin = Some(new_admin);
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.admin = ctx.accounts.new_admin.key();
+        config.pending_admin = None;
+        Ok(())
+    }
+
+    pub fn set_paused(ctx: Context<UpdatePlatformConfig>, paused: bool) -> Result<()> {
+        let config = &mut ctx.accounts.platform_config;
+        config.is_paused = paused;
+        Ok(())
+    }
+
+    pub fn set_platform_fee(
+        ctx: Context<UpdatePlatformConfig>,
+        fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        require!(
+            fee_bps as u64 <= MAX_PLATFORM_FEE_BPS,
+            HopeRiseError::InvalidPlatformFee
+        );
+
+        let config = &mut ctx.accounts.platform_config;
+        config.fee_bps = fee_bps;
+        config.treasury = treasury;
+        Ok(())
+    }
+
+    pub fn add_accepted_mint(ctx: Context<UpdateAcceptedMints>) -> Result<()> {
+        let mint = ctx.accounts.mint.key();
+        let config = &mut ctx.accounts.platform_config;
+
+        require!(
+            !config.accepted_mints.contains(&mint),
+            HopeRiseError::MintAlreadyAccepted
+        );
+        require!(
+            config.accepted_mints.len() < MAX_ACCEPTED_MINTS,
+            HopeRiseError::MaxAcceptedMintsReached
+        );
+
+        config.accepted_mints.push(mint);
+        Ok(())
+    }
+
+    pub fn remove_accepted_mint(ctx: Context<UpdateAcceptedMints>) -> Result<()> {
+        let mint = ctx.accounts.mint.key();
+        let config = &mut ctx.accounts.platform_config;
+
+        let position = config
+            .accepted_mints
+            .iter()
+            .position(|accepted| *accepted == mint)
+            .ok_or(HopeRiseError::InvalidMint)?;
+
+        config.accepted_mints.remove(position);
+        Ok(())
+    }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         title: String,
@@ -37,37 +126,77 @@ pub mod hope_rise {
         story_url: String,
         funding_goal: u64,
         duration_days: u64,
+        asset: FundingAsset,
+        vote_quorum_bps: u16,
+        vote_approval_bps: u16,
+        allow_early_close: bool,
+        transferable_receipts: bool,
+        unpledge_cutoff_hours: u32,
     ) -> Result<()> {
-
-        require!(title.len() <= MAX_TITLE_LENGTH, HopeRiseError::TitleTooLong);
+        require!(funding_goal > 0, HopeRiseError::InvalidFundingGoal);
         require!(
-            short_description.len() <= MAX_DESCRIPTION_LENGTH,
-            HopeRiseError::DescriptionTooLong
+            (MIN_CAMPAIGN_DURATION_DAYS..=MAX_CAMPAIGN_DURATION_DAYS).contains(&duration_days),
+            HopeRiseError::InvalidDuration
         );
         require!(
-            cover_image_url.len() <= MAX_URL_LENGTH,
-            HopeRiseError::UrlTooLong
+            vote_quorum_bps as u64 <= BPS_DENOMINATOR
+                && (MIN_APPROVAL_THRESHOLD_BPS..=BPS_DENOMINATOR)
+                    .contains(&(vote_approval_bps as u64)),
+            HopeRiseError::InvalidVoteThreshold
         );
-        require!(story_url.len() <= MAX_URL_LENGTH, HopeRiseError::UrlTooLong);
-        require!(funding_goal > 0, HopeRiseError::InvalidFundingGoal);
+        let unpledge_cutoff = unpledge_cutoff_hours as i64 * SECONDS_PER_HOUR;
         require!(
-            duration_days >= MIN_CAMPAIGN_DURATION_DAYS
-                && duration_days <= MAX_CAMPAIGN_DURATION_DAYS,
-            HopeRiseError::InvalidDuration
+            unpledge_cutoff <= duration_days as i64 * SECONDS_PER_DAY,
+            HopeRiseError::InvalidUnpledgeCutoff
         );
 
+        let mint = match asset {
+            FundingAsset::Token => {
+                let mint = ctx
+                    .accounts
+                    .mint
+                    .as_ref()
+                    .ok_or(HopeRiseError::InvalidMint)?
+                    .key();
+                require!(
+                    ctx.accounts.platform_config.accepted_mints.contains(&mint),
+                    HopeRiseError::InvalidMint
+                );
+                // The creator pays for the vault up front so closing it can
+                // return the rent to whoever funded it
+                require!(
+                    ctx.accounts.campaign_vault.is_some(),
+                    HopeRiseError::MissingVaultAccount
+                );
+                mint
+            }
+            FundingAsset::Sol => {
+                // Seed the lamport vault with its rent-exempt minimum so it can
+                // hold any contribution size and never drops below rent
+                let sol_vault = ctx
+                    .accounts
+                    .sol_vault
+                    .as_ref()
+                    .ok_or(HopeRiseError::MissingVaultAccount)?;
+                let cpi_accounts = system_program::Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: sol_vault.to_account_info(),
+                };
+                let cpi_ctx =
+                    CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_ctx, Rent::get()?.minimum_balance(0))?;
+                Pubkey::default()
+            }
+        };
+
         let clock = Clock::get()?;
         let counter = &mut ctx.accounts.campaign_counter;
         let campaign = &mut ctx.accounts.campaign;
 
-
         campaign.campaign_id = counter.count;
         campaign.creator = ctx.accounts.creator.key();
-        campaign.title = title;
-        campaign.short_description = short_description;
+        campaign.set_metadata(title, short_description, cover_image_url, story_url)?;
         campaign.category = category;
-        campaign.cover_image_url = cover_image_url;
-        campaign.story_url = story_url;
         campaign.funding_goal = funding_goal;
         campaign.deadline = clock
             .unix_timestamp
@@ -75,119 +204,520 @@ pub mod hope_rise {
             .ok_or(HopeRiseError::ArithmeticOverflow)?;
         campaign.amount_raised = 0;
         campaign.backer_count = 0;
-        campaign.is_active = true;
+        campaign.status = CampaignStatus::Draft;
         campaign.created_at = clock.unix_timestamp;
         campaign.milestone_count = 0;
         campaign.bump = ctx.bumps.campaign;
-
+        campaign.released_amount = 0;
+        campaign.allocated_bps = 0;
+        campaign.released_bps = 0;
+        campaign.vote_quorum_bps = vote_quorum_bps;
+        campaign.vote_approval_bps = vote_approval_bps;
+        campaign.failed_milestone = None;
+        campaign.mint = mint;
+        campaign.asset = asset;
+        campaign.fee_bps = ctx.accounts.platform_config.fee_bps;
+        campaign.moderation_reason = 0;
+        campaign.allow_early_close = allow_early_close;
+        campaign.finalized_at = 0;
+        campaign.open_contributions = 0;
+        campaign.open_milestones = 0;
+        campaign.version = Campaign::VERSION;
+        campaign.reward_tier_count = 0;
+        campaign.open_reward_tiers = 0;
+        campaign.transferable_receipts = transferable_receipts;
+        campaign.unpledge_cutoff = unpledge_cutoff;
+        campaign.total_refunded = 0;
+        campaign.stretch_goal_count = 0;
+        campaign.open_stretch_goals = 0;
+        campaign.unlocked_stretch_goals = 0;
+        campaign.suspended_from = None;
 
         counter.count = counter
             .count
             .checked_add(1)
             .ok_or(HopeRiseError::ArithmeticOverflow)?;
 
+        emit!(CampaignCreated {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            campaign_id: campaign.campaign_id,
+            asset: campaign.asset,
+            mint: campaign.mint,
+            funding_goal: campaign.funding_goal,
+            deadline: campaign.deadline,
+            fee_bps: campaign.fee_bps,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Replaces the campaign's metadata, resizing the account to fit it
+    pub fn update_campaign_metadata(
+        ctx: Context<UpdateCampaignMetadata>,
+        title: String,
+        short_description: String,
+        cover_image_url: String,
+        story_url: String,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        require!(
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            matches!(
+                campaign.status,
+                CampaignStatus::Draft | CampaignStatus::Active
+            ),
+            HopeRiseError::InvalidCampaignStatus
+        );
+
+        campaign.set_metadata(title, short_description, cover_image_url, story_url)?;
+        // Shorter metadata leaves stale bytes past the fields; clear them so
+        // the layout reserve stays zeroed once the account is written back
+        let fields_end = 8 + borsh::to_vec(&**campaign)?.len();
+        campaign.to_account_info().try_borrow_mut_data()?[fields_end..].fill(0);
+
+        emit!(CampaignMetadataUpdated {
+            campaign: campaign.key(),
+            title: campaign.title.clone(),
+            short_description: campaign.short_description.clone(),
+            cover_image_url: campaign.cover_image_url.clone(),
+            story_url: campaign.story_url.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
         Ok(())
     }
 
+    pub fn launch_campaign(ctx: Context<LaunchCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
+
+        // The funding period starts at launch, keeping the duration chosen at creation
+        let duration = campaign
+            .deadline
+            .checked_sub(campaign.created_at)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        campaign.deadline = clock
+            .unix_timestamp
+            .checked_add(duration)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        campaign.transition_to(CampaignStatus::Active)?;
+
+        emit!(CampaignLaunched {
+            campaign: campaign.key(),
+            deadline: campaign.deadline,
+            timestamp: clock.unix_timestamp,
+        });
 
-    pub fn fund_campaign(ctx: Context<FundCampaign>, amount: u64) -> Result<()> {
+        Ok(())
+    }
+
+    /// Contributes `amount` to an active campaign, optionally claiming the
+    /// reward tier at `reward_tier` once the contributor's total meets its
+    /// minimum pledge. A contributor claims at most one tier. The first
+    /// contribution mints the contributor a receipt token.
+    ///
+    /// The campaign's locked stretch goals may follow the named accounts in
+    /// index order, starting with the next one; each goal passed that the
+    /// amount raised now crosses is unlocked, and goals left out wait for a
+    /// later pledge or finalization. Unlocked goals stay unlocked if pledges
+    /// are withdrawn later.
+    pub fn fund_campaign<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundCampaign<'info>>,
+        amount: u64,
+        reward_tier: Option<u8>,
+    ) -> Result<()> {
         require!(amount > 0, HopeRiseError::InvalidContributionAmount);
 
         let campaign = &ctx.accounts.campaign;
         let clock = Clock::get()?;
 
-
-        require!(campaign.is_active, HopeRiseError::CampaignNotActive);
+        require!(
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            campaign.status == CampaignStatus::Active,
+            HopeRiseError::CampaignNotActive
+        );
         require!(
             clock.unix_timestamp < campaign.deadline,
             HopeRiseError::CampaignEnded
         );
 
-
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.contributor_token_account.to_account_info(),
-            to: ctx.accounts.campaign_vault.to_account_info(),
-            authority: ctx.accounts.contributor.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        token::transfer(cpi_ctx, amount)?;
-
+        let vault = CampaignVault::resolve(
+            &ctx.accounts.campaign,
+            &ctx.accounts.campaign_vault,
+            ctx.bumps.campaign_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &ctx.accounts.sol_vault,
+            ctx.bumps.sol_vault,
+            &ctx.accounts.system_program,
+        )?;
+        // Token-2022 transfer fees are withheld from the transfer, so only
+        // count what actually landed in the vault
+        let received = vault.deposit(
+            &ctx.accounts.contributor,
+            &ctx.accounts.contributor_token_account,
+            amount,
+        )?;
+        require!(received > 0, HopeRiseError::InvalidContributionAmount);
 
         let campaign = &mut ctx.accounts.campaign;
         let contribution = &mut ctx.accounts.contribution;
 
-
         if contribution.amount == 0 {
             campaign.backer_count = campaign
                 .backer_count
                 .checked_add(1)
                 .ok_or(HopeRiseError::ArithmeticOverflow)?;
-
+            // A contribution unpledged in full keeps its account, which
+            // only counts as opened the first time
+            if contribution.campaign == Pubkey::default() {
+                campaign.open_contributions = campaign
+                    .open_contributions
+                    .checked_add(1)
+                    .ok_or(HopeRiseError::ArithmeticOverflow)?;
+            }
 
             contribution.campaign = campaign.key();
             contribution.contributor = ctx.accounts.contributor.key();
             contribution.contributed_at = clock.unix_timestamp;
             contribution.refund_claimed = false;
             contribution.bump = ctx.bumps.contribution;
+            contribution.version = Contribution::VERSION;
+            contribution.reward_tier = None;
+
+            if !contribution.has_receipt {
+                let receipt = ContributionReceipt::resolve(
+                    &ctx.accounts.receipt_mint,
+                    &ctx.accounts.receipt_token_program,
+                )?;
+                receipt.issue(
+                    campaign,
+                    &contribution.key(),
+                    ctx.bumps
+                        .receipt_mint
+                        .ok_or(HopeRiseError::MissingReceiptAccount)?,
+                    &ctx.accounts.contributor,
+                    &ctx.accounts.receipt_token_account,
+                    &ctx.accounts.associated_token_program,
+                    &ctx.accounts.system_program,
+                )?;
+                contribution.has_receipt = true;
+            }
         }
 
-
         campaign.amount_raised = campaign
             .amount_raised
-            .checked_add(amount)
+            .checked_add(received)
             .ok_or(HopeRiseError::ArithmeticOverflow)?;
 
         contribution.amount = contribution
             .amount
-            .checked_add(amount)
+            .checked_add(received)
             .ok_or(HopeRiseError::ArithmeticOverflow)?;
 
+        if let Some(tier_index) = reward_tier {
+            let tier = ctx
+                .accounts
+                .reward_tier
+                .as_mut()
+                .filter(|tier| tier.tier_index == tier_index)
+                .ok_or(HopeRiseError::RewardTierMismatch)?;
+            require!(
+                contribution.amount >= tier.min_pledge,
+                HopeRiseError::PledgeBelowTierMinimum
+            );
+
+            match contribution.reward_tier {
+                Some(claimed) => require!(
+                    claimed == tier_index,
+                    HopeRiseError::RewardTierAlreadyClaimed
+                ),
+                None => {
+                    require!(tier.remaining() > 0, HopeRiseError::RewardTierSoldOut);
+                    tier.claimed = tier
+                        .claimed
+                        .checked_add(1)
+                        .ok_or(HopeRiseError::ArithmeticOverflow)?;
+                    contribution.reward_tier = Some(tier_index);
+                }
+            }
+        }
+
+        emit!(ContributionMade {
+            campaign: campaign.key(),
+            contributor: contribution.contributor,
+            amount: received,
+            contribution_total: contribution.amount,
+            amount_raised: campaign.amount_raised,
+            backer_count: campaign.backer_count,
+            reward_tier: contribution.reward_tier,
+            timestamp: clock.unix_timestamp,
+        });
+
+        unlock_stretch_goals(campaign, ctx.remaining_accounts, clock.unix_timestamp)?;
+
         Ok(())
     }
 
+    /// Withdraws `amount` of the contributor's pledge from an active campaign
+    /// until `unpledge_cutoff` before the deadline. Dropping below the
+    /// claimed reward tier's minimum gives the reward back, and withdrawing
+    /// everything burns the receipt and removes the contributor from the
+    /// backers.
+    pub fn unpledge(ctx: Context<Unpledge>, amount: u64) -> Result<()> {
+        require!(amount > 0, HopeRiseError::InvalidContributionAmount);
 
-    pub fn withdraw_funds(ctx: Context<WithdrawFunds>) -> Result<()> {
         let campaign = &ctx.accounts.campaign;
+        let clock = Clock::get()?;
 
-
         require!(
-            campaign.amount_raised >= campaign.funding_goal,
-            HopeRiseError::GoalNotMet
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            campaign.status == CampaignStatus::Active,
+            HopeRiseError::CampaignNotActive
+        );
+        let window_end = campaign
+            .deadline
+            .checked_sub(campaign.unpledge_cutoff)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        require!(
+            clock.unix_timestamp < window_end,
+            HopeRiseError::UnpledgeWindowClosed
+        );
+        require!(
+            amount <= ctx.accounts.contribution.amount,
+            HopeRiseError::UnpledgeExceedsContribution
         );
 
+        let vault = CampaignVault::resolve(
+            &ctx.accounts.campaign,
+            &ctx.accounts.campaign_vault,
+            ctx.bumps.campaign_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &ctx.accounts.sol_vault,
+            ctx.bumps.sol_vault,
+            &ctx.accounts.system_program,
+        )?;
+        vault.pay(
+            &ctx.accounts.contributor.to_account_info(),
+            &ctx.accounts.contributor_token_account,
+            amount,
+        )?;
+
+        let contribution = &mut ctx.accounts.contribution;
+        contribution.amount = contribution
+            .amount
+            .checked_sub(amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        if let Some(tier_index) = contribution.reward_tier {
+            let tier = ctx
+                .accounts
+                .reward_tier
+                .as_mut()
+                .filter(|tier| tier.tier_index == tier_index)
+                .ok_or(HopeRiseError::RewardTierMismatch)?;
+            if contribution.amount < tier.min_pledge {
+                tier.claimed = tier
+                    .claimed
+                    .checked_sub(1)
+                    .ok_or(HopeRiseError::ArithmeticOverflow)?;
+                contribution.reward_tier = None;
+            }
+        }
+
+        if contribution.amount == 0 && contribution.has_receipt {
+            let receipt = ContributionReceipt::resolve(
+                &ctx.accounts.receipt_mint,
+                &ctx.accounts.receipt_token_program,
+            )?;
+            receipt.burn(
+                &ctx.accounts.campaign,
+                &ctx.accounts.contributor,
+                &ctx.accounts.receipt_token_account,
+            )?;
+            contribution.has_receipt = false;
+        }
 
-        let vault_balance = ctx.accounts.campaign_vault.amount;
-        require!(vault_balance > 0, HopeRiseError::InsufficientFunds);
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.amount_raised = campaign
+            .amount_raised
+            .checked_sub(amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        if contribution.amount == 0 {
+            campaign.backer_count = campaign
+                .backer_count
+                .checked_sub(1)
+                .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        }
 
+        emit!(PledgeWithdrawn {
+            campaign: campaign.key(),
+            contributor: contribution.contributor,
+            amount,
+            contribution_total: contribution.amount,
+            amount_raised: campaign.amount_raised,
+            backer_count: campaign.backer_count,
+            reward_tier: contribution.reward_tier,
+            timestamp: clock.unix_timestamp,
+        });
 
-        let campaign_key = ctx.accounts.campaign.key();
-        let bump = ctx.bumps.campaign_vault;
-        let seeds = &[
-            CAMPAIGN_VAULT_SEED,
-            campaign_key.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        Ok(())
+    }
 
+    /// Ends the funding period. A successful campaign with stretch goals
+    /// still locked takes all of its milestones after the named accounts,
+    /// writable and in index order, followed by its locked stretch goals.
+    /// Goals the amount raised crossed without being unlocked unlock first.
+    /// Milestones gated on a goal still locked can never take proof, so
+    /// their shares move to the milestones that can, keeping them under a
+    /// backer vote.
+    pub fn finalize_campaign<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeCampaign<'info>>,
+    ) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let clock = Clock::get()?;
 
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.campaign_vault.to_account_info(),
-            to: ctx.accounts.creator_token_account.to_account_info(),
-            authority: ctx.accounts.campaign_vault.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, vault_balance)?;
+        require!(
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            campaign.status == CampaignStatus::Active,
+            HopeRiseError::CampaignNotActive
+        );
+
+        let goal_met = campaign.amount_raised >= campaign.funding_goal;
+        if clock.unix_timestamp < campaign.deadline {
+            require!(campaign.allow_early_close, HopeRiseError::CampaignNotEnded);
+            require!(goal_met, HopeRiseError::GoalNotMet);
+        }
+
+        campaign.transition_to(if goal_met {
+            CampaignStatus::Succeeded
+        } else {
+            CampaignStatus::Failed
+        })?;
+        campaign.finalized_at = clock.unix_timestamp;
+
+        if goal_met && campaign.unlocked_stretch_goals < campaign.stretch_goal_count {
+            let (milestones, stretch_goals) = ctx
+                .remaining_accounts
+                .split_at_checked(campaign.milestone_count as usize)
+                .ok_or(HopeRiseError::MilestoneMismatch)?;
+            require!(
+                stretch_goals.len()
+                    == (campaign.stretch_goal_count - campaign.unlocked_stretch_goals) as usize,
+                HopeRiseError::StretchGoalMismatch
+            );
+            unlock_stretch_goals(campaign, stretch_goals, clock.unix_timestamp)?;
+            if campaign.unlocked_stretch_goals < campaign.stretch_goal_count {
+                reassign_locked_payouts(campaign, milestones, clock.unix_timestamp)?;
+            }
+        }
+
+        emit!(CampaignFinalized {
+            campaign: campaign.key(),
+            status: campaign.status,
+            amount_raised: campaign.amount_raised,
+            backer_count: campaign.backer_count,
+            timestamp: clock.unix_timestamp,
+        });
 
         Ok(())
     }
 
+    pub fn withdraw_funds(ctx: Context<WithdrawFunds>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        require!(
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            campaign.status == CampaignStatus::Succeeded,
+            HopeRiseError::WithdrawalNotAllowed
+        );
+
+        let amount = campaign.releasable_amount()?;
+        require!(amount > 0, HopeRiseError::InsufficientFunds);
+
+        let vault = CampaignVault::resolve(
+            &ctx.accounts.campaign,
+            &ctx.accounts.campaign_vault,
+            ctx.bumps.campaign_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &ctx.accounts.sol_vault,
+            ctx.bumps.sol_vault,
+            &ctx.accounts.system_program,
+        )?;
+        let (creator_amount, fee) = ctx.accounts.campaign.split_platform_fee(amount)?;
+        vault.pay(
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.creator_token_account,
+            creator_amount,
+        )?;
+        if fee > 0 {
+            vault.pay(
+                &ctx.accounts.treasury.to_account_info(),
+                &ctx.accounts.treasury_token_account,
+                fee,
+            )?;
+        }
+
+        let campaign = &mut ctx.accounts.campaign;
+        campaign.released_amount = campaign
+            .released_amount
+            .checked_add(amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(FundsWithdrawn {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            milestone_index: None,
+            amount,
+            creator_amount,
+            fee,
+            released_amount: campaign.released_amount,
+            timestamp,
+        });
+
+        if campaign.is_fully_released() {
+            campaign.transition_to(CampaignStatus::Completed)?;
+
+            emit!(CampaignCompleted {
+                campaign: campaign.key(),
+                released_amount: campaign.released_amount,
+                timestamp,
+            });
+        }
+
+        Ok(())
+    }
 
+    /// Adds a milestone paying out `payout_bps` of the funds raised. A
+    /// milestone gated on `stretch_goal` only takes proof once that goal is
+    /// unlocked.
     pub fn add_milestone(
         ctx: Context<AddMilestone>,
         title: String,
         target_amount: u64,
+        payout_bps: u16,
+        stretch_goal: Option<u8>,
     ) -> Result<()> {
         require!(
             title.len() <= MAX_MILESTONE_TITLE_LENGTH,
@@ -197,6 +727,24 @@ pub mod hope_rise {
         let campaign = &mut ctx.accounts.campaign;
         let milestone = &mut ctx.accounts.milestone;
 
+        require!(
+            campaign.released_amount == 0,
+            HopeRiseError::FundsAlreadyReleased
+        );
+        if let Some(goal_index) = stretch_goal {
+            require!(
+                goal_index < campaign.stretch_goal_count,
+                HopeRiseError::StretchGoalNotFound
+            );
+        }
+        let allocated_bps = campaign
+            .allocated_bps
+            .checked_add(payout_bps)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        require!(
+            allocated_bps as u64 <= BPS_DENOMINATOR,
+            HopeRiseError::InvalidPayoutShare
+        );
 
         milestone.campaign = campaign.key();
         milestone.milestone_index = campaign.milestone_count;
@@ -204,340 +752,1995 @@ pub mod hope_rise {
         milestone.target_amount = target_amount;
         milestone.is_completed = false;
         milestone.bump = ctx.bumps.milestone;
-
-
+        milestone.payout_bps = payout_bps;
+        milestone.is_released = false;
+        milestone.proof_url = String::new();
+        milestone.voting_ends_at = 0;
+        milestone.yes_weight = 0;
+        milestone.no_weight = 0;
+        milestone.version = Milestone::VERSION;
+        milestone.stretch_goal = stretch_goal;
+
+        campaign.allocated_bps = allocated_bps;
         campaign.milestone_count = campaign
             .milestone_count
             .checked_add(1)
             .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        campaign.open_milestones = campaign
+            .open_milestones
+            .checked_add(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        emit!(MilestoneAdded {
+            campaign: campaign.key(),
+            milestone: milestone.key(),
+            milestone_index: milestone.milestone_index,
+            target_amount,
+            payout_bps,
+            allocated_bps,
+            stretch_goal,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
 
-
-    pub fn complete_milestone(ctx: Context<CompleteMilestone>) -> Result<()> {
-        let campaign = &ctx.accounts.campaign;
-        let milestone = &mut ctx.accounts.milestone;
-
-
+    /// Adds a reward tier that backers claim by pledging at least `min_pledge`,
+    /// until `max_supply` of them did
+    pub fn add_reward_tier(
+        ctx: Context<AddRewardTier>,
+        min_pledge: u64,
+        max_supply: u32,
+        metadata_url: String,
+    ) -> Result<()> {
         require!(
-            !milestone.is_completed,
-            HopeRiseError::MilestoneAlreadyCompleted
+            min_pledge > 0 && max_supply > 0,
+            HopeRiseError::InvalidRewardTier
         );
         require!(
-            campaign.amount_raised >= milestone.target_amount,
-            HopeRiseError::MilestoneTargetNotReached
+            metadata_url.len() <= MAX_URL_LENGTH,
+            HopeRiseError::UrlTooLong
         );
 
-        milestone.is_completed = true;
+        let campaign = &mut ctx.accounts.campaign;
+        let reward_tier = &mut ctx.accounts.reward_tier;
+
+        reward_tier.campaign = campaign.key();
+        reward_tier.tier_index = campaign.reward_tier_count;
+        reward_tier.min_pledge = min_pledge;
+        reward_tier.max_supply = max_supply;
+        reward_tier.claimed = 0;
+        reward_tier.metadata_url = metadata_url;
+        reward_tier.bump = ctx.bumps.reward_tier;
+        reward_tier.version = RewardTier::VERSION;
+
+        campaign.reward_tier_count = campaign
+            .reward_tier_count
+            .checked_add(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        campaign.open_reward_tiers = campaign
+            .open_reward_tiers
+            .checked_add(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        emit!(RewardTierAdded {
+            campaign: campaign.key(),
+            reward_tier: reward_tier.key(),
+            tier_index: reward_tier.tier_index,
+            min_pledge,
+            max_supply,
+            metadata_url: reward_tier.metadata_url.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
 
+    /// Adds a stretch goal that unlocks once the amount raised reaches
+    /// `threshold`. Thresholds start above the funding goal and rise with
+    /// each goal, so goals unlock in the order they were added.
+    pub fn add_stretch_goal(
+        ctx: Context<AddStretchGoal>,
+        threshold: u64,
+        metadata_url: String,
+    ) -> Result<()> {
+        require!(
+            metadata_url.len() <= MAX_URL_LENGTH,
+            HopeRiseError::UrlTooLong
+        );
 
-    pub fn close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
         let campaign = &mut ctx.accounts.campaign;
+        let stretch_goal = &mut ctx.accounts.stretch_goal;
 
-        require!(campaign.is_active, HopeRiseError::CampaignNotActive);
+        require!(
+            threshold > campaign.funding_goal && threshold > campaign.amount_raised,
+            HopeRiseError::InvalidStretchGoalThreshold
+        );
+        if campaign.stretch_goal_count > 0 {
+            let previous = ctx
+                .accounts
+                .previous_stretch_goal
+                .as_ref()
+                .filter(|goal| goal.goal_index == campaign.stretch_goal_count - 1)
+                .ok_or(HopeRiseError::StretchGoalMismatch)?;
+            require!(
+                threshold > previous.threshold,
+                HopeRiseError::InvalidStretchGoalThreshold
+            );
+        }
+
+        stretch_goal.campaign = campaign.key();
+        stretch_goal.goal_index = campaign.stretch_goal_count;
+        stretch_goal.threshold = threshold;
+        stretch_goal.metadata_url = metadata_url;
+        stretch_goal.unlocked_at = 0;
+        stretch_goal.bump = ctx.bumps.stretch_goal;
+        stretch_goal.version = StretchGoal::VERSION;
+
+        campaign.stretch_goal_count = campaign
+            .stretch_goal_count
+            .checked_add(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        campaign.open_stretch_goals = campaign
+            .open_stretch_goals
+            .checked_add(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
 
-        campaign.is_active = false;
+        emit!(StretchGoalAdded {
+            campaign: campaign.key(),
+            stretch_goal: stretch_goal.key(),
+            goal_index: stretch_goal.goal_index,
+            threshold,
+            metadata_url: stretch_goal.metadata_url.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
         Ok(())
     }
 
+    pub fn submit_milestone_proof(
+        ctx: Context<SubmitMilestoneProof>,
+        proof_url: String,
+    ) -> Result<()> {
+        require!(proof_url.len() <= MAX_URL_LENGTH, HopeRiseError::UrlTooLong);
 
-    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
         let campaign = &ctx.accounts.campaign;
-        let contribution = &ctx.accounts.contribution;
-
+        let milestone = &mut ctx.accounts.milestone;
 
-        require!(!campaign.is_active, HopeRiseError::CampaignStillActive);
         require!(
-            campaign.amount_raised < campaign.funding_goal,
-            HopeRiseError::GoalWasMet
+            campaign.failed_milestone.is_none(),
+            HopeRiseError::MilestoneVoteFailed
         );
         require!(
-            !contribution.refund_claimed,
-            HopeRiseError::RefundAlreadyClaimed
+            campaign.status == CampaignStatus::Succeeded,
+            HopeRiseError::InvalidCampaignStatus
         );
-        require!(contribution.amount > 0, HopeRiseError::NoContribution);
+        require!(
+            !milestone.is_completed,
+            HopeRiseError::MilestoneAlreadyCompleted
+        );
+        require!(
+            milestone.voting_ends_at == 0,
+            HopeRiseError::MilestoneProofAlreadySubmitted
+        );
+        require!(
+            campaign.amount_raised >= milestone.target_amount,
+            HopeRiseError::MilestoneTargetNotReached
+        );
+        if let Some(goal_index) = milestone.stretch_goal {
+            require!(
+                goal_index < campaign.unlocked_stretch_goals,
+                HopeRiseError::StretchGoalLocked
+            );
+        }
 
-        let refund_amount = contribution.amount;
+        let clock = Clock::get()?;
+        milestone.proof_url = proof_url;
+        milestone.voting_ends_at = clock
+            .unix_timestamp
+            .checked_add(MILESTONE_VOTING_PERIOD)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
 
+        emit!(MilestoneProofSubmitted {
+            campaign: campaign.key(),
+            milestone: milestone.key(),
+            milestone_index: milestone.milestone_index,
+            proof_url: milestone.proof_url.clone(),
+            voting_ends_at: milestone.voting_ends_at,
+            timestamp: clock.unix_timestamp,
+        });
 
-        let campaign_key = ctx.accounts.campaign.key();
-        let bump = ctx.bumps.campaign_vault;
-        let seeds = &[
-            CAMPAIGN_VAULT_SEED,
-            campaign_key.as_ref(),
-            &[bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
+        Ok(())
+    }
 
+    pub fn vote_milestone(ctx: Context<VoteMilestone>, approve: bool) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let contribution = &ctx.accounts.contribution;
+        let milestone = &ctx.accounts.milestone;
+        let clock = Clock::get()?;
 
-        let cpi_accounts = Transfer {
-            from: ctx.accounts.campaign_vault.to_account_info(),
-            to: ctx.accounts.contributor_token_account.to_account_info(),
-            authority: ctx.accounts.campaign_vault.to_account_info(),
-        };
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
-        token::transfer(cpi_ctx, refund_amount)?;
+        require!(
+            campaign.failed_milestone.is_none(),
+            HopeRiseError::MilestoneVoteFailed
+        );
+        require!(
+            campaign.status == CampaignStatus::Succeeded,
+            HopeRiseError::InvalidCampaignStatus
+        );
+        require!(milestone.voting_ends_at != 0, HopeRiseError::VotingNotOpen);
+        require!(
+            clock.unix_timestamp < milestone.voting_ends_at,
+            HopeRiseError::VotingClosed
+        );
+        require!(contribution.amount > 0, HopeRiseError::NoContribution);
 
+        let weight = contribution.amount;
+        let milestone = &mut ctx.accounts.milestone;
+        if approve {
+            milestone.yes_weight = milestone
+                .yes_weight
+                .checked_add(weight)
+                .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        } else {
+            milestone.no_weight = milestone
+                .no_weight
+                .checked_add(weight)
+                .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        }
 
-        let contribution = &mut ctx.accounts.contribution;
-        contribution.refund_claimed = true;
+        let vote = &mut ctx.accounts.milestone_vote;
+        vote.milestone = milestone.key();
+        vote.voter = ctx.accounts.contributor.key();
+        vote.weight = weight;
+        vote.approve = approve;
+        vote.voted_at = clock.unix_timestamp;
+        vote.bump = ctx.bumps.milestone_vote;
+
+        emit!(MilestoneVoted {
+            campaign: ctx.accounts.campaign.key(),
+            milestone: milestone.key(),
+            milestone_index: milestone.milestone_index,
+            voter: vote.voter,
+            approve,
+            weight,
+            yes_weight: milestone.yes_weight,
+            no_weight: milestone.no_weight,
+            timestamp: clock.unix_timestamp,
+        });
 
         Ok(())
     }
-}
 
+    pub fn complete_milestone(ctx: Context<CompleteMilestone>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let milestone = &mut ctx.accounts.milestone;
+        let clock = Clock::get()?;
 
+        require!(
+            campaign.failed_milestone.is_none(),
+            HopeRiseError::MilestoneVoteFailed
+        );
+        require!(
+            campaign.status == CampaignStatus::Succeeded,
+            HopeRiseError::InvalidCampaignStatus
+        );
+        require!(
+            !milestone.is_completed,
+            HopeRiseError::MilestoneAlreadyCompleted
+        );
+        require!(milestone.voting_ends_at != 0, HopeRiseError::VotingNotOpen);
+        require!(
+            clock.unix_timestamp >= milestone.voting_ends_at,
+            HopeRiseError::VotingStillOpen
+        );
 
+        if !milestone.quorum_met(campaign.amount_raised, campaign.vote_quorum_bps) {
+            // Too few backers voted to decide either way: reopen the milestone
+            // so the creator can submit proof again. Votes already cast keep
+            // counting towards the next round.
+            milestone.voting_ends_at = 0;
+
+            emit!(MilestoneQuorumMissed {
+                campaign: campaign.key(),
+                milestone: milestone.key(),
+                milestone_index: milestone.milestone_index,
+                yes_weight: milestone.yes_weight,
+                no_weight: milestone.no_weight,
+                timestamp: clock.unix_timestamp,
+            });
+        } else if milestone.approved(campaign.vote_approval_bps) {
+            milestone.is_completed = true;
+
+            emit!(MilestoneCompleted {
+                campaign: campaign.key(),
+                milestone: milestone.key(),
+                milestone_index: milestone.milestone_index,
+                yes_weight: milestone.yes_weight,
+                no_weight: milestone.no_weight,
+                timestamp: clock.unix_timestamp,
+            });
+        } else {
+            // Backers rejected the milestone: stop the campaign and keep the
+            // unreleased funds in the vault for `claim_partial_refund`
+            campaign.transition_to(CampaignStatus::Failed)?;
+            campaign.failed_milestone = Some(milestone.milestone_index);
+
+            emit!(CampaignFailed {
+                campaign: campaign.key(),
+                milestone: milestone.key(),
+                milestone_index: milestone.milestone_index,
+                yes_weight: milestone.yes_weight,
+                no_weight: milestone.no_weight,
+                timestamp: clock.unix_timestamp,
+            });
+        }
 
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let milestone = &ctx.accounts.milestone;
+
+        require!(
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            campaign.status == CampaignStatus::Succeeded,
+            HopeRiseError::WithdrawalNotAllowed
+        );
+        require!(milestone.is_completed, HopeRiseError::MilestoneNotCompleted);
+        require!(
+            !milestone.is_released,
+            HopeRiseError::MilestoneAlreadyReleased
+        );
+
+        let campaign = &mut ctx.accounts.campaign;
+        let milestone = &mut ctx.accounts.milestone;
+        campaign.released_bps = campaign
+            .released_bps
+            .checked_add(milestone.payout_bps)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        milestone.is_released = true;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        emit!(MilestoneReleased {
+            campaign: campaign.key(),
+            milestone: milestone.key(),
+            milestone_index: milestone.milestone_index,
+            released_bps: campaign.released_bps,
+            timestamp,
+        });
+
+        let amount = campaign.releasable_amount()?;
+        if amount > 0 {
+            let vault = CampaignVault::resolve(
+                &ctx.accounts.campaign,
+                &ctx.accounts.campaign_vault,
+                ctx.bumps.campaign_vault,
+                &ctx.accounts.mint,
+                &ctx.accounts.token_program,
+                &ctx.accounts.sol_vault,
+                ctx.bumps.sol_vault,
+                &ctx.accounts.system_program,
+            )?;
+            let (creator_amount, fee) = ctx.accounts.campaign.split_platform_fee(amount)?;
+            vault.pay(
+                &ctx.accounts.creator.to_account_info(),
+                &ctx.accounts.creator_token_account,
+                creator_amount,
+            )?;
+            if fee > 0 {
+                vault.pay(
+                    &ctx.accounts.treasury.to_account_info(),
+                    &ctx.accounts.treasury_token_account,
+                    fee,
+                )?;
+            }
+
+            let campaign = &mut ctx.accounts.campaign;
+            campaign.released_amount = campaign
+                .released_amount
+                .checked_add(amount)
+                .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+            emit!(FundsWithdrawn {
+                campaign: campaign.key(),
+                creator: campaign.creator,
+                milestone_index: Some(ctx.accounts.milestone.milestone_index),
+                amount,
+                creator_amount,
+                fee,
+                released_amount: campaign.released_amount,
+                timestamp,
+            });
+        }
+
+        let campaign = &mut ctx.accounts.campaign;
+        if campaign.is_fully_released() {
+            campaign.transition_to(CampaignStatus::Completed)?;
+
+            emit!(CampaignCompleted {
+                campaign: campaign.key(),
+                released_amount: campaign.released_amount,
+                timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn suspend_campaign(ctx: Context<ModerateCampaign>, reason: u16) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        let previous = campaign.status;
+        campaign.transition_to(CampaignStatus::Suspended)?;
+        campaign.suspended_from = Some(previous);
+        campaign.moderation_reason = reason;
+
+        emit!(CampaignModerated {
+            campaign: campaign.key(),
+            status: campaign.status,
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn unsuspend_campaign(ctx: Context<ModerateCampaign>, reason: u16) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        let previous = campaign
+            .suspended_from
+            .ok_or(HopeRiseError::CampaignNotSuspended)?;
+        campaign.transition_to(previous)?;
+        campaign.suspended_from = None;
+        campaign.moderation_reason = reason;
+
+        emit!(CampaignModerated {
+            campaign: campaign.key(),
+            status: campaign.status,
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn force_fail_campaign(ctx: Context<ModerateCampaign>, reason: u16) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        campaign.transition_to(CampaignStatus::Failed)?;
+        campaign.suspended_from = None;
+        campaign.moderation_reason = reason;
+
+        emit!(CampaignModerated {
+            campaign: campaign.key(),
+            status: campaign.status,
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn close_campaign(ctx: Context<CloseCampaign>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        // Once funded, backers are owed the project, and once the deadline
+        // passes the outcome is settled: only finalizing ends the funding
+        // period then
+        if campaign.status == CampaignStatus::Active {
+            require!(
+                campaign.amount_raised < campaign.funding_goal,
+                HopeRiseError::GoalWasMet
+            );
+            require!(
+                Clock::get()?.unix_timestamp < campaign.deadline,
+                HopeRiseError::CampaignEnded
+            );
+        }
+        campaign.transition_to(CampaignStatus::Cancelled)?;
+
+        emit!(CampaignClosed {
+            campaign: campaign.key(),
+            creator: campaign.creator,
+            amount_raised: campaign.amount_raised,
+            backer_count: campaign.backer_count,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let contribution = &ctx.accounts.contribution;
+
+        require!(
+            !matches!(
+                campaign.status,
+                CampaignStatus::Draft | CampaignStatus::Active
+            ),
+            HopeRiseError::CampaignStillActive
+        );
+        require!(
+            campaign.status != CampaignStatus::Suspended,
+            HopeRiseError::CampaignSuspended
+        );
+        require!(
+            matches!(
+                campaign.status,
+                CampaignStatus::Failed | CampaignStatus::Cancelled
+            ),
+            HopeRiseError::GoalWasMet
+        );
+        require!(
+            !contribution.refund_claimed,
+            HopeRiseError::RefundAlreadyClaimed
+        );
+        require!(contribution.amount > 0, HopeRiseError::NoContribution);
+
+        ctx.accounts.refund(&ctx.bumps)
+    }
+
+    pub fn claim_partial_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+        let contribution = &ctx.accounts.contribution;
+
+        require!(
+            campaign.failed_milestone.is_some(),
+            HopeRiseError::CampaignNotFailed
+        );
+        require!(
+            !contribution.refund_claimed,
+            HopeRiseError::RefundAlreadyClaimed
+        );
+        require!(contribution.amount > 0, HopeRiseError::NoContribution);
+
+        ctx.accounts.refund(&ctx.bumps)
+    }
+
+    /// Closes a contribution nobody needs anymore, returning its rent to the
+    /// contributor. Anyone may call it so creators can clear their campaign.
+    pub fn close_contribution(ctx: Context<CloseContribution>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+        let contribution = &ctx.accounts.contribution;
+
+        // Refunds zero the contribution, as does unpledging it in full.
+        // Contributions refunded by older versions only carry the flag.
+        require!(
+            campaign.status == CampaignStatus::Completed
+                || (campaign.is_settled()
+                    && (contribution.refund_claimed || contribution.amount == 0)),
+            HopeRiseError::ContributionNotSettled
+        );
+
+        campaign.open_contributions = campaign
+            .open_contributions
+            .checked_sub(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Closes a milestone of a settled campaign, returning its rent to the creator
+    pub fn close_milestone(ctx: Context<CloseMilestone>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        require!(campaign.is_settled(), HopeRiseError::CampaignNotSettled);
+
+        campaign.open_milestones = campaign
+            .open_milestones
+            .checked_sub(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Closes a vote on a milestone that was released or whose campaign is
+    /// settled, returning its rent to the voter. Anyone may call it.
+    pub fn close_milestone_vote(
+        ctx: Context<CloseMilestoneVote>,
+        milestone_index: u8,
+    ) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        require!(
+            milestone_index < campaign.milestone_count,
+            HopeRiseError::MilestoneMismatch
+        );
+
+        // Milestones are only closed once their campaign is settled, so an
+        // unsettled campaign still has the milestone to check
+        if !campaign.is_settled() {
+            let data = ctx.accounts.milestone.try_borrow_data()?;
+            let milestone = Milestone::try_deserialize(&mut &data[..])?;
+            require!(
+                milestone.is_released,
+                HopeRiseError::MilestoneVoteStillNeeded
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Closes a reward tier of a settled campaign, returning its rent to the creator
+    pub fn close_reward_tier(ctx: Context<CloseRewardTier>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        require!(campaign.is_settled(), HopeRiseError::CampaignNotSettled);
+
+        campaign.open_reward_tiers = campaign
+            .open_reward_tiers
+            .checked_sub(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Closes a stretch goal of a settled campaign, returning its rent to the creator
+    pub fn close_stretch_goal(ctx: Context<CloseStretchGoal>) -> Result<()> {
+        let campaign = &mut ctx.accounts.campaign;
+
+        require!(campaign.is_settled(), HopeRiseError::CampaignNotSettled);
+
+        campaign.open_stretch_goals = campaign
+            .open_stretch_goals
+            .checked_sub(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Closes a settled campaign and its vault once every contribution,
+    /// milestone, reward tier and stretch goal account is gone. Rounding dust
+    /// left in the vault goes to the creator together with the rent.
+    pub fn close_campaign_accounts(ctx: Context<CloseCampaignAccounts>) -> Result<()> {
+        let campaign = &ctx.accounts.campaign;
+
+        require!(campaign.is_settled(), HopeRiseError::CampaignNotSettled);
+        require!(
+            campaign.open_contributions == 0
+                && campaign.open_milestones == 0
+                && campaign.open_reward_tiers == 0
+                && campaign.open_stretch_goals == 0,
+            HopeRiseError::CampaignAccountsStillOpen
+        );
+
+        let vault = CampaignVault::resolve(
+            &ctx.accounts.campaign,
+            &ctx.accounts.campaign_vault,
+            ctx.bumps.campaign_vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            &ctx.accounts.sol_vault,
+            ctx.bumps.sol_vault,
+            &ctx.accounts.system_program,
+        )?;
+        vault.close(
+            &ctx.accounts.creator.to_account_info(),
+            &ctx.accounts.creator_token_account,
+        )?;
+
+        Ok(())
+    }
+
+    /// Upgrades a campaign, milestone, contribution, reward tier or stretch
+    /// goal account written by an older program version to the current layout. Anyone may
+    /// migrate any account, paying for the space the layout adds. Allowed
+    /// while the program is paused, so accounts can be upgraded before it
+    /// resumes.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        let account = ctx.accounts.account.to_account_info();
+        let discriminator = account.try_borrow_data()?.get(..8).map(<[u8]>::to_vec);
+
+        let upgrade = match discriminator.as_deref() {
+            Some(d) if d == Campaign::DISCRIMINATOR => migration::upgrade::<Campaign>,
+            Some(d) if d == Milestone::DISCRIMINATOR => migration::upgrade::<Milestone>,
+            Some(d) if d == Contribution::DISCRIMINATOR => migration::upgrade::<Contribution>,
+            Some(d) if d == RewardTier::DISCRIMINATOR => migration::upgrade::<RewardTier>,
+            Some(d) if d == StretchGoal::DISCRIMINATOR => migration::upgrade::<StretchGoal>,
+            _ => return err!(HopeRiseError::NotVersionedAccount),
+        };
+        let (from_version, to_version) =
+            upgrade(&account, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+        msg!(
+            "Migrated {} from layout version {} to {}",
+            account.key(),
+            from_version,
+            to_version
+        );
+
+        Ok(())
+    }
+}
+
+/// Moves the payout shares of the campaign's milestones gated on stretch
+/// goals still locked to the milestones able to take proof. `accounts` are
+/// all of the campaign's milestones in index order.
+fn reassign_locked_payouts<'info>(
+    campaign: &Account<'info, Campaign>,
+    accounts: &'info [AccountInfo<'info>],
+    timestamp: i64,
+) -> Result<()> {
+    let mut milestones = Vec::with_capacity(campaign.milestone_count as usize);
+    for (milestone_index, account) in (0..campaign.milestone_count).zip(accounts) {
+        let milestone = Account::<Milestone>::try_from(account)?;
+        require!(
+            milestone.campaign == campaign.key() && milestone.milestone_index == milestone_index,
+            HopeRiseError::MilestoneMismatch
+        );
+        require!(
+            milestone.version == Milestone::VERSION,
+            HopeRiseError::AccountNotMigrated
+        );
+        milestones.push(milestone);
+    }
+
+    let unlocked = campaign.unlocked_stretch_goals;
+    let is_locked = |milestone: &Milestone| {
+        milestone
+            .stretch_goal
+            .is_some_and(|goal_index| goal_index >= unlocked)
+    };
+    let mut locked_bps: u16 = 0;
+    for milestone in milestones.iter().filter(|milestone| is_locked(milestone)) {
+        locked_bps = locked_bps
+            .checked_add(milestone.payout_bps)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+    }
+    let eligible = milestones
+        .iter()
+        .filter(|milestone| !is_locked(milestone))
+        .count() as u16;
+
+    if locked_bps > 0 {
+        // Spread evenly over the milestones that can still take proof,
+        // the first ones taking the remainder. Without any, the locked
+        // milestones drop their gate and go to a vote themselves.
+        let mut remainder = locked_bps.checked_rem(eligible).unwrap_or(0);
+        for milestone in milestones.iter_mut() {
+            if eligible == 0 {
+                if !is_locked(milestone) {
+                    continue;
+                }
+                milestone.stretch_goal = None;
+            } else if is_locked(milestone) {
+                milestone.payout_bps = 0;
+            } else {
+                let extra = u16::from(remainder > 0);
+                remainder -= extra;
+                milestone.payout_bps = milestone
+                    .payout_bps
+                    .checked_add(locked_bps / eligible + extra)
+                    .ok_or(HopeRiseError::ArithmeticOverflow)?;
+            }
+            milestone.exit(&crate::ID)?;
+
+            emit!(MilestonePayoutReassigned {
+                campaign: campaign.key(),
+                milestone: milestone.key(),
+                milestone_index: milestone.milestone_index,
+                payout_bps: milestone.payout_bps,
+                stretch_goal: milestone.stretch_goal,
+                timestamp,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Unlocks the campaign's locked stretch goals in `stretch_goals`, which
+/// start with the next locked one, while the amount raised crosses them
+fn unlock_stretch_goals<'info>(
+    campaign: &mut Account<'info, Campaign>,
+    stretch_goals: &'info [AccountInfo<'info>],
+    timestamp: i64,
+) -> Result<()> {
+    let mut stretch_goals = stretch_goals.iter();
+    while campaign.unlocked_stretch_goals < campaign.stretch_goal_count {
+        let Some(account) = stretch_goals.next() else {
+            break;
+        };
+        let mut goal = Account::<StretchGoal>::try_from(account)?;
+        require!(
+            goal.campaign == campaign.key() && goal.goal_index == campaign.unlocked_stretch_goals,
+            HopeRiseError::StretchGoalMismatch
+        );
+        require!(
+            goal.version == StretchGoal::VERSION,
+            HopeRiseError::AccountNotMigrated
+        );
+        if campaign.amount_raised < goal.threshold {
+            break;
+        }
+
+        goal.unlocked_at = timestamp;
+        goal.exit(&crate::ID)?;
+        campaign.unlocked_stretch_goals = campaign
+            .unlocked_stretch_goals
+            .checked_add(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        emit!(StretchGoalUnlocked {
+            campaign: campaign.key(),
+            stretch_goal: goal.key(),
+            goal_index: goal.goal_index,
+            threshold: goal.threshold,
+            amount_raised: campaign.amount_raised,
+            timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = CampaignCounter::SIZE,
+        seeds = [CAMPAIGN_COUNTER_SEED],
+        bump
+    )]
+    pub campaign_counter: Account<'info, CampaignCounter>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePlatformConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = PlatformConfig::SIZE,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ HopeRiseError::Unauthorized
+    )]
+    pub program: Program<'info, program::HopeRise>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ HopeRiseError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAcceptedMints<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ HopeRiseError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePlatformConfig<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ HopeRiseError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = platform_config.pending_admin == Some(new_admin.key()) @ HopeRiseError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, short_description: String, category: Category, cover_image_url: String, story_url: String)]
+pub struct CreateCampaign<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Campaign::space(&title, &short_description, &cover_image_url, &story_url),
+        seeds = [CAMPAIGN_SEED, creator.key().as_ref(), campaign_counter.count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_COUNTER_SEED],
+        bump = campaign_counter.bump
+    )]
+    pub campaign_counter: Account<'info, CampaignCounter>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        token::token_program = token_program,
+    )]
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+#[instruction(title: String, short_description: String, cover_image_url: String, story_url: String)]
+pub struct UpdateCampaignMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        realloc = Campaign::space(&title, &short_description, &cover_image_url, &story_url),
+        realloc::payer = creator,
+        realloc::zero = false,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LaunchCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FundCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        space = Contribution::SIZE,
+        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump,
+        constraint = contribution.version == Contribution::VERSION || contribution.amount == 0 @ HopeRiseError::AccountNotMigrated
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        token::token_program = token_program,
+    )]
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = contributor_token_account.mint == campaign.mint @ HopeRiseError::InvalidTokenAccount,
+        constraint = contributor_token_account.owner == contributor.key() @ HopeRiseError::InvalidTokenAccount,
+    )]
+    pub contributor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = campaign.mint @ HopeRiseError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+
+    /// Reward tier claimed with this contribution, if any
+    #[account(
+        mut,
+        seeds = [REWARD_TIER_SEED, campaign.key().as_ref(), &[reward_tier.tier_index]],
+        bump = reward_tier.bump,
+        constraint = reward_tier.version == RewardTier::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub reward_tier: Option<Account<'info, RewardTier>>,
+
+    /// CHECK: receipt mint created by the handler with the first contribution
+    #[account(
+        mut,
+        seeds = [RECEIPT_MINT_SEED, contribution.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: the contributor's receipt token account, created and checked
+    /// by the associated token program
+    #[account(mut)]
+    pub receipt_token_account: Option<UncheckedAccount<'info>>,
+
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+}
+
+#[derive(Accounts)]
+pub struct Unpledge<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        token::token_program = token_program,
+    )]
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.contributor == contributor.key() @ HopeRiseError::Unauthorized,
+        constraint = contribution.version == Contribution::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = contributor,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
+    )]
+    pub contributor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = campaign.mint @ HopeRiseError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+
+    /// Reward tier claimed by the contributor, required while they hold one
+    #[account(
+        mut,
+        seeds = [REWARD_TIER_SEED, campaign.key().as_ref(), &[reward_tier.tier_index]],
+        bump = reward_tier.bump,
+        constraint = reward_tier.version == RewardTier::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub reward_tier: Option<Account<'info, RewardTier>>,
+
+    /// CHECK: receipt mint of the contribution, burned and closed by the
+    /// handler once the whole pledge is withdrawn
+    #[account(
+        mut,
+        seeds = [RECEIPT_MINT_SEED, contribution.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: token account holding the receipt, whoever it was transferred
+    /// to. Read only when the whole pledge is withdrawn
+    #[account(mut)]
+    pub receipt_token_account: Option<UncheckedAccount<'info>>,
+
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFunds<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = campaign_vault,
+        token::token_program = token_program,
+    )]
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: only receives fee payouts, validated against the platform config
+    #[account(
+        mut,
+        address = platform_config.treasury @ HopeRiseError::Unauthorized
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = campaign.mint @ HopeRiseError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = matches!(campaign.status, CampaignStatus::Draft | CampaignStatus::Active) @ HopeRiseError::CampaignNotActive,
+        constraint = campaign.milestone_count < MAX_MILESTONES_PER_CAMPAIGN @ HopeRiseError::MaxMilestonesReached,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = Milestone::SIZE,
+        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[campaign.milestone_count]],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddRewardTier<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = matches!(campaign.status, CampaignStatus::Draft | CampaignStatus::Active) @ HopeRiseError::CampaignNotActive,
+        constraint = campaign.reward_tier_count < MAX_REWARD_TIERS_PER_CAMPAIGN @ HopeRiseError::MaxRewardTiersReached,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = RewardTier::SIZE,
+        seeds = [REWARD_TIER_SEED, campaign.key().as_ref(), &[campaign.reward_tier_count]],
+        bump
+    )]
+    pub reward_tier: Account<'info, RewardTier>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddStretchGoal<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = matches!(campaign.status, CampaignStatus::Draft | CampaignStatus::Active) @ HopeRiseError::CampaignNotActive,
+        constraint = campaign.stretch_goal_count < MAX_STRETCH_GOALS_PER_CAMPAIGN @ HopeRiseError::MaxStretchGoalsReached,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = StretchGoal::SIZE,
+        seeds = [STRETCH_GOAL_SEED, campaign.key().as_ref(), &[campaign.stretch_goal_count]],
+        bump
+    )]
+    pub stretch_goal: Account<'info, StretchGoal>,
+
+    /// Last stretch goal added, required once the campaign has one
+    #[account(
+        seeds = [STRETCH_GOAL_SEED, campaign.key().as_ref(), &[previous_stretch_goal.goal_index]],
+        bump = previous_stretch_goal.bump
+    )]
+    pub previous_stretch_goal: Option<Account<'info, StretchGoal>>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestoneProof<'info> {
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump,
+        constraint = milestone.campaign == campaign.key() @ HopeRiseError::Unauthorized,
+        constraint = milestone.version == Milestone::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct VoteMilestone<'info> {
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump,
+        constraint = milestone.campaign == campaign.key() @ HopeRiseError::Unauthorized,
+        constraint = milestone.version == Milestone::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub milestone: Account<'info, Milestone>,
+
+    #[account(
+        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.contributor == contributor.key() @ HopeRiseError::Unauthorized,
+        constraint = contribution.version == Contribution::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
         init,
-        payer = authority,
-        space = CampaignCounter::SIZE,
-        seeds = [CAMPAIGN_COUNTER_SEED],
+        payer = contributor,
+        space = MilestoneVote::SIZE,
+        seeds = [MILESTONE_VOTE_SEED, milestone.key().as_ref(), contributor.key().as_ref()],
         bump
     )]
-    pub campaign_counter: Account<'info, CampaignCounter>,
+    pub milestone_vote: Account<'info, MilestoneVote>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub contributor: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct CreateCampaign<'info> {
+pub struct CompleteMilestone<'info> {
     #[account(
-        init,
-        payer = creator,
-        space = Campaign::SIZE,
-        seeds = [CAMPAIGN_SEED, creator.key().as_ref(), campaign_counter.count.to_le_bytes().as_ref()],
-        bump
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
     #[account(
-        mut,
-        seeds = [CAMPAIGN_COUNTER_SEED],
-        bump = campaign_counter.bump
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
     )]
-    pub campaign_counter: Account<'info, CampaignCounter>,
+    pub platform_config: Account<'info, PlatformConfig>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump,
+        constraint = milestone.campaign == campaign.key() @ HopeRiseError::Unauthorized,
+        constraint = milestone.version == Milestone::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub milestone: Account<'info, Milestone>,
 }
 
 #[derive(Accounts)]
-pub struct FundCampaign<'info> {
+pub struct ReleaseMilestone<'info> {
     #[account(
         mut,
         seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
-        bump = campaign.bump
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(
+        mut,
+        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[milestone.milestone_index]],
+        bump = milestone.bump,
+        constraint = milestone.campaign == campaign.key() @ HopeRiseError::Unauthorized,
+        constraint = milestone.version == Milestone::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub milestone: Account<'info, Milestone>,
 
     #[account(
-        init_if_needed,
-        payer = contributor,
+        mut,
         seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
         bump,
-        token::mint = usdc_mint,
+        token::mint = mint,
         token::authority = campaign_vault,
+        token::token_program = token_program,
     )]
-    pub campaign_vault: Account<'info, TokenAccount>,
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        init_if_needed,
-        payer = contributor,
-        space = Contribution::SIZE,
-        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
+        mut,
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
         bump
     )]
-    pub contribution: Account<'info, Contribution>,
+    pub sol_vault: Option<SystemAccount<'info>>,
 
     #[account(mut)]
-    pub contributor: Signer<'info>,
+    pub creator: Signer<'info>,
 
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
-        mut,
-        constraint = contributor_token_account.mint == usdc_mint.key() @ HopeRiseError::InvalidTokenAccount,
-        constraint = contributor_token_account.owner == contributor.key() @ HopeRiseError::InvalidTokenAccount,
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
     )]
-    pub contributor_token_account: Account<'info, TokenAccount>,
+    pub platform_config: Account<'info, PlatformConfig>,
 
+    /// CHECK: only receives fee payouts, validated against the platform config
+    #[account(
+        mut,
+        address = platform_config.treasury @ HopeRiseError::Unauthorized
+    )]
+    pub treasury: UncheckedAccount<'info>,
 
     #[account(
-        constraint = usdc_mint.key().to_string() == USDC_MINT @ HopeRiseError::InvalidMint
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
     )]
-    pub usdc_mint: Account<'info, Mint>,
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    #[account(address = campaign.mint @ HopeRiseError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct WithdrawFunds<'info> {
+pub struct ModerateCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        has_one = admin @ HopeRiseError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
     #[account(
         mut,
         seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
         bump = campaign.bump,
-        has_one = creator @ HopeRiseError::Unauthorized
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
         mut,
         seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
         bump,
-        token::mint = usdc_mint,
+        token::mint = mint,
         token::authority = campaign_vault,
+        token::token_program = token_program,
     )]
-    pub campaign_vault: Account<'info, TokenAccount>,
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
+        bump
+    )]
+    pub sol_vault: Option<SystemAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.contributor == contributor.key() @ HopeRiseError::Unauthorized,
+        constraint = contribution.version == Contribution::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub contribution: Account<'info, Contribution>,
 
+    #[account(mut)]
+    pub contributor: Signer<'info>,
 
     #[account(
         init_if_needed,
-        payer = creator,
-        associated_token::mint = usdc_mint,
-        associated_token::authority = creator,
+        payer = contributor,
+        associated_token::mint = mint,
+        associated_token::authority = contributor,
+        associated_token::token_program = token_program,
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub contributor_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(address = campaign.mint @ HopeRiseError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: receipt mint of the contribution, burned and closed by the handler
     #[account(
-        constraint = usdc_mint.key().to_string() == USDC_MINT @ HopeRiseError::InvalidMint
+        mut,
+        seeds = [RECEIPT_MINT_SEED, contribution.key().as_ref()],
+        bump
     )]
-    pub usdc_mint: Account<'info, Mint>,
+    pub receipt_mint: Option<UncheckedAccount<'info>>,
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub system_program: Program<'info, System>,
+    /// CHECK: token account holding the receipt, whoever it was transferred
+    /// to. Read only when the contribution still has a receipt, so a claim
+    /// repeated after the burn fails on the claim itself
+    #[account(mut)]
+    pub receipt_token_account: Option<UncheckedAccount<'info>>,
+
+    pub receipt_token_program: Option<Program<'info, Token2022>>,
+}
+
+impl<'info> ClaimRefund<'info> {
+    /// Pays the contributor their share of the funds still in the vault,
+    /// burns their receipt and takes the pledge out of the campaign totals
+    fn refund(&mut self, bumps: &ClaimRefundBumps) -> Result<()> {
+        let contribution_amount = self.contribution.amount;
+        // Equals the full contribution unless the campaign failed after
+        // tranches had already been released
+        let refund_amount = self.campaign.unreleased_share(contribution_amount)?;
+
+        let vault = CampaignVault::resolve(
+            &self.campaign,
+            &self.campaign_vault,
+            bumps.campaign_vault,
+            &self.mint,
+            &self.token_program,
+            &self.sol_vault,
+            bumps.sol_vault,
+            &self.system_program,
+        )?;
+        vault.pay(
+            &self.contributor.to_account_info(),
+            &self.contributor_token_account,
+            refund_amount,
+        )?;
+
+        let contribution = &mut self.contribution;
+        contribution.amount = 0;
+        contribution.refund_claimed = true;
+
+        // The receipt set tracks the live backer set
+        if contribution.has_receipt {
+            let receipt =
+                ContributionReceipt::resolve(&self.receipt_mint, &self.receipt_token_program)?;
+            receipt.burn(
+                &self.campaign,
+                &self.contributor,
+                &self.receipt_token_account,
+            )?;
+            contribution.has_receipt = false;
+        }
+
+        let campaign = &mut self.campaign;
+        campaign.record_refund(contribution_amount)?;
+
+        emit!(RefundClaimed {
+            campaign: contribution.campaign,
+            contributor: contribution.contributor,
+            amount: refund_amount,
+            contribution_amount,
+            partial: refund_amount < contribution_amount,
+            amount_raised: campaign.amount_raised,
+            backer_count: campaign.backer_count,
+            total_refunded: campaign.total_refunded,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
-pub struct AddMilestone<'info> {
+pub struct CloseContribution<'info> {
     #[account(
         mut,
         seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
         bump = campaign.bump,
-        has_one = creator @ HopeRiseError::Unauthorized,
-        constraint = campaign.is_active @ HopeRiseError::CampaignNotActive,
-        constraint = campaign.milestone_count < MAX_MILESTONES_PER_CAMPAIGN @ HopeRiseError::MaxMilestonesReached
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
     #[account(
-        init,
-        payer = creator,
-        space = Milestone::SIZE,
-        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[campaign.milestone_count]],
-        bump
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
     )]
-    pub milestone: Account<'info, Milestone>,
+    pub platform_config: Account<'info, PlatformConfig>,
 
-    #[account(mut)]
-    pub creator: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        constraint = contribution.contributor == contributor.key() @ HopeRiseError::Unauthorized,
+        close = contributor,
+        constraint = contribution.version == Contribution::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub contribution: Account<'info, Contribution>,
 
-    pub system_program: Program<'info, System>,
+    /// CHECK: only receives the rent back, tied to the contribution by its seeds
+    #[account(mut)]
+    pub contributor: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction()]
-pub struct CompleteMilestone<'info> {
+pub struct CloseMilestone<'info> {
     #[account(
+        mut,
         seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
         bump = campaign.bump,
-        has_one = creator @ HopeRiseError::Unauthorized
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
     #[account(
         mut,
         seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[milestone.milestone_index]],
         bump = milestone.bump,
-        constraint = milestone.campaign == campaign.key() @ HopeRiseError::Unauthorized
+        close = creator,
+        constraint = milestone.version == Milestone::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub milestone: Account<'info, Milestone>,
 
-    pub creator: Signer<'info>,
+    /// CHECK: only receives the rent back, validated against the campaign
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-pub struct CloseCampaign<'info> {
+#[instruction(milestone_index: u8)]
+pub struct CloseMilestoneVote<'info> {
+    #[account(
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// CHECK: may already be closed once the campaign is settled, and is
+    /// decoded by the instruction otherwise. Only the program writes to its
+    /// PDA, so the data there is a milestone.
+    #[account(
+        seeds = [MILESTONE_SEED, campaign.key().as_ref(), &[milestone_index]],
+        bump
+    )]
+    pub milestone: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_VOTE_SEED, milestone.key().as_ref(), voter.key().as_ref()],
+        bump = milestone_vote.bump,
+        close = voter
+    )]
+    pub milestone_vote: Account<'info, MilestoneVote>,
+
+    /// CHECK: only receives the rent back, tied to the vote by its seeds
+    #[account(mut)]
+    pub voter: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseRewardTier<'info> {
     #[account(
         mut,
         seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
         bump = campaign.bump,
-        has_one = creator @ HopeRiseError::Unauthorized
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [REWARD_TIER_SEED, campaign.key().as_ref(), &[reward_tier.tier_index]],
+        bump = reward_tier.bump,
+        close = creator,
+        constraint = reward_tier.version == RewardTier::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub reward_tier: Account<'info, RewardTier>,
+
+    /// CHECK: only receives the rent back, validated against the campaign
     #[account(mut)]
-    pub creator: Signer<'info>,
+    pub creator: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
-pub struct ClaimRefund<'info> {
+pub struct CloseStretchGoal<'info> {
+    #[account(
+        mut,
+        seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        seeds = [STRETCH_GOAL_SEED, campaign.key().as_ref(), &[stretch_goal.goal_index]],
+        bump = stretch_goal.bump,
+        close = creator,
+        constraint = stretch_goal.version == StretchGoal::VERSION @ HopeRiseError::AccountNotMigrated
+    )]
+    pub stretch_goal: Account<'info, StretchGoal>,
+
+    /// CHECK: only receives the rent back, validated against the campaign
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCampaignAccounts<'info> {
     #[account(
         mut,
         seeds = [CAMPAIGN_SEED, campaign.creator.as_ref(), campaign.campaign_id.to_le_bytes().as_ref()],
-        bump = campaign.bump
+        bump = campaign.bump,
+        has_one = creator @ HopeRiseError::Unauthorized,
+        close = creator,
+        constraint = campaign.version == Campaign::VERSION @ HopeRiseError::AccountNotMigrated
     )]
     pub campaign: Account<'info, Campaign>,
 
+    #[account(
+        seeds = [PLATFORM_CONFIG_SEED],
+        bump = platform_config.bump,
+        constraint = !platform_config.is_paused @ HopeRiseError::ProgramPaused
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
 
     #[account(
         mut,
         seeds = [CAMPAIGN_VAULT_SEED, campaign.key().as_ref()],
         bump,
-        token::mint = usdc_mint,
+        token::mint = mint,
         token::authority = campaign_vault,
+        token::token_program = token_program,
     )]
-    pub campaign_vault: Account<'info, TokenAccount>,
+    pub campaign_vault: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        seeds = [CONTRIBUTION_SEED, campaign.key().as_ref(), contributor.key().as_ref()],
-        bump = contribution.bump,
-        constraint = contribution.contributor == contributor.key() @ HopeRiseError::Unauthorized
+        seeds = [SOL_VAULT_SEED, campaign.key().as_ref()],
+        bump
     )]
-    pub contribution: Account<'info, Contribution>,
+    pub sol_vault: Option<SystemAccount<'info>>,
 
     #[account(mut)]
-    pub contributor: Signer<'info>,
-
+    pub creator: Signer<'info>,
 
     #[account(
         init_if_needed,
-        payer = contributor,
-        associated_token::mint = usdc_mint,
-        associated_token::authority = contributor,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
     )]
-    pub contributor_token_account: Account<'info, TokenAccount>,
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(address = campaign.mint @ HopeRiseError::InvalidMint)]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
 
-    #[account(
-        constraint = usdc_mint.key().to_string() == USDC_MINT @ HopeRiseError::InvalidMint
-    )]
-    pub usdc_mint: Account<'info, Mint>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: decoded by the handler, which only accepts campaign, milestone,
+    /// contribution, reward tier and stretch goal accounts
+    #[account(mut, owner = crate::ID @ HopeRiseError::NotVersionedAccount)]
+    pub account: UncheckedAccount<'info>,
+
+    /// Pays the rent of any space the current layout adds
+    #[account(mut)]
+    pub payer: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
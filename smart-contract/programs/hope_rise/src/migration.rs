@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::system_program;
+
+use crate::errors::HopeRiseError;
+use crate::state::Versioned;
+
+/// Rewrites a `T` account in the current layout and returns the versions it
+/// moved between. The account grows when the layout needs more space, with
+/// `payer` topping up its rent, but never shrinks: its lamports belong to
+/// whoever funded it.
+pub fn upgrade<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<(u8, u8)> {
+    // Accounts from before the layout reserve can end short of fields added
+    // since. Read against as much zeroed space as an account may grow by,
+    // those decode as zero like the rest of the reserve.
+    let mut data = account.try_borrow_data()?.to_vec();
+    data.resize(data.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+    let mut state = T::try_deserialize(&mut &data[..])?;
+    let from_version = state.version();
+    state.upgrade(Clock::get()?.unix_timestamp);
+    require!(
+        state.version() > from_version,
+        HopeRiseError::AccountAlreadyMigrated
+    );
+
+    let space = state.current_size();
+    if space > account.data_len() {
+        let shortfall = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(account.lamports());
+        if shortfall > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, shortfall)?;
+        }
+        account.resize(space)?;
+    }
+
+    let mut bytes = Vec::with_capacity(space);
+    state.try_serialize(&mut bytes)?;
+    let mut data = account.try_borrow_mut_data()?;
+    data[..bytes.len()].copy_from_slice(&bytes);
+    // Everything past the fields is the reserve later layouts decode from
+    data[bytes.len()..].fill(0);
+
+    Ok((from_version, state.version()))
+}
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::{self, AssociatedToken};
+use anchor_spl::token_2022::{self, spl_token_2022, Token2022};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{
+    Field, TokenMetadata,
+};
+use anchor_spl::token_2022_extensions::{self as extensions};
+use anchor_spl::token_interface::TokenAccount;
+use spl_token_2022::extension::ExtensionType;
+
+use crate::constants::{CAMPAIGN_SEED, RECEIPT_CAMPAIGN_FIELD, RECEIPT_MINT_SEED, RECEIPT_SYMBOL};
+use crate::errors::HopeRiseError;
+use crate::state::Campaign;
+
+/// Accounts of a contribution's receipt token: a Token-2022 mint with a
+/// supply of one whose metadata points at the campaign. The campaign PDA is
+/// its permanent delegate and close authority, so a refund can burn the
+/// receipt wherever it is held and hand the mint's rent back to the backer.
+pub struct ContributionReceipt<'a, 'info> {
+    mint: &'a UncheckedAccount<'info>,
+    token_program: &'a Program<'info, Token2022>,
+}
+
+impl<'a, 'info> ContributionReceipt<'a, 'info> {
+    /// Picks the receipt accounts out of an instruction's optional accounts
+    pub fn resolve(
+        mint: &'a Option<UncheckedAccount<'info>>,
+        token_program: &'a Option<Program<'info, Token2022>>,
+    ) -> Result<Self> {
+        match (mint, token_program) {
+            (Some(mint), Some(token_program)) => Ok(Self {
+                mint,
+                token_program,
+            }),
+            _ => err!(HopeRiseError::MissingReceiptAccount),
+        }
+    }
+
+    /// Creates the receipt mint for `contribution` and mints its token into
+    /// the backer's associated token account, both paid for by the backer.
+    /// The receipt cannot be transferred unless the campaign allows it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue(
+        &self,
+        campaign: &Account<'info, Campaign>,
+        contribution: &Pubkey,
+        mint_bump: u8,
+        backer: &Signer<'info>,
+        backer_token_account: &Option<UncheckedAccount<'info>>,
+        associated_token_program: &Option<Program<'info, AssociatedToken>>,
+        system_program: &Program<'info, System>,
+    ) -> Result<()> {
+        let (Some(backer_token_account), Some(associated_token_program)) =
+            (backer_token_account, associated_token_program)
+        else {
+            return err!(HopeRiseError::MissingReceiptAccount);
+        };
+        let campaign_key = campaign.key();
+        let campaign_id = campaign.campaign_id.to_le_bytes();
+        let campaign_seeds = &[
+            CAMPAIGN_SEED,
+            campaign.creator.as_ref(),
+            campaign_id.as_ref(),
+            &[campaign.bump],
+        ];
+        let campaign_signer = &[&campaign_seeds[..]];
+        let mint_seeds = &[RECEIPT_MINT_SEED, contribution.as_ref(), &[mint_bump]];
+        let mint_signer = &[&mint_seeds[..]];
+        let program = self.token_program.to_account_info();
+        let mint = self.mint.to_account_info();
+        let authority = campaign.to_account_info();
+
+        let mut extension_types = vec![
+            ExtensionType::MetadataPointer,
+            ExtensionType::PermanentDelegate,
+            ExtensionType::MintCloseAuthority,
+        ];
+        if !campaign.transferable_receipts {
+            extension_types.push(ExtensionType::NonTransferable);
+        }
+        let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
+            &extension_types,
+        )?;
+        let campaign_field = (RECEIPT_CAMPAIGN_FIELD.to_string(), campaign_key.to_string());
+        let metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(campaign_key))?,
+            mint: mint.key(),
+            name: campaign.title.clone(),
+            symbol: RECEIPT_SYMBOL.to_string(),
+            uri: campaign.story_url.clone(),
+            additional_metadata: vec![campaign_field.clone()],
+        };
+        // Token-2022 grows the mint to fit the metadata itself, but the rent
+        // for the grown account has to be there up front
+        let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+        // Anyone can send lamports to the mint's address ahead of time, which
+        // would make `create_account` fail: like Anchor's `init`, top such an
+        // account up to rent exemption and allocate it in place instead
+        let system = system_program.to_account_info();
+        let current_lamports = mint.lamports();
+        if current_lamports == 0 {
+            let cpi_accounts = system_program::CreateAccount {
+                from: backer.to_account_info(),
+                to: mint.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(system, cpi_accounts, mint_signer);
+            system_program::create_account(cpi_ctx, lamports, space as u64, &token_2022::ID)?;
+        } else {
+            let shortfall = lamports.saturating_sub(current_lamports);
+            if shortfall > 0 {
+                let cpi_accounts = system_program::Transfer {
+                    from: backer.to_account_info(),
+                    to: mint.clone(),
+                };
+                system_program::transfer(CpiContext::new(system.clone(), cpi_accounts), shortfall)?;
+            }
+            let cpi_accounts = system_program::Allocate {
+                account_to_allocate: mint.clone(),
+            };
+            system_program::allocate(
+                CpiContext::new_with_signer(system.clone(), cpi_accounts, mint_signer),
+                space as u64,
+            )?;
+            let cpi_accounts = system_program::Assign {
+                account_to_assign: mint.clone(),
+            };
+            system_program::assign(
+                CpiContext::new_with_signer(system, cpi_accounts, mint_signer),
+                &token_2022::ID,
+            )?;
+        }
+
+        let cpi_accounts = extensions::MetadataPointerInitialize {
+            token_program_id: program.clone(),
+            mint: mint.clone(),
+        };
+        extensions::metadata_pointer_initialize(
+            CpiContext::new(program.clone(), cpi_accounts),
+            Some(campaign_key),
+            Some(mint.key()),
+        )?;
+        let cpi_accounts = extensions::PermanentDelegateInitialize {
+            token_program_id: program.clone(),
+            mint: mint.clone(),
+        };
+        extensions::permanent_delegate_initialize(
+            CpiContext::new(program.clone(), cpi_accounts),
+            &campaign_key,
+        )?;
+        let cpi_accounts = extensions::MintCloseAuthorityInitialize {
+            token_program_id: program.clone(),
+            mint: mint.clone(),
+        };
+        extensions::mint_close_authority_initialize(
+            CpiContext::new(program.clone(), cpi_accounts),
+            Some(&campaign_key),
+        )?;
+        if !campaign.transferable_receipts {
+            let cpi_accounts = extensions::NonTransferableMintInitialize {
+                token_program_id: program.clone(),
+                mint: mint.clone(),
+            };
+            extensions::non_transferable_mint_initialize(CpiContext::new(
+                program.clone(),
+                cpi_accounts,
+            ))?;
+        }
+
+        let cpi_accounts = token_2022::InitializeMint2 { mint: mint.clone() };
+        token_2022::initialize_mint2(
+            CpiContext::new(program.clone(), cpi_accounts),
+            0,
+            &campaign_key,
+            None,
+        )?;
+
+        let cpi_accounts = extensions::TokenMetadataInitialize {
+            program_id: program.clone(),
+            metadata: mint.clone(),
+            update_authority: authority.clone(),
+            mint_authority: authority.clone(),
+            mint: mint.clone(),
+        };
+        extensions::token_metadata_initialize(
+            CpiContext::new_with_signer(program.clone(), cpi_accounts, campaign_signer),
+            metadata.name,
+            metadata.symbol,
+            metadata.uri,
+        )?;
+        let cpi_accounts = extensions::TokenMetadataUpdateField {
+            program_id: program.clone(),
+            metadata: mint.clone(),
+            update_authority: authority.clone(),
+        };
+        extensions::token_metadata_update_field(
+            CpiContext::new_with_signer(program.clone(), cpi_accounts, campaign_signer),
+            Field::Key(campaign_field.0),
+            campaign_field.1,
+        )?;
+
+        let cpi_accounts = associated_token::Create {
+            payer: backer.to_account_info(),
+            associated_token: backer_token_account.to_account_info(),
+            authority: backer.to_account_info(),
+            mint: mint.clone(),
+            system_program: system_program.to_account_info(),
+            token_program: program.clone(),
+        };
+        associated_token::create(CpiContext::new(
+            associated_token_program.to_account_info(),
+            cpi_accounts,
+        ))?;
+
+        let cpi_accounts = token_2022::MintTo {
+            mint: mint.clone(),
+            to: backer_token_account.to_account_info(),
+            authority,
+        };
+        token_2022::mint_to(
+            CpiContext::new_with_signer(program, cpi_accounts, campaign_signer),
+            1,
+        )
+    }
+
+    /// Owner of `token_account` when it is a Token-2022 account holding the receipt
+    fn holder(&self, token_account: &AccountInfo) -> Option<Pubkey> {
+        if *token_account.owner != token_2022::ID {
+            return None;
+        }
+        let data = token_account.try_borrow_data().ok()?;
+        let state = TokenAccount::try_deserialize(&mut &data[..]).ok()?;
+        (state.mint == self.mint.key() && state.amount == 1).then_some(state.owner)
+    }
+
+    /// Burns the receipt out of `holder_token_account` as the permanent
+    /// delegate, then closes the mint. The backer gets the mint's rent back,
+    /// and the token account's too when they still hold the receipt.
+    pub fn burn(
+        &self,
+        campaign: &Account<'info, Campaign>,
+        backer: &Signer<'info>,
+        holder_token_account: &Option<UncheckedAccount<'info>>,
+    ) -> Result<()> {
+        let (holder_token_account, holder) = holder_token_account
+            .as_ref()
+            .and_then(|account| Some((account, self.holder(account)?)))
+            .ok_or(HopeRiseError::InvalidReceiptAccount)?;
+        let campaign_id = campaign.campaign_id.to_le_bytes();
+        let campaign_seeds = &[
+            CAMPAIGN_SEED,
+            campaign.creator.as_ref(),
+            campaign_id.as_ref(),
+            &[campaign.bump],
+        ];
+        let campaign_signer = &[&campaign_seeds[..]];
+        let program = self.token_program.to_account_info();
+        let mint = self.mint.to_account_info();
+
+        let cpi_accounts = token_2022::Burn {
+            mint: mint.clone(),
+            from: holder_token_account.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        token_2022::burn(
+            CpiContext::new_with_signer(program.clone(), cpi_accounts, campaign_signer),
+            1,
+        )?;
+
+        if holder == backer.key() {
+            let cpi_accounts = token_2022::CloseAccount {
+                account: holder_token_account.to_account_info(),
+                destination: backer.to_account_info(),
+                authority: backer.to_account_info(),
+            };
+            token_2022::close_account(CpiContext::new(program.clone(), cpi_accounts))?;
+        }
+
+        let cpi_accounts = token_2022::CloseAccount {
+            account: mint,
+            destination: backer.to_account_info(),
+            authority: campaign.to_account_info(),
+        };
+        token_2022::close_account(CpiContext::new_with_signer(
+            program,
+            cpi_accounts,
+            campaign_signer,
+        ))
+    }
+}
@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    BPS_DENOMINATOR, LAYOUT_RESERVE, LEGACY_USDC_MINT, MAX_ACCEPTED_MINTS, MAX_DESCRIPTION_LENGTH,
+    MAX_TITLE_LENGTH, MAX_URL_LENGTH, MIN_APPROVAL_THRESHOLD_BPS,
+};
+use crate::errors::HopeRiseError;
+
 /// Campaign category enum matching frontend categories
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Category {
@@ -11,6 +17,75 @@ pub enum Category {
     Arts,
 }
 
+/// Asset a campaign raises funds in
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FundingAsset {
+    /// SPL token accepted by the platform, held in the campaign vault token account
+    Token,
+    /// Native SOL, held as lamports in the campaign SOL vault
+    Sol,
+}
+
+/// Campaign lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CampaignStatus {
+    /// Created but not yet accepting contributions; milestones can be set up
+    Draft,
+    /// Accepting contributions until the deadline
+    Active,
+    /// Funding goal was met, creator payouts and milestone votes are allowed
+    Succeeded,
+    /// Goal missed, milestone rejected or failed by the platform; backers can claim refunds
+    Failed,
+    /// Cancelled by the creator; backers can claim refunds
+    Cancelled,
+    /// Frozen by the platform pending review
+    Suspended,
+    /// All funds have been released to the creator
+    Completed,
+}
+
+impl CampaignStatus {
+    /// Whether the lifecycle allows moving from this status to `next`
+    pub fn can_transition_to(self, next: CampaignStatus) -> bool {
+        use CampaignStatus::*;
+
+        matches!(
+            (self, next),
+            (Draft, Active)
+                | (Draft, Cancelled)
+                | (Active, Succeeded)
+                | (Active, Failed)
+                | (Active, Cancelled)
+                | (Active, Suspended)
+                | (Succeeded, Failed)
+                | (Succeeded, Suspended)
+                | (Succeeded, Completed)
+                | (Suspended, Active)
+                | (Suspended, Succeeded)
+                | (Suspended, Failed)
+        )
+    }
+}
+
+/// Accounts whose layout is versioned. Layouts are append only and accounts
+/// keep zeroed spare bytes past their fields, so an account written by an
+/// older version still decodes into the current struct, with the fields it
+/// predates read as zero. Instructions reject such accounts until they are
+/// migrated.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    /// Layout version the account was last written with (0 for accounts
+    /// created before versioning)
+    fn version(&self) -> u8;
+
+    /// Account size the current layout needs for this state
+    fn current_size(&self) -> usize;
+
+    /// Sets the fields added since `version()` and marks the account current.
+    /// `now` is the migration time, for state the older layout left implicit.
+    fn upgrade(&mut self, now: i64);
+}
+
 /// Global campaign counter for generating unique campaign IDs
 #[account]
 pub struct CampaignCounter {
@@ -29,7 +104,37 @@ impl CampaignCounter {
         1;   // bump
 }
 
-/// Main campaign account storing all campaign data
+/// Program-level configuration: admin, pause flag, accepted token mints and platform fee
+#[account]
+pub struct PlatformConfig {
+    /// Admin allowed to update the configuration
+    pub admin: Pubkey,
+    /// Token mints campaigns may raise funds in
+    pub accepted_mints: Vec<Pubkey>,
+    /// PDA bump
+    pub bump: u8,
+    /// Fee taken from creator payouts (basis points), snapshotted by new campaigns
+    pub fee_bps: u16,
+    /// Wallet receiving platform fees (or the owner of its token accounts)
+    pub treasury: Pubkey,
+    /// Admin proposed by the current admin, pending acceptance
+    pub pending_admin: Option<Pubkey>,
+    /// Whether state-mutating instructions are frozen
+    pub is_paused: bool,
+}
+
+impl PlatformConfig {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // admin
+        (4 + 32 * MAX_ACCEPTED_MINTS) + // accepted_mints
+        1 + // bump
+        2 + // fee_bps
+        32 + // treasury
+        (1 + 32) + // pending_admin
+        1; // is_paused
+}
+
+/// Main campaign account storing all campaign data, sized to its metadata
 #[account]
 pub struct Campaign {
     /// Unique campaign identifier
@@ -46,33 +151,286 @@ pub struct Campaign {
     pub cover_image_url: String,
     /// IPFS hash for long story content
     pub story_url: String,
-    /// Target funding goal in lamports
+    /// Target funding goal in base units of the campaign asset
     pub funding_goal: u64,
     /// Campaign deadline (Unix timestamp)
     pub deadline: i64,
-    /// Total amount raised in lamports
+    /// Amount currently pledged in base units of the campaign asset, net of
+    /// unpledges and refunds
     pub amount_raised: u64,
-    /// Number of unique backers
+    /// Number of backers with a pledge still in the campaign
     pub backer_count: u64,
-    /// Whether campaign is active
-    pub is_active: bool,
+    /// Lifecycle status
+    pub status: CampaignStatus,
     /// Creation timestamp
     pub created_at: i64,
     /// Number of milestones added
     pub milestone_count: u8,
     /// PDA bump
     pub bump: u8,
+    /// Total amount released from the vault to the creator
+    pub released_amount: u64,
+    /// Sum of payout shares (basis points) assigned to milestones
+    pub allocated_bps: u16,
+    /// Sum of payout shares (basis points) of released milestones
+    pub released_bps: u16,
+    /// Share of amount raised that must vote on a milestone (basis points)
+    pub vote_quorum_bps: u16,
+    /// Share of cast votes that must approve a milestone (basis points)
+    pub vote_approval_bps: u16,
+    /// Index of the milestone rejected by backers, if the campaign failed at one
+    pub failed_milestone: Option<u8>,
+    /// Token mint the campaign raises funds in (default pubkey for SOL campaigns)
+    pub mint: Pubkey,
+    /// Asset the campaign raises funds in
+    pub asset: FundingAsset,
+    /// Platform fee on payouts (basis points), fixed at creation
+    pub fee_bps: u16,
+    /// Platform reason code recorded by the last moderation action (0 = none)
+    pub moderation_reason: u16,
+    /// Whether the campaign may be finalized before the deadline once the goal is met
+    pub allow_early_close: bool,
+    /// Timestamp the funding period was finalized (0 until finalized)
+    pub finalized_at: i64,
+    /// Contribution accounts created for the campaign and not closed yet
+    pub open_contributions: u32,
+    /// Milestone accounts created for the campaign and not closed yet
+    pub open_milestones: u8,
+    /// Layout version the account was last written with
+    pub version: u8,
+    /// Number of reward tiers added
+    pub reward_tier_count: u8,
+    /// Reward tier accounts created for the campaign and not closed yet
+    pub open_reward_tiers: u8,
+    /// Whether backers may transfer their contribution receipts
+    pub transferable_receipts: bool,
+    /// Seconds before the deadline after which pledges can no longer be withdrawn
+    pub unpledge_cutoff: i64,
+    /// Pledges refunded to backers and taken out of `amount_raised`
+    pub total_refunded: u64,
+    /// Number of stretch goals added
+    pub stretch_goal_count: u8,
+    /// Stretch goal accounts created for the campaign and not closed yet
+    pub open_stretch_goals: u8,
+    /// Number of stretch goals unlocked. Goals unlock in index order, so
+    /// these are the first ones.
+    pub unlocked_stretch_goals: u8,
+    /// Status the campaign had before it was suspended, restored by
+    /// `unsuspend_campaign`
+    pub suspended_from: Option<CampaignStatus>,
 }
 
 impl Campaign {
-    // Account size calculation:
+    /// Layout version written by this program
+    pub const VERSION: u8 = 7;
+
+    // Everything but the string contents:
     // 8 (discriminator) + 8 (campaign_id) + 32 (creator) +
-    // (4 + 80) (title) + (4 + 200) (short_description) + 1 (category) +
-    // (4 + 200) (cover_image_url) + (4 + 200) (story_url) +
+    // 4 (title length) + 4 (short_description length) + 1 (category) +
+    // 4 (cover_image_url length) + 4 (story_url length) +
     // 8 (funding_goal) + 8 (deadline) + 8 (amount_raised) + 8 (backer_count) +
-    // 1 (is_active) + 8 (created_at) + 1 (milestone_count) + 1 (bump)
-    // = 8 + 8 + 32 + 84 + 204 + 1 + 204 + 204 + 8 + 8 + 8 + 8 + 1 + 8 + 1 + 1 = 788
-    pub const SIZE: usize = 800; // Rounded up for safety
+    // 1 (status) + 8 (created_at) + 1 (milestone_count) + 1 (bump) +
+    // 8 (released_amount) + 2 (allocated_bps) + 2 (released_bps) +
+    // 2 (vote_quorum_bps) + 2 (vote_approval_bps) + (1 + 1) (failed_milestone) +
+    // 32 (mint) + 1 (asset) + 2 (fee_bps) + 2 (moderation_reason) +
+    // 1 (allow_early_close) + 8 (finalized_at) + 4 (open_contributions) +
+    // 1 (open_milestones) + 1 (version) + 1 (reward_tier_count) +
+    // 1 (open_reward_tiers) + 1 (transferable_receipts) +
+    // 8 (unpledge_cutoff) + 8 (total_refunded) + 1 (stretch_goal_count) +
+    // 1 (open_stretch_goals) + 1 (unlocked_stretch_goals) +
+    // (1 + 1) (suspended_from) = 202
+    const FIXED_SIZE: usize = 202 + LAYOUT_RESERVE;
+
+    /// Account size of a campaign holding the given metadata
+    pub fn space(
+        title: &str,
+        short_description: &str,
+        cover_image_url: &str,
+        story_url: &str,
+    ) -> usize {
+        Self::FIXED_SIZE
+            + title.len()
+            + short_description.len()
+            + cover_image_url.len()
+            + story_url.len()
+    }
+
+    /// Validates and stores the creator-provided metadata
+    pub fn set_metadata(
+        &mut self,
+        title: String,
+        short_description: String,
+        cover_image_url: String,
+        story_url: String,
+    ) -> Result<()> {
+        require!(title.len() <= MAX_TITLE_LENGTH, HopeRiseError::TitleTooLong);
+        require!(
+            short_description.len() <= MAX_DESCRIPTION_LENGTH,
+            HopeRiseError::DescriptionTooLong
+        );
+        require!(
+            cover_image_url.len() <= MAX_URL_LENGTH,
+            HopeRiseError::UrlTooLong
+        );
+        require!(story_url.len() <= MAX_URL_LENGTH, HopeRiseError::UrlTooLong);
+
+        self.title = title;
+        self.short_description = short_description;
+        self.cover_image_url = cover_image_url;
+        self.story_url = story_url;
+        Ok(())
+    }
+
+    /// Moves the campaign to `next`, rejecting transitions the lifecycle does not allow
+    pub fn transition_to(&mut self, next: CampaignStatus) -> Result<()> {
+        if !self.status.can_transition_to(next) {
+            msg!(
+                "Illegal campaign status transition: {:?} -> {:?}",
+                self.status,
+                next
+            );
+            return err!(HopeRiseError::IllegalStatusTransition);
+        }
+
+        self.status = next;
+        Ok(())
+    }
+
+    /// Whether every unit raised has been paid out to the creator
+    pub fn is_fully_released(&self) -> bool {
+        self.released_bps == self.allocated_bps && self.released_amount == self.amount_raised
+    }
+
+    /// Amount unlocked for the creator that has not been paid out yet.
+    /// The share not assigned to any milestone unlocks once the goal is met,
+    /// each milestone share unlocks when that milestone is released.
+    pub fn releasable_amount(&self) -> Result<u64> {
+        let unlocked_bps = BPS_DENOMINATOR
+            .checked_sub(self.allocated_bps as u64)
+            .and_then(|bps| bps.checked_add(self.released_bps as u64))
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        let unlocked = (self.amount_raised as u128)
+            .checked_mul(unlocked_bps as u128)
+            .map(|amount| amount / BPS_DENOMINATOR as u128)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        (unlocked as u64)
+            .checked_sub(self.released_amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow.into())
+    }
+
+    /// Splits a payout into the creator's amount and the platform fee
+    pub fn split_platform_fee(&self, amount: u64) -> Result<(u64, u64)> {
+        let fee = (amount as u128)
+            .checked_mul(self.fee_bps as u128)
+            .map(|fee| fee / BPS_DENOMINATOR as u128)
+            .ok_or(HopeRiseError::ArithmeticOverflow)? as u64;
+        let creator_amount = amount
+            .checked_sub(fee)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok((creator_amount, fee))
+    }
+
+    /// Amount raised by the end of funding, including pledges refunded since
+    pub fn gross_raised(&self) -> Result<u64> {
+        self.amount_raised
+            .checked_add(self.total_refunded)
+            .ok_or(HopeRiseError::ArithmeticOverflow.into())
+    }
+
+    /// Pro-rata share of the unreleased funds owed to a contribution. Shares
+    /// come out of the gross amount so earlier refunds don't shift them.
+    pub fn unreleased_share(&self, contribution_amount: u64) -> Result<u64> {
+        let gross_raised = self.gross_raised()?;
+        let unreleased = gross_raised
+            .checked_sub(self.released_amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        let share = (contribution_amount as u128)
+            .checked_mul(unreleased as u128)
+            .and_then(|amount| amount.checked_div(gross_raised as u128))
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok(share as u64)
+    }
+
+    /// Takes a refunded pledge out of the campaign totals
+    pub fn record_refund(&mut self, contribution_amount: u64) -> Result<()> {
+        self.amount_raised = self
+            .amount_raised
+            .checked_sub(contribution_amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        self.backer_count = self
+            .backer_count
+            .checked_sub(1)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+        self.total_refunded = self
+            .total_refunded
+            .checked_add(contribution_amount)
+            .ok_or(HopeRiseError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Whether the campaign reached a status no instruction moves it out of
+    pub fn is_settled(&self) -> bool {
+        matches!(
+            self.status,
+            CampaignStatus::Completed | CampaignStatus::Failed | CampaignStatus::Cancelled
+        )
+    }
+}
+
+impl Versioned for Campaign {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn current_size(&self) -> usize {
+        Self::space(
+            &self.title,
+            &self.short_description,
+            &self.cover_image_url,
+            &self.story_url,
+        )
+    }
+
+    fn upgrade(&mut self, now: i64) {
+        if self.version == 0 {
+            // The original layout ended at `bump` and stored `is_active`
+            // where `status` sits, so it reads as Draft (closed) or Active.
+            // Those campaigns raised devnet USDC, closing was the only way to
+            // end one early and the deadline settled the rest.
+            let goal_met = self.amount_raised >= self.funding_goal;
+            self.status = match self.status {
+                CampaignStatus::Active if now < self.deadline => CampaignStatus::Active,
+                _ if goal_met => CampaignStatus::Succeeded,
+                CampaignStatus::Active => CampaignStatus::Failed,
+                _ => CampaignStatus::Cancelled,
+            };
+            if matches!(
+                self.status,
+                CampaignStatus::Succeeded | CampaignStatus::Failed
+            ) {
+                self.finalized_at = now.min(self.deadline);
+            }
+            self.mint = LEGACY_USDC_MINT;
+            self.asset = FundingAsset::Token;
+            self.vote_approval_bps = MIN_APPROVAL_THRESHOLD_BPS as u16;
+            // Every backer got a contribution account and every milestone
+            // its own, none of which could be closed
+            self.open_contributions = self.backer_count.try_into().unwrap_or(u32::MAX);
+            self.open_milestones = self.milestone_count;
+            // Backers funded these campaigns without a way back out, and a
+            // cutoff as long as the funding period keeps it that way
+            self.unpledge_cutoff = self.deadline.saturating_sub(self.created_at);
+            // Refunds claimed before stay counted in amount_raised, which
+            // keeps the gross amount later refunds are shared out of intact.
+            // Withdrawals were not recorded either: the creator's payout is
+            // whatever the vault still holds.
+        }
+        self.version = Self::VERSION;
+    }
 }
 
 /// Milestone account linked to a campaign
@@ -84,18 +442,94 @@ pub struct Milestone {
     pub milestone_index: u8,
     /// Milestone title (max 100 characters)
     pub title: String,
-    /// Target amount for this milestone (in lamports)
+    /// Target amount for this milestone (in base units of the campaign asset)
     pub target_amount: u64,
     /// Whether milestone is completed
     pub is_completed: bool,
     /// PDA bump
     pub bump: u8,
+    /// Share of funds raised paid out for this milestone (basis points)
+    pub payout_bps: u16,
+    /// Whether the milestone tranche has been released to the creator
+    pub is_released: bool,
+    /// IPFS hash of the creator's proof of delivery
+    pub proof_url: String,
+    /// End of the backer voting window (Unix timestamp, 0 until proof is submitted)
+    pub voting_ends_at: i64,
+    /// Contribution weight voting to approve
+    pub yes_weight: u64,
+    /// Contribution weight voting to reject
+    pub no_weight: u64,
+    /// Layout version the account was last written with
+    pub version: u8,
+    /// Index of the stretch goal that has to unlock before proof can be
+    /// submitted, if the milestone is gated on one
+    pub stretch_goal: Option<u8>,
 }
 
 impl Milestone {
+    /// Layout version written by this program
+    pub const VERSION: u8 = 2;
+
     // 8 (discriminator) + 32 (campaign) + 1 (index) + (4 + 100) (title) +
-    // 8 (target_amount) + 1 (is_completed) + 1 (bump) = 155
-    pub const SIZE: usize = 160; // Rounded up
+    // 8 (target_amount) + 1 (is_completed) + 1 (bump) + 2 (payout_bps) +
+    // 1 (is_released) + (4 + 200) (proof_url) + 8 (voting_ends_at) +
+    // 8 (yes_weight) + 8 (no_weight) + 1 (version) + (1 + 1) (stretch_goal) = 389
+    pub const SIZE: usize = 392; // Rounded up, later fields take the spare bytes
+
+    /// Whether enough of the amount raised voted for the result to count
+    pub fn quorum_met(&self, amount_raised: u64, quorum_bps: u16) -> bool {
+        let total = self.yes_weight as u128 + self.no_weight as u128;
+
+        total > 0 && total * BPS_DENOMINATOR as u128 >= amount_raised as u128 * quorum_bps as u128
+    }
+
+    /// Whether the cast votes meet the campaign's approval threshold
+    pub fn approved(&self, approval_bps: u16) -> bool {
+        let yes = self.yes_weight as u128;
+        let total = yes + self.no_weight as u128;
+
+        yes * BPS_DENOMINATOR as u128 >= total * approval_bps as u128
+    }
+}
+
+impl Versioned for Milestone {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn current_size(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn upgrade(&mut self, _now: i64) {
+        // The original layout ended at `is_completed` and `bump`, so every
+        // field since reads as zero: no payout share and no stretch goal gate
+        self.version = Self::VERSION;
+    }
+}
+
+/// Vote cast by a contributor on a milestone, one per (milestone, contributor)
+#[account]
+pub struct MilestoneVote {
+    /// Reference to the milestone voted on
+    pub milestone: Pubkey,
+    /// Contributor who cast the vote
+    pub voter: Pubkey,
+    /// Vote weight (contribution amount at the time of voting)
+    pub weight: u64,
+    /// Whether the vote approves the milestone
+    pub approve: bool,
+    /// Timestamp of the vote
+    pub voted_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MilestoneVote {
+    // 8 (discriminator) + 32 (milestone) + 32 (voter) + 8 (weight) +
+    // 1 (approve) + 8 (voted_at) + 1 (bump) = 90
+    pub const SIZE: usize = 96; // Rounded up
 }
 
 /// Contribution account tracking individual backer contributions
@@ -105,7 +539,8 @@ pub struct Contribution {
     pub campaign: Pubkey,
     /// Contributor's wallet address
     pub contributor: Pubkey,
-    /// Total amount contributed (in lamports)
+    /// Amount pledged (in base units of the campaign asset), zeroed once
+    /// refunded
     pub amount: u64,
     /// Timestamp of first contribution
     pub contributed_at: i64,
@@ -113,10 +548,135 @@ pub struct Contribution {
     pub refund_claimed: bool,
     /// PDA bump
     pub bump: u8,
+    /// Layout version the account was last written with
+    pub version: u8,
+    /// Index of the reward tier claimed by the contributor, if any
+    pub reward_tier: Option<u8>,
+    /// Whether a receipt token minted for the contribution is still live
+    pub has_receipt: bool,
 }
 
 impl Contribution {
+    /// Layout version written by this program
+    pub const VERSION: u8 = 3;
+
     // 8 (discriminator) + 32 (campaign) + 32 (contributor) + 8 (amount) +
-    // 8 (contributed_at) + 1 (refund_claimed) + 1 (bump) = 90
-    pub const SIZE: usize = 96; // Rounded up
+    // 8 (contributed_at) + 1 (refund_claimed) + 1 (bump) + 1 (version) +
+    // (1 + 1) (reward_tier) + 1 (has_receipt) = 94
+    pub const SIZE: usize = 96; // Rounded up, later fields take the spare bytes
+}
+
+impl Versioned for Contribution {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn current_size(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn upgrade(&mut self, _now: i64) {
+        // The original layout ended at `bump`, so the contribution reads
+        // without a reward tier or receipt
+        self.version = Self::VERSION;
+    }
+}
+
+/// Perk of a campaign, claimed by backers pledging at least `min_pledge`
+/// while supply lasts
+#[account]
+pub struct RewardTier {
+    /// Reference to parent campaign
+    pub campaign: Pubkey,
+    /// Tier index (0-based)
+    pub tier_index: u8,
+    /// Minimum total contribution to claim the tier (in base units of the campaign asset)
+    pub min_pledge: u64,
+    /// Number of backers that can claim the tier
+    pub max_supply: u32,
+    /// Number of backers that claimed the tier
+    pub claimed: u32,
+    /// IPFS hash of the reward description
+    pub metadata_url: String,
+    /// PDA bump
+    pub bump: u8,
+    /// Layout version the account was last written with
+    pub version: u8,
+}
+
+impl RewardTier {
+    /// Layout version written by this program
+    pub const VERSION: u8 = 1;
+
+    // 8 (discriminator) + 32 (campaign) + 1 (tier_index) + 8 (min_pledge) +
+    // 4 (max_supply) + 4 (claimed) + (4 + 200) (metadata_url) + 1 (bump) +
+    // 1 (version) = 263
+    pub const SIZE: usize = 272; // Rounded up, later fields take the spare bytes
+
+    /// Number of rewards still available
+    pub fn remaining(&self) -> u32 {
+        self.max_supply.saturating_sub(self.claimed)
+    }
+}
+
+impl Versioned for RewardTier {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn current_size(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn upgrade(&mut self, _now: i64) {
+        self.version = Self::VERSION;
+    }
+}
+
+/// Amount raised beyond the funding goal that unlocks more of the creator's
+/// plans, announced as a stretch goal
+#[account]
+pub struct StretchGoal {
+    /// Reference to parent campaign
+    pub campaign: Pubkey,
+    /// Stretch goal index (0-based)
+    pub goal_index: u8,
+    /// Amount raised that unlocks the goal (in base units of the campaign asset)
+    pub threshold: u64,
+    /// IPFS hash of the stretch goal description
+    pub metadata_url: String,
+    /// Timestamp the amount raised first crossed the threshold (0 while locked)
+    pub unlocked_at: i64,
+    /// PDA bump
+    pub bump: u8,
+    /// Layout version the account was last written with
+    pub version: u8,
+}
+
+impl StretchGoal {
+    /// Layout version written by this program
+    pub const VERSION: u8 = 1;
+
+    // 8 (discriminator) + 32 (campaign) + 1 (goal_index) + 8 (threshold) +
+    // (4 + 200) (metadata_url) + 8 (unlocked_at) + 1 (bump) + 1 (version) = 263
+    pub const SIZE: usize = 272; // Rounded up, later fields take the spare bytes
+
+    /// Whether the amount raised has crossed the threshold
+    pub fn is_unlocked(&self) -> bool {
+        self.unlocked_at != 0
+    }
+}
+
+impl Versioned for StretchGoal {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn current_size(&self) -> usize {
+        Self::SIZE
+    }
+
+    fn upgrade(&mut self, _now: i64) {
+        self.version = Self::VERSION;
+    }
 }
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
+
+use crate::constants::{CAMPAIGN_VAULT_SEED, SOL_VAULT_SEED};
+use crate::errors::HopeRiseError;
+use crate::state::{Campaign, FundingAsset};
+
+/// Escrow holding a campaign's funds, either a token account (SPL Token or
+/// Token-2022) or a system-owned PDA holding lamports
+pub enum CampaignVault<'a, 'info> {
+    Token {
+        campaign_key: Pubkey,
+        vault: &'a InterfaceAccount<'info, TokenAccount>,
+        mint: &'a InterfaceAccount<'info, Mint>,
+        token_program: &'a Interface<'info, TokenInterface>,
+        bump: u8,
+    },
+    Sol {
+        campaign_key: Pubkey,
+        vault: &'a SystemAccount<'info>,
+        system_program: &'a Program<'info, System>,
+        bump: u8,
+    },
+}
+
+impl<'a, 'info> CampaignVault<'a, 'info> {
+    /// Picks the vault matching the campaign's asset from an instruction's optional accounts
+    #[allow(clippy::too_many_arguments)]
+    pub fn resolve(
+        campaign: &Account<'info, Campaign>,
+        token_vault: &'a Option<InterfaceAccount<'info, TokenAccount>>,
+        token_vault_bump: Option<u8>,
+        mint: &'a Option<InterfaceAccount<'info, Mint>>,
+        token_program: &'a Option<Interface<'info, TokenInterface>>,
+        sol_vault: &'a Option<SystemAccount<'info>>,
+        sol_vault_bump: Option<u8>,
+        system_program: &'a Program<'info, System>,
+    ) -> Result<Self> {
+        match campaign.asset {
+            FundingAsset::Token => match (token_vault, token_vault_bump, mint, token_program) {
+                (Some(vault), Some(bump), Some(mint), Some(token_program)) => Ok(Self::Token {
+                    campaign_key: campaign.key(),
+                    vault,
+                    mint,
+                    token_program,
+                    bump,
+                }),
+                _ => err!(HopeRiseError::MissingVaultAccount),
+            },
+            FundingAsset::Sol => match (sol_vault, sol_vault_bump) {
+                (Some(vault), Some(bump)) => Ok(Self::Sol {
+                    campaign_key: campaign.key(),
+                    vault,
+                    system_program,
+                    bump,
+                }),
+                _ => err!(HopeRiseError::MissingVaultAccount),
+            },
+        }
+    }
+
+    /// Moves `amount` from the depositor into the vault and returns the amount
+    /// the vault actually received, net of any Token-2022 transfer fee
+    pub fn deposit(
+        &self,
+        depositor: &Signer<'info>,
+        depositor_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<u64> {
+        match self {
+            Self::Token {
+                vault,
+                mint,
+                token_program,
+                ..
+            } => {
+                let from = depositor_token_account
+                    .as_ref()
+                    .ok_or(HopeRiseError::InvalidTokenAccount)?;
+                let cpi_accounts = token_interface::TransferChecked {
+                    from: from.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: vault.to_account_info(),
+                    authority: depositor.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(token_program.to_account_info(), cpi_accounts);
+                token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
+
+                let vault_info = vault.to_account_info();
+                let balance_after =
+                    TokenAccount::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?.amount;
+
+                balance_after
+                    .checked_sub(vault.amount)
+                    .ok_or(HopeRiseError::ArithmeticOverflow.into())
+            }
+            Self::Sol {
+                vault,
+                system_program,
+                ..
+            } => {
+                let cpi_accounts = system_program::Transfer {
+                    from: depositor.to_account_info(),
+                    to: vault.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_ctx, amount)?;
+
+                Ok(amount)
+            }
+        }
+    }
+
+    /// Pays `amount` out of the vault, signing with the vault PDA. Token
+    /// campaigns pay into `recipient_token_account`, SOL campaigns pay the
+    /// `recipient` wallet directly.
+    pub fn pay(
+        &self,
+        recipient: &AccountInfo<'info>,
+        recipient_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        match self {
+            Self::Token {
+                campaign_key,
+                vault,
+                mint,
+                token_program,
+                bump,
+            } => {
+                let to = recipient_token_account
+                    .as_ref()
+                    .ok_or(HopeRiseError::InvalidTokenAccount)?;
+                let seeds = &[CAMPAIGN_VAULT_SEED, campaign_key.as_ref(), &[*bump]];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = token_interface::TransferChecked {
+                    from: vault.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: vault.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)
+            }
+            Self::Sol {
+                campaign_key,
+                vault,
+                system_program,
+                bump,
+            } => {
+                let seeds = &[SOL_VAULT_SEED, campaign_key.as_ref(), &[*bump]];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = system_program::Transfer {
+                    from: vault.to_account_info(),
+                    to: recipient.clone(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    system_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                system_program::transfer(cpi_ctx, amount)
+            }
+        }
+    }
+
+    /// Pays whatever is left in the vault to `recipient` and closes it,
+    /// returning its rent to `recipient` as well. Token-2022 vaults holding
+    /// withheld transfer fees must have them harvested to the mint first.
+    pub fn close(
+        &self,
+        recipient: &AccountInfo<'info>,
+        recipient_token_account: &Option<InterfaceAccount<'info, TokenAccount>>,
+    ) -> Result<()> {
+        match self {
+            Self::Token {
+                campaign_key,
+                vault,
+                token_program,
+                bump,
+                ..
+            } => {
+                if vault.amount > 0 {
+                    self.pay(recipient, recipient_token_account, vault.amount)?;
+                }
+
+                let seeds = &[CAMPAIGN_VAULT_SEED, campaign_key.as_ref(), &[*bump]];
+                let signer_seeds = &[&seeds[..]];
+
+                let cpi_accounts = token_interface::CloseAccount {
+                    account: vault.to_account_info(),
+                    destination: recipient.clone(),
+                    authority: vault.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token_interface::close_account(cpi_ctx)
+            }
+            // Draining every lamport lets the runtime garbage collect the account
+            Self::Sol { vault, .. } => self.pay(recipient, &None, vault.lamports()),
+        }
+    }
+}
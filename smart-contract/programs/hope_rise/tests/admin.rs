@@ -0,0 +1,201 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{constants::MAX_ACCEPTED_MINTS, errors::HopeRiseError};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn initialize_sets_up_counter_and_config() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+
+    let counter: hope_rise::state::CampaignCounter = env.account(&pda::campaign_counter()).await;
+    assert_eq!(counter.count, 0);
+    assert_eq!(counter.authority, admin);
+
+    let config = env.platform_config().await;
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.treasury, admin);
+    assert_eq!(config.fee_bps, 0);
+    assert!(config.accepted_mints.is_empty());
+    assert!(config.pending_admin.is_none());
+    assert!(!config.is_paused);
+}
+
+#[tokio::test]
+async fn platform_config_is_created_by_the_upgrade_authority() {
+    // Deployments from before the config only have their campaign counter
+    let mut env = TestEnv::start().await;
+    let admin = env.admin.pubkey();
+    env.process(&[ix::initialize(&admin)], &[]).await.unwrap();
+    assert!(env.raw_account(&pda::platform_config()).await.is_none());
+
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let instruction = ix::initialize_platform_config(&outsider.pubkey());
+    let result = env.process(&[instruction], &[&outsider]).await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::initialize_platform_config(&admin)], &[])
+        .await
+        .unwrap();
+    let config = env.platform_config().await;
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.treasury, admin);
+}
+
+#[tokio::test]
+async fn admin_manages_accepted_mints() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let mint = env.create_mint(&TOKEN_PROGRAM_ID).await;
+    let mint_2022 = env.create_mint(&TOKEN_2022_PROGRAM_ID).await;
+
+    env.accept_mint(&mint).await;
+    env.accept_mint(&mint_2022).await;
+    assert_eq!(
+        env.platform_config().await.accepted_mints,
+        vec![mint, mint_2022]
+    );
+
+    let result = env
+        .process(&[ix::add_accepted_mint(&admin, &mint)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MintAlreadyAccepted);
+
+    env.process(&[ix::remove_accepted_mint(&admin, &mint)], &[])
+        .await
+        .unwrap();
+    assert_eq!(env.platform_config().await.accepted_mints, vec![mint_2022]);
+
+    let result = env
+        .process(&[ix::remove_accepted_mint(&admin, &mint)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidMint);
+}
+
+#[tokio::test]
+async fn accepted_mints_are_capped() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+
+    for _ in 0..MAX_ACCEPTED_MINTS {
+        let mint = env.create_mint(&TOKEN_PROGRAM_ID).await;
+        env.accept_mint(&mint).await;
+    }
+
+    let mint = env.create_mint(&TOKEN_PROGRAM_ID).await;
+    let result = env
+        .process(&[ix::add_accepted_mint(&admin, &mint)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MaxAcceptedMintsReached);
+}
+
+#[tokio::test]
+async fn config_updates_require_the_admin() {
+    let mut env = TestEnv::new().await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let mint = env.create_mint(&TOKEN_PROGRAM_ID).await;
+    let outsider_key = outsider.pubkey();
+
+    let instructions = [
+        ix::add_accepted_mint(&outsider_key, &mint),
+        ix::remove_accepted_mint(&outsider_key, &mint),
+        ix::set_platform_fee(&outsider_key, 100, &outsider_key),
+        ix::propose_admin(&outsider_key, &outsider_key),
+        ix::set_paused(&outsider_key, true),
+    ];
+    for instruction in instructions {
+        let result = env.process(&[instruction], &[&outsider]).await;
+        assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+    }
+}
+
+#[tokio::test]
+async fn platform_fee_is_bounded() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let treasury = Keypair::new().pubkey();
+
+    env.process(&[ix::set_platform_fee(&admin, 1_000, &treasury)], &[])
+        .await
+        .unwrap();
+    let config = env.platform_config().await;
+    assert_eq!(config.fee_bps, 1_000);
+    assert_eq!(config.treasury, treasury);
+
+    let result = env
+        .process(&[ix::set_platform_fee(&admin, 1_001, &treasury)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidPlatformFee);
+}
+
+#[tokio::test]
+async fn admin_handover_needs_acceptance_by_the_proposed_admin() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let new_admin = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+
+    let result = env
+        .process(&[ix::accept_admin(&new_admin.pubkey())], &[&new_admin])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::propose_admin(&admin, &new_admin.pubkey())], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.platform_config().await.pending_admin,
+        Some(new_admin.pubkey())
+    );
+
+    let result = env
+        .process(&[ix::accept_admin(&outsider.pubkey())], &[&outsider])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::accept_admin(&new_admin.pubkey())], &[&new_admin])
+        .await
+        .unwrap();
+    let config = env.platform_config().await;
+    assert_eq!(config.admin, new_admin.pubkey());
+    assert!(config.pending_admin.is_none());
+
+    // The previous admin lost its rights
+    let result = env.process(&[ix::set_paused(&admin, true)], &[]).await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+}
+
+#[tokio::test]
+async fn pausing_freezes_mutating_instructions() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let backer = env.backer(&campaign, 1_000).await;
+
+    env.process(&[ix::set_paused(&admin, true)], &[])
+        .await
+        .unwrap();
+    assert!(env.platform_config().await.is_paused);
+
+    let result = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .map(|_| ());
+    assert_hope_rise_error(result, HopeRiseError::ProgramPaused);
+    let result = env.fund(&campaign, &backer, 500).await;
+    assert_hope_rise_error(result, HopeRiseError::ProgramPaused);
+    let result = env
+        .process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::ProgramPaused);
+
+    env.process(&[ix::set_paused(&admin, false)], &[])
+        .await
+        .unwrap();
+    env.fund(&campaign, &backer, 500).await.unwrap();
+}
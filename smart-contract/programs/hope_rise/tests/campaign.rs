@@ -0,0 +1,884 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use common::*;
+use hope_rise::{
+    constants::{LAYOUT_RESERVE, SECONDS_PER_DAY},
+    errors::HopeRiseError,
+    state::{Campaign, CampaignStatus, FundingAsset},
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn create_campaign_starts_as_draft() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    env.process(&[ix::set_platform_fee(&admin, 250, &admin)], &[])
+        .await
+        .unwrap();
+
+    let now = env.now().await;
+    let campaign = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+    let second = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+    assert_eq!(second.address, pda::campaign(&creator.pubkey(), 1));
+
+    let state = env.campaign(&campaign.address).await;
+    let Asset::Token { mint, .. } = asset else {
+        unreachable!()
+    };
+    assert_eq!(state.campaign_id, 0);
+    assert_eq!(state.creator, creator.pubkey());
+    assert_eq!(state.status, CampaignStatus::Draft);
+    assert_eq!(state.asset, FundingAsset::Token);
+    assert_eq!(state.mint, mint);
+    assert_eq!(state.fee_bps, 250);
+    assert_eq!(state.funding_goal, campaign_args().funding_goal);
+    assert_eq!(state.deadline, now + 30 * SECONDS_PER_DAY);
+    assert_eq!(state.amount_raised, 0);
+    assert_eq!(state.backer_count, 0);
+}
+
+#[tokio::test]
+async fn create_campaign_validates_arguments() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+
+    let cases: Vec<(CampaignArgs, HopeRiseError)> = vec![
+        (
+            CampaignArgs {
+                title: "t".repeat(81),
+                ..campaign_args()
+            },
+            HopeRiseError::TitleTooLong,
+        ),
+        (
+            CampaignArgs {
+                short_description: "d".repeat(201),
+                ..campaign_args()
+            },
+            HopeRiseError::DescriptionTooLong,
+        ),
+        (
+            CampaignArgs {
+                cover_image_url: "u".repeat(201),
+                ..campaign_args()
+            },
+            HopeRiseError::UrlTooLong,
+        ),
+        (
+            CampaignArgs {
+                story_url: "u".repeat(201),
+                ..campaign_args()
+            },
+            HopeRiseError::UrlTooLong,
+        ),
+        (
+            CampaignArgs {
+                funding_goal: 0,
+                ..campaign_args()
+            },
+            HopeRiseError::InvalidFundingGoal,
+        ),
+        (
+            CampaignArgs {
+                duration_days: 0,
+                ..campaign_args()
+            },
+            HopeRiseError::InvalidDuration,
+        ),
+        (
+            CampaignArgs {
+                duration_days: 91,
+                ..campaign_args()
+            },
+            HopeRiseError::InvalidDuration,
+        ),
+        (
+            CampaignArgs {
+                vote_approval_bps: 4_999,
+                ..campaign_args()
+            },
+            HopeRiseError::InvalidVoteThreshold,
+        ),
+        (
+            CampaignArgs {
+                vote_quorum_bps: 10_001,
+                ..campaign_args()
+            },
+            HopeRiseError::InvalidVoteThreshold,
+        ),
+    ];
+
+    for (args, error) in cases {
+        let result = env.create_campaign(&creator, asset, args).await.map(|_| ());
+        assert_hope_rise_error(result, error);
+    }
+}
+
+#[tokio::test]
+async fn create_campaign_requires_an_accepted_mint_or_sol_vault() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let mint = env.create_mint(&TOKEN_PROGRAM_ID).await;
+    let unlisted = Asset::Token {
+        mint,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+
+    let result = env
+        .create_campaign(&creator, unlisted, campaign_args())
+        .await
+        .map(|_| ());
+    assert_hope_rise_error(result, HopeRiseError::InvalidMint);
+
+    let instruction = ix::create_campaign(&creator.pubkey(), 0, Asset::Sol, campaign_args());
+    let result = env
+        .process(&[without_account(instruction, 4)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MissingVaultAccount);
+
+    let listed = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let instruction = ix::create_campaign(&creator.pubkey(), 0, listed, campaign_args());
+    let result = env
+        .process(&[without_account(instruction, 7)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MissingVaultAccount);
+
+    let campaign = env
+        .create_campaign(&creator, Asset::Sol, campaign_args())
+        .await
+        .unwrap();
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+    assert_eq!(
+        env.campaign(&campaign.address).await.asset,
+        FundingAsset::Sol
+    );
+}
+
+#[tokio::test]
+async fn launch_starts_the_funding_period() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+
+    let impostor = CampaignKeys {
+        creator: outsider.pubkey(),
+        ..campaign
+    };
+    let result = env
+        .process(&[ix::launch_campaign(&impostor)], &[&outsider])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.warp_forward(5 * SECONDS_PER_DAY).await;
+    let now = env.now().await;
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Active);
+    assert_eq!(state.deadline, now + 30 * SECONDS_PER_DAY);
+
+    let result = env
+        .process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::IllegalStatusTransition);
+}
+
+#[tokio::test]
+async fn fund_campaign_records_contributions() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, 10_000).await;
+    let bob = env.backer(&campaign, 10_000).await;
+
+    let result = env.fund(&campaign, &alice, 1_000).await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotActive);
+
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let result = env.fund(&campaign, &alice, 0).await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidContributionAmount);
+
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.fund(&campaign, &alice, 2_000).await.unwrap();
+    env.fund(&campaign, &bob, 4_000).await.unwrap();
+
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 7_000);
+    assert_eq!(state.backer_count, 2);
+    let contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert_eq!(contribution.amount, 3_000);
+    assert_eq!(contribution.contributor, alice.pubkey());
+    assert!(!contribution.refund_claimed);
+    assert_eq!(env.vault_balance(&campaign).await, 7_000);
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 7_000);
+}
+
+#[tokio::test]
+async fn fund_campaign_rejects_foreign_accounts() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let other_asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+    let bob = env.backer(&campaign, 1_000).await;
+
+    // Bob's token account, spent by Alice
+    let instruction = ix::fund_campaign(&campaign, &alice.pubkey(), 500, None, 0..0);
+    let bob_funding = ix::fund_campaign(&campaign, &bob.pubkey(), 500, None, 0..0);
+    let mut stolen = instruction.clone();
+    stolen.accounts[6] = bob_funding.accounts[6].clone();
+    let result = env.process(&[stolen], &[&alice]).await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidTokenAccount);
+
+    // A token account and mint the campaign does not raise in
+    let Asset::Token {
+        mint: other_mint, ..
+    } = other_asset
+    else {
+        unreachable!()
+    };
+    let other_account = env
+        .fund_token_account(&other_mint, &TOKEN_PROGRAM_ID, &alice.pubkey(), 500)
+        .await;
+    let mut wrong_account = instruction.clone();
+    wrong_account.accounts[6].pubkey = other_account;
+    let result = env.process(&[wrong_account], &[&alice]).await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidTokenAccount);
+
+    // The vault exists since creation, so its mint constraint trips first
+    let mut wrong_mint = instruction.clone();
+    wrong_mint.accounts[7].pubkey = other_mint;
+    let result = env.process(&[wrong_mint], &[&alice]).await;
+    assert_error(result, ErrorCode::ConstraintTokenMint);
+
+    let result = env
+        .process(&[without_account(instruction, 3)], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MissingVaultAccount);
+}
+
+#[tokio::test]
+async fn fund_campaign_closes_at_the_deadline() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+
+    env.warp_forward(30 * SECONDS_PER_DAY - 10).await;
+    env.fund(&campaign, &alice, 500).await.unwrap();
+
+    env.warp_forward(10).await;
+    let result = env.fund(&campaign, &alice, 500).await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignEnded);
+}
+
+#[tokio::test]
+async fn fund_campaign_counts_tokens_net_of_transfer_fees() {
+    let mut env = TestEnv::new().await;
+    // 1% transfer fee, uncapped for these amounts
+    let mint = env.create_transfer_fee_mint(100, u64::MAX).await;
+    env.accept_mint(&mint).await;
+    let asset = Asset::Token {
+        mint,
+        token_program: TOKEN_2022_PROGRAM_ID,
+    };
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 20_000).await;
+
+    env.fund(&campaign, &alice, 10_000).await.unwrap();
+    assert_eq!(env.campaign(&campaign.address).await.amount_raised, 9_900);
+    assert_eq!(
+        env.contribution(&campaign.address, &alice.pubkey())
+            .await
+            .amount,
+        9_900
+    );
+    assert_eq!(env.vault_balance(&campaign).await, 9_900);
+
+    // The whole contribution is withheld as fee
+    let result = env.fund(&campaign, &alice, 1).await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidContributionAmount);
+}
+
+#[tokio::test]
+async fn fund_campaign_in_sol() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .active_campaign(&creator, Asset::Sol, LAMPORTS_PER_SOL)
+        .await;
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+
+    let before = env.lamports(&alice.pubkey()).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL / 2)
+        .await
+        .unwrap();
+    // The backer pays rent for the contribution and its receipt
+    let rent = before - LAMPORTS_PER_SOL / 2 - env.lamports(&alice.pubkey()).await;
+    let contribution = pda::contribution(&campaign.address, &alice.pubkey());
+    let receipt_rent = env.lamports(&pda::receipt_mint(&contribution)).await
+        + env
+            .lamports(&ix::receipt_token_account(
+                &campaign.address,
+                &alice.pubkey(),
+            ))
+            .await;
+    assert_eq!(rent, env.lamports(&contribution).await + receipt_rent);
+    assert_eq!(env.vault_balance(&campaign).await, LAMPORTS_PER_SOL / 2);
+    assert_eq!(
+        env.campaign(&campaign.address).await.amount_raised,
+        LAMPORTS_PER_SOL / 2
+    );
+
+    let instruction = ix::fund_campaign(&campaign, &alice.pubkey(), 1, None, 0..0);
+    let result = env
+        .process(&[without_account(instruction, 4)], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MissingVaultAccount);
+}
+
+#[tokio::test]
+async fn fund_campaign_rejects_overflowing_totals() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+
+    // Token supply can't exceed u64::MAX, so the total is staged directly
+    let mut state = env.campaign(&campaign.address).await;
+    state.amount_raised = u64::MAX - 1;
+    env.set_account(&campaign.address, &state).await;
+
+    let result = env.fund(&campaign, &alice, 2).await;
+    assert_hope_rise_error(result, HopeRiseError::ArithmeticOverflow);
+}
+
+#[tokio::test]
+async fn finalize_before_the_deadline_needs_early_close_and_the_goal() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+
+    let locked = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&locked, 2_000).await;
+    env.fund(&locked, &alice, 1_000).await.unwrap();
+    let result = env
+        .process(&[ix::finalize_campaign(&locked.address, 0..0, 0..0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotEnded);
+
+    let args = CampaignArgs {
+        funding_goal: 1_000,
+        allow_early_close: true,
+        ..campaign_args()
+    };
+    let early = env.create_campaign(&creator, asset, args).await.unwrap();
+    env.process(&[ix::launch_campaign(&early)], &[&creator])
+        .await
+        .unwrap();
+    env.fund(&early, &alice, 500).await.unwrap();
+    let result = env
+        .process(&[ix::finalize_campaign(&early.address, 0..0, 0..0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::GoalNotMet);
+
+    env.fund(&early, &alice, 500).await.unwrap();
+    env.process(&[ix::finalize_campaign(&early.address, 0..0, 0..0)], &[])
+        .await
+        .unwrap();
+    let state = env.campaign(&early.address).await;
+    assert_eq!(state.status, CampaignStatus::Succeeded);
+    assert_eq!(state.finalized_at, env.now().await);
+
+    let result = env
+        .process(&[ix::finalize_campaign(&early.address, 0..0, 0..0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotActive);
+}
+
+#[tokio::test]
+async fn finalize_after_the_deadline_settles_the_outcome() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let funded = env.active_campaign(&creator, asset, 1_000).await;
+    let short = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&funded, 2_000).await;
+    env.fund(&funded, &alice, 1_000).await.unwrap();
+    env.fund(&short, &alice, 999).await.unwrap();
+
+    env.finalize_after_deadline(&funded).await;
+    env.process(&[ix::finalize_campaign(&short.address, 0..0, 0..0)], &[])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        env.campaign(&funded.address).await.status,
+        CampaignStatus::Succeeded
+    );
+    assert_eq!(
+        env.campaign(&short.address).await.status,
+        CampaignStatus::Failed
+    );
+}
+
+#[tokio::test]
+async fn withdraw_pays_the_creator_and_the_platform_fee() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let treasury = Keypair::new().pubkey();
+    env.process(&[ix::set_platform_fee(&admin, 500, &treasury)], &[])
+        .await
+        .unwrap();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 10_000).await;
+    env.fund(&campaign, &alice, 10_000).await.unwrap();
+
+    let result = env
+        .process(&[ix::withdraw_funds(&campaign, &treasury)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::WithdrawalNotAllowed);
+
+    // Fee changes don't apply to existing campaigns
+    env.process(&[ix::set_platform_fee(&admin, 0, &treasury)], &[])
+        .await
+        .unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let result = env
+        .process(
+            &[ix::withdraw_funds(&campaign, &outsider.pubkey())],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::withdraw_funds(&campaign, &treasury)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &creator.pubkey()).await, 9_500);
+    assert_eq!(env.asset_balance(&campaign, &treasury).await, 500);
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.released_amount, 10_000);
+    assert_eq!(state.status, CampaignStatus::Completed);
+
+    let result = env
+        .process(&[ix::withdraw_funds(&campaign, &treasury)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::WithdrawalNotAllowed);
+}
+
+#[tokio::test]
+async fn withdraw_in_sol() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .active_campaign(&creator, Asset::Sol, LAMPORTS_PER_SOL)
+        .await;
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    let before = env.lamports(&creator.pubkey()).await;
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.lamports(&creator.pubkey()).await,
+        before + LAMPORTS_PER_SOL
+    );
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+}
+
+#[tokio::test]
+async fn withdraw_needs_an_unlocked_share() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .create_campaign(
+            &creator,
+            asset,
+            CampaignArgs {
+                funding_goal: 1_000,
+                ..campaign_args()
+            },
+        )
+        .await
+        .unwrap();
+    env.process(
+        &[ix::add_milestone(
+            &campaign,
+            0,
+            "Everything",
+            0,
+            10_000,
+            None,
+        )],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    let result = env
+        .process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InsufficientFunds);
+}
+
+#[tokio::test]
+async fn campaign_accounts_are_sized_to_their_metadata() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = campaign_args();
+    let campaign = env
+        .create_campaign(&creator, asset, args.clone())
+        .await
+        .unwrap();
+
+    let rent = env.ctx.banks_client.get_rent().await.unwrap();
+    let account = env.raw_account(&campaign.address).await.unwrap();
+    let space = Campaign::space(
+        &args.title,
+        &args.short_description,
+        &args.cover_image_url,
+        &args.story_url,
+    );
+    assert_eq!(account.data.len(), space);
+    assert_eq!(account.lamports, rent.minimum_balance(space));
+    assert_eq!(
+        env.campaign(&campaign.address).await.version,
+        Campaign::VERSION
+    );
+
+    // Growing the metadata charges the creator the extra rent
+    let story_url = format!("ipfs://{}", "s".repeat(150));
+    let before = env.lamports(&creator.pubkey()).await;
+    let instruction =
+        ix::update_campaign_metadata(&campaign, "Wells", "Two wells", "ipfs://c", &story_url);
+    env.process(&[instruction], &[&creator]).await.unwrap();
+    let grown = Campaign::space("Wells", "Two wells", "ipfs://c", &story_url);
+    assert_eq!(
+        env.raw_account(&campaign.address).await.unwrap().data.len(),
+        grown
+    );
+    assert_eq!(
+        env.lamports(&creator.pubkey()).await,
+        before - (rent.minimum_balance(grown) - rent.minimum_balance(space))
+    );
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.title, "Wells");
+    assert_eq!(state.short_description, "Two wells");
+    assert_eq!(state.cover_image_url, "ipfs://c");
+    assert_eq!(state.story_url, story_url);
+
+    // Shrinking it refunds the difference
+    let instruction = ix::update_campaign_metadata(&campaign, "W", "", "", "");
+    env.process(&[instruction], &[&creator]).await.unwrap();
+    let shrunk = Campaign::space("W", "", "", "");
+    let data = env.raw_account(&campaign.address).await.unwrap().data;
+    assert_eq!(data.len(), shrunk);
+    // Nothing stale is left where later layouts append their fields
+    assert!(data[shrunk - LAYOUT_RESERVE..]
+        .iter()
+        .all(|byte| *byte == 0));
+    assert_eq!(
+        env.lamports(&creator.pubkey()).await,
+        before + (rent.minimum_balance(space) - rent.minimum_balance(shrunk))
+    );
+
+    let instruction = ix::update_campaign_metadata(&campaign, &"t".repeat(81), "", "", "");
+    let result = env.process(&[instruction], &[&creator]).await;
+    assert_hope_rise_error(result, HopeRiseError::TitleTooLong);
+
+    let impostor = CampaignKeys {
+        creator: outsider.pubkey(),
+        ..campaign
+    };
+    let instruction = ix::update_campaign_metadata(&impostor, "Mine", "", "", "");
+    let result = env.process(&[instruction], &[&outsider]).await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let instruction = ix::update_campaign_metadata(&campaign, "Late", "", "", "");
+    let result = env.process(&[instruction], &[&creator]).await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidCampaignStatus);
+}
+
+#[tokio::test]
+async fn close_campaign_cancels_it() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+
+    let impostor = CampaignKeys {
+        creator: outsider.pubkey(),
+        ..campaign
+    };
+    let result = env
+        .process(&[ix::close_campaign(&impostor)], &[&outsider])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.status,
+        CampaignStatus::Cancelled
+    );
+
+    let result = env
+        .process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::IllegalStatusTransition);
+
+    // A campaign that met its goal can no longer be called off, neither
+    // during funding nor after succeeding
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    let result = env
+        .process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::GoalWasMet);
+    env.finalize_after_deadline(&campaign).await;
+    let result = env
+        .process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::IllegalStatusTransition);
+
+    // Nor can one that missed its goal once the deadline passed: it fails
+    // through finalizing instead
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let bob = env.backer(&campaign, 500).await;
+    env.fund(&campaign, &bob, 500).await.unwrap();
+    let deadline = env.campaign(&campaign.address).await.deadline;
+    let now = env.now().await;
+    env.warp_forward(deadline - now).await;
+    let result = env
+        .process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignEnded);
+    env.process(&[ix::finalize_campaign(&campaign.address, 0..0, 0..0)], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.status,
+        CampaignStatus::Failed
+    );
+}
+
+#[tokio::test]
+async fn admin_moderates_campaigns() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+
+    let result = env
+        .process(
+            &[ix::suspend_campaign(
+                &campaign.address,
+                &outsider.pubkey(),
+                1,
+            )],
+            &[&outsider],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::suspend_campaign(&campaign.address, &admin, 7)], &[])
+        .await
+        .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Suspended);
+    assert_eq!(state.moderation_reason, 7);
+
+    let result = env.fund(&campaign, &alice, 1).await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignSuspended);
+    env.warp_forward(31 * SECONDS_PER_DAY).await;
+    let result = env
+        .process(&[ix::finalize_campaign(&campaign.address, 0..0, 0..0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignSuspended);
+
+    let result = env
+        .process(
+            &[ix::force_fail_campaign(
+                &campaign.address,
+                &outsider.pubkey(),
+                1,
+            )],
+            &[&outsider],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(
+        &[ix::force_fail_campaign(&campaign.address, &admin, 9)],
+        &[],
+    )
+    .await
+    .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Failed);
+    assert_eq!(state.moderation_reason, 9);
+}
+
+#[tokio::test]
+async fn suspension_freezes_payouts() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    env.process(&[ix::suspend_campaign(&campaign.address, &admin, 1)], &[])
+        .await
+        .unwrap();
+    let result = env
+        .process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignSuspended);
+}
+
+#[tokio::test]
+async fn unsuspending_restores_the_previous_status() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+
+    let result = env
+        .process(&[ix::unsuspend_campaign(&campaign.address, &admin, 1)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotSuspended);
+
+    env.process(&[ix::suspend_campaign(&campaign.address, &admin, 1)], &[])
+        .await
+        .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.suspended_from, Some(CampaignStatus::Active));
+    let result = env
+        .process(
+            &[ix::unsuspend_campaign(
+                &campaign.address,
+                &outsider.pubkey(),
+                0,
+            )],
+            &[&outsider],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(&[ix::unsuspend_campaign(&campaign.address, &admin, 0)], &[])
+        .await
+        .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Active);
+    assert_eq!(state.suspended_from, None);
+    assert_eq!(state.moderation_reason, 0);
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    env.process(&[ix::suspend_campaign(&campaign.address, &admin, 2)], &[])
+        .await
+        .unwrap();
+    env.process(&[ix::unsuspend_campaign(&campaign.address, &admin, 0)], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.status,
+        CampaignStatus::Succeeded
+    );
+
+    // Campaigns suspended before the status was stored get it back from
+    // whether funding had been finalized
+    env.process(&[ix::suspend_campaign(&campaign.address, &admin, 3)], &[])
+        .await
+        .unwrap();
+    let legacy = Campaign {
+        version: 6,
+        suspended_from: None,
+        ..env.campaign(&campaign.address).await
+    };
+    env.set_account(&campaign.address, &legacy).await;
+    let result = env
+        .process(&[ix::unsuspend_campaign(&campaign.address, &admin, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::AccountNotMigrated);
+    env.process(&[ix::migrate_account(&campaign.address, &admin)], &[])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.suspended_from,
+        Some(CampaignStatus::Succeeded)
+    );
+    env.process(&[ix::unsuspend_campaign(&campaign.address, &admin, 0)], &[])
+        .await
+        .unwrap();
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.status,
+        CampaignStatus::Completed
+    );
+}
@@ -0,0 +1,306 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{constants::MILESTONE_VOTING_PERIOD, errors::HopeRiseError};
+use solana_sdk::signature::Signer;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+#[tokio::test]
+async fn refunded_contributions_return_rent_to_their_backers() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 3_000).await;
+    let bob = env.backer(&campaign, 2_000).await;
+    env.fund(&campaign, &alice, 3_000).await.unwrap();
+    env.fund(&campaign, &bob, 2_000).await.unwrap();
+    assert_eq!(env.campaign(&campaign.address).await.open_contributions, 2);
+
+    let result = env
+        .process(
+            &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+            &[],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::ContributionNotSettled);
+
+    env.finalize_after_deadline(&campaign).await;
+    let result = env
+        .process(
+            &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+            &[],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::ContributionNotSettled);
+
+    env.process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    let contribution = pda::contribution(&campaign.address, &alice.pubkey());
+    let rent = env.lamports(&contribution).await;
+    let before = env.lamports(&alice.pubkey()).await;
+    // Anyone may close it, the rent still goes to the backer
+    env.process(
+        &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(env.raw_account(&contribution).await.is_none());
+    assert_eq!(env.lamports(&alice.pubkey()).await, before + rent);
+    assert_eq!(env.campaign(&campaign.address).await.open_contributions, 1);
+
+    let result = env
+        .process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignAccountsStillOpen);
+
+    env.process(&[ix::claim_refund(&campaign, &bob.pubkey())], &[&bob])
+        .await
+        .unwrap();
+    env.process(
+        &[ix::close_contribution(&campaign.address, &bob.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    let vault = pda::campaign_vault(&campaign.address);
+    let rent = env.lamports(&campaign.address).await + env.lamports(&vault).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    env.process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&campaign.address).await.is_none());
+    assert!(env.raw_account(&vault).await.is_none());
+    // Less the token account opened for the creator to receive any leftovers
+    let Asset::Token { mint, .. } = asset else {
+        unreachable!()
+    };
+    let creator_ata =
+        get_associated_token_address_with_program_id(&creator.pubkey(), &mint, &TOKEN_PROGRAM_ID);
+    let ata_rent = env.lamports(&creator_ata).await;
+    assert_eq!(
+        env.lamports(&creator.pubkey()).await,
+        before + rent - ata_rent
+    );
+}
+
+#[tokio::test]
+async fn completed_campaign_closes_every_account() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: LAMPORTS_PER_SOL,
+        ..campaign_args()
+    };
+    let campaign = env
+        .create_campaign(&creator, Asset::Sol, args)
+        .await
+        .unwrap();
+    env.process(
+        &[ix::add_milestone(&campaign, 0, "Ship", 0, 5_000, None)],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+
+    // Backers still vote with their contribution while milestones are pending
+    let result = env
+        .process(
+            &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+            &[],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::ContributionNotSettled);
+    let result = env.process(&[ix::close_milestone(&campaign, 0)], &[]).await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotSettled);
+
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &alice.pubkey(),
+            true,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    env.warp_forward(MILESTONE_VOTING_PERIOD + 1).await;
+    env.process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+    env.process(&[ix::release_milestone(&campaign, 0, &admin)], &[&creator])
+        .await
+        .unwrap();
+
+    let result = env
+        .process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignAccountsStillOpen);
+
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let impostor = CampaignKeys {
+        creator: outsider.pubkey(),
+        ..campaign
+    };
+    let result = env.process(&[ix::close_milestone(&impostor, 0)], &[]).await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    let milestone = pda::milestone(&campaign.address, 0);
+    let rent = env.lamports(&milestone).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    env.process(&[ix::close_milestone(&campaign, 0)], &[])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&milestone).await.is_none());
+    assert_eq!(env.lamports(&creator.pubkey()).await, before + rent);
+
+    // Votes outlive their milestone until the voter gets the rent back
+    let vote = pda::milestone_vote(&milestone, &alice.pubkey());
+    let rent = env.lamports(&vote).await;
+    let before = env.lamports(&alice.pubkey()).await;
+    env.process(
+        &[ix::close_milestone_vote(
+            &campaign.address,
+            0,
+            &alice.pubkey(),
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(env.raw_account(&vote).await.is_none());
+    assert_eq!(env.lamports(&alice.pubkey()).await, before + rent);
+
+    env.process(
+        &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.open_contributions, 0);
+    assert_eq!(state.open_milestones, 0);
+
+    let sol_vault = pda::sol_vault(&campaign.address);
+    let rent = env.lamports(&campaign.address).await + env.lamports(&sol_vault).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    env.process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&campaign.address).await.is_none());
+    assert!(env.raw_account(&sol_vault).await.is_none());
+    assert_eq!(env.lamports(&creator.pubkey()).await, before + rent);
+}
+
+#[tokio::test]
+async fn closing_sweeps_refund_dust_to_the_creator() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_2022_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 3_000,
+        ..campaign_args()
+    };
+    let campaign = env.create_campaign(&creator, asset, args).await.unwrap();
+    env.process(
+        &[ix::add_milestone(&campaign, 0, "Ship", 0, 5_000, None)],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, 1_001).await;
+    let bob = env.backer(&campaign, 2_000).await;
+    env.fund(&campaign, &alice, 1_001).await.unwrap();
+    env.fund(&campaign, &bob, 2_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+    env.process(
+        &[ix::force_fail_campaign(&campaign.address, &admin, 1)],
+        &[],
+    )
+    .await
+    .unwrap();
+
+    for backer in [&alice, &bob] {
+        env.process(&[ix::claim_refund(&campaign, &backer.pubkey())], &[backer])
+            .await
+            .unwrap();
+        env.process(
+            &[ix::close_contribution(&campaign.address, &backer.pubkey())],
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+    env.process(&[ix::close_milestone(&campaign, 0)], &[])
+        .await
+        .unwrap();
+    // 3_001 raised, 1_500 withdrawn, refunds of 500 and 1_000 round down
+    assert_eq!(env.vault_balance(&campaign).await, 1);
+
+    env.process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &creator.pubkey()).await, 1_501);
+    assert!(env
+        .raw_account(&pda::campaign_vault(&campaign.address))
+        .await
+        .is_none());
+}
+
+#[tokio::test]
+async fn cancelled_draft_closes_the_vault_paid_at_creation() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    let campaign = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+    let vault = pda::campaign_vault(&campaign.address);
+    assert!(env.raw_account(&vault).await.is_some());
+
+    let result = env
+        .process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotSettled);
+
+    env.process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    // Nothing was left to sweep, so the creator needs no token account
+    let instruction = without_account(ix::close_campaign_accounts(&campaign), 5);
+    env.process(&[instruction], &[&creator]).await.unwrap();
+    assert!(env.raw_account(&campaign.address).await.is_none());
+    assert!(env.raw_account(&vault).await.is_none());
+    assert_eq!(env.lamports(&creator.pubkey()).await, before);
+}
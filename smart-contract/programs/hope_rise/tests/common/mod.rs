@@ -0,0 +1,567 @@
+//! In-process test harness for the hope_rise program.
+//!
+//! Runs the program inside `solana-program-test` next to the bundled SPL
+//! Token, Token-2022 and Associated Token programs, so the suite needs no
+//! validator, airdrops or real mints. Run it with `cargo test-sbf`, which
+//! builds the `hope_rise.so` the suite deploys.
+//! Instructions are built with `hope_rise-client`, so the suite exercises
+//! the same PDAs and account lists integrations send.
+
+#![allow(dead_code)]
+
+use std::collections::HashSet;
+
+use anchor_lang::{
+    prelude::{Clock, Pubkey},
+    solana_program::{instruction::Instruction, program_pack::Pack, system_instruction},
+    AccountDeserialize, AccountSerialize, Event,
+};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use hope_rise::{
+    constants::LEGACY_USDC_MINT,
+    errors::HopeRiseError,
+    state::{Campaign, Category, Contribution, Milestone, PlatformConfig, RewardTier, StretchGoal},
+};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable::UpgradeableLoaderState,
+    instruction::InstructionError,
+    signature::{Keypair, Signature, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::{transfer_fee, ExtensionType};
+
+pub use anchor_spl::{token::ID as TOKEN_PROGRAM_ID, token_2022::ID as TOKEN_2022_PROGRAM_ID};
+pub use hope_rise_client::{instructions as ix, pda, Asset, CampaignArgs, CampaignKeys};
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+pub const DECIMALS: u8 = 6;
+
+/// Arguments to `create_campaign` for a valid 30 day campaign
+pub fn campaign_args() -> CampaignArgs {
+    CampaignArgs {
+        title: "Clean water for Kisumu".to_string(),
+        short_description: "Drilling two boreholes for the local school".to_string(),
+        category: Category::Community,
+        cover_image_url: "ipfs://cover".to_string(),
+        story_url: "ipfs://story".to_string(),
+        funding_goal: 1_000_000,
+        duration_days: 30,
+        vote_quorum_bps: 0,
+        vote_approval_bps: 5_000,
+        allow_early_close: false,
+        transferable_receipts: false,
+        unpledge_cutoff_hours: 48,
+    }
+}
+
+pub struct TestEnv {
+    pub ctx: ProgramTestContext,
+    /// Platform admin and treasury, also the fee payer of every transaction
+    pub admin: Keypair,
+    sent: HashSet<Signature>,
+}
+
+impl TestEnv {
+    /// Starts the SVM and initializes the platform with the payer as admin
+    pub async fn new() -> Self {
+        let mut env = Self::start().await;
+        let admin = env.admin.pubkey();
+        env.process(
+            &[
+                ix::initialize(&admin),
+                ix::initialize_platform_config(&admin),
+            ],
+            &[],
+        )
+        .await
+        .unwrap();
+        env
+    }
+
+    /// Starts the SVM with the program deployed and upgradeable by the
+    /// payer, before anything is initialized
+    pub async fn start() -> Self {
+        let mut program_test = ProgramTest::default();
+        program_test.add_upgradeable_program_to_genesis("hope_rise", &hope_rise::ID);
+        let mut ctx = program_test.start_with_context().await;
+        let admin = ctx.payer.insecure_clone();
+
+        // Genesis deploys the program with the default upgrade authority
+        let program_data = pda::program_data();
+        let mut account = ctx
+            .banks_client
+            .get_account(program_data)
+            .await
+            .unwrap()
+            .expect("program data not found");
+        let metadata = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(admin.pubkey()),
+        })
+        .unwrap();
+        account.data[..metadata.len()].copy_from_slice(&metadata);
+        ctx.set_account(&program_data, &account.into());
+
+        Self {
+            ctx,
+            admin,
+            sent: HashSet::new(),
+        }
+    }
+
+    /// Sends a transaction paid by the admin, signed by `signers` as well
+    async fn transaction(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Transaction {
+        let mut all_signers = vec![&self.admin];
+        all_signers.extend(
+            signers
+                .iter()
+                .copied()
+                .filter(|s| s.pubkey() != self.admin.pubkey()),
+        );
+
+        let mut tx = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.admin.pubkey()),
+            &all_signers,
+            self.ctx.last_blockhash,
+        );
+        // Resending an identical transaction needs a fresh blockhash, or the
+        // bank rejects it as already processed
+        if self.sent.contains(&tx.signatures[0]) {
+            self.ctx.last_blockhash = self.ctx.get_new_latest_blockhash().await.unwrap();
+            tx.sign(&all_signers, self.ctx.last_blockhash);
+        }
+        self.sent.insert(tx.signatures[0]);
+        tx
+    }
+
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        let tx = self.transaction(instructions, signers).await;
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    /// Like `process`, returning the transaction's log messages on success
+    pub async fn process_with_logs(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Vec<String>, BanksClientError> {
+        let tx = self.transaction(instructions, signers).await;
+        let outcome = self
+            .ctx
+            .banks_client
+            .process_transaction_with_metadata(tx)
+            .await?;
+        outcome.result?;
+        Ok(outcome
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default())
+    }
+
+    pub async fn funded_keypair(&mut self, lamports: u64) -> Keypair {
+        let keypair = Keypair::new();
+        let transfer =
+            system_instruction::transfer(&self.admin.pubkey(), &keypair.pubkey(), lamports);
+        self.process(&[transfer], &[]).await.unwrap();
+        keypair
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        self.clock().await.unix_timestamp
+    }
+
+    async fn clock(&mut self) -> Clock {
+        self.ctx.banks_client.get_sysvar::<Clock>().await.unwrap()
+    }
+
+    /// Moves the cluster clock `seconds` forward
+    pub async fn warp_forward(&mut self, seconds: i64) {
+        let mut clock = self.clock().await;
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    pub async fn raw_account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.ctx.banks_client.get_account(*address).await.unwrap()
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.raw_account(address)
+            .await
+            .map_or(0, |account| account.lamports)
+    }
+
+    pub async fn account<T: AccountDeserialize>(&mut self, address: &Pubkey) -> T {
+        let account = self.raw_account(address).await.expect("account not found");
+        T::try_deserialize(&mut account.data.as_slice()).unwrap()
+    }
+
+    /// Overwrites a program account, for states no instruction sequence reaches
+    pub async fn set_account<T: AccountSerialize>(&mut self, address: &Pubkey, state: &T) {
+        let mut account = self.raw_account(address).await.expect("account not found");
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        account.data[..data.len()].copy_from_slice(&data);
+        self.ctx.set_account(address, &account.into());
+    }
+
+    /// Stores `data` as a program account the way an older program wrote
+    /// it, funded for exactly its size
+    pub async fn set_legacy_account(&mut self, address: &Pubkey, data: Vec<u8>) {
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+        let account = Account {
+            lamports: rent.minimum_balance(data.len()),
+            data,
+            owner: hope_rise::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.ctx.set_account(address, &account.into());
+    }
+
+    pub async fn campaign(&mut self, address: &Pubkey) -> Campaign {
+        self.account(address).await
+    }
+
+    pub async fn milestone(&mut self, address: &Pubkey) -> Milestone {
+        self.account(address).await
+    }
+
+    pub async fn contribution(&mut self, campaign: &Pubkey, contributor: &Pubkey) -> Contribution {
+        self.account(&pda::contribution(campaign, contributor))
+            .await
+    }
+
+    pub async fn reward_tier(&mut self, address: &Pubkey) -> RewardTier {
+        self.account(address).await
+    }
+
+    pub async fn stretch_goal(&mut self, address: &Pubkey) -> StretchGoal {
+        self.account(address).await
+    }
+
+    pub async fn platform_config(&mut self) -> PlatformConfig {
+        self.account(&pda::platform_config()).await
+    }
+
+    /// Creates a mint owned by `token_program` with the admin as mint authority
+    pub async fn create_mint(&mut self, token_program: &Pubkey) -> Pubkey {
+        self.create_mint_with_extensions(token_program, &[], |_| Vec::new())
+            .await
+    }
+
+    /// Creates a Token-2022 mint charging a transfer fee of `fee_bps`
+    pub async fn create_transfer_fee_mint(&mut self, fee_bps: u16, maximum_fee: u64) -> Pubkey {
+        self.create_mint_with_extensions(
+            &TOKEN_2022_PROGRAM_ID,
+            &[ExtensionType::TransferFeeConfig],
+            |mint| {
+                vec![transfer_fee::instruction::initialize_transfer_fee_config(
+                    &TOKEN_2022_PROGRAM_ID,
+                    mint,
+                    None,
+                    None,
+                    fee_bps,
+                    maximum_fee,
+                )
+                .unwrap()]
+            },
+        )
+        .await
+    }
+
+    async fn create_mint_with_extensions(
+        &mut self,
+        token_program: &Pubkey,
+        extensions: &[ExtensionType],
+        extension_instructions: impl FnOnce(&Pubkey) -> Vec<Instruction>,
+    ) -> Pubkey {
+        let mint = Keypair::new();
+        let space = if extensions.is_empty() {
+            spl_token_2022::state::Mint::LEN
+        } else {
+            ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(extensions)
+                .unwrap()
+        };
+        let rent = self.ctx.banks_client.get_rent().await.unwrap();
+
+        let mut instructions = vec![system_instruction::create_account(
+            &self.admin.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(space),
+            space as u64,
+            token_program,
+        )];
+        instructions.extend(extension_instructions(&mint.pubkey()));
+        instructions.push(
+            spl_token_2022::instruction::initialize_mint2(
+                token_program,
+                &mint.pubkey(),
+                &self.admin.pubkey(),
+                None,
+                DECIMALS,
+            )
+            .unwrap(),
+        );
+
+        self.process(&instructions, &[&mint]).await.unwrap();
+        mint.pubkey()
+    }
+
+    /// Creates `owner`'s associated token account and mints `amount` into it
+    pub async fn fund_token_account(
+        &mut self,
+        mint: &Pubkey,
+        token_program: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Pubkey {
+        let ata = get_associated_token_address_with_program_id(owner, mint, token_program);
+        let mut instructions = Vec::new();
+        if self.raw_account(&ata).await.is_none() {
+            instructions.push(
+                spl_associated_token_account::instruction::create_associated_token_account(
+                    &self.admin.pubkey(),
+                    owner,
+                    mint,
+                    token_program,
+                ),
+            );
+        }
+        if amount > 0 {
+            instructions.push(
+                spl_token_2022::instruction::mint_to(
+                    token_program,
+                    mint,
+                    &ata,
+                    &self.admin.pubkey(),
+                    &[],
+                    amount,
+                )
+                .unwrap(),
+            );
+        }
+        self.process(&instructions, &[]).await.unwrap();
+        ata
+    }
+
+    pub async fn token_balance(&mut self, token_account: &Pubkey) -> u64 {
+        match self.raw_account(token_account).await {
+            Some(account) => spl_token_2022::extension::StateWithExtensions::<
+                spl_token_2022::state::Account,
+            >::unpack(&account.data)
+            .unwrap()
+            .base
+            .amount,
+            None => 0,
+        }
+    }
+
+    /// Creates a mint owned by `token_program` and whitelists it
+    pub async fn accepted_mint(&mut self, token_program: &Pubkey) -> Asset {
+        let mint = self.create_mint(token_program).await;
+        self.accept_mint(&mint).await;
+        Asset::Token {
+            mint,
+            token_program: *token_program,
+        }
+    }
+
+    /// Places an accepted mint at the devnet USDC address campaigns raised
+    /// funds in before they could choose their asset
+    pub async fn legacy_usdc(&mut self) -> Asset {
+        let mint = self.create_mint(&TOKEN_PROGRAM_ID).await;
+        let account = self.raw_account(&mint).await.unwrap();
+        self.ctx.set_account(&LEGACY_USDC_MINT, &account.into());
+        self.accept_mint(&LEGACY_USDC_MINT).await;
+        Asset::Token {
+            mint: LEGACY_USDC_MINT,
+            token_program: TOKEN_PROGRAM_ID,
+        }
+    }
+
+    pub async fn accept_mint(&mut self, mint: &Pubkey) {
+        let admin = self.admin.pubkey();
+        self.process(&[ix::add_accepted_mint(&admin, mint)], &[])
+            .await
+            .unwrap();
+    }
+
+    /// Creates a Draft campaign and returns its accounts
+    pub async fn create_campaign(
+        &mut self,
+        creator: &Keypair,
+        asset: Asset,
+        args: CampaignArgs,
+    ) -> Result<CampaignKeys, BanksClientError> {
+        let campaign_id = self
+            .account::<hope_rise::state::CampaignCounter>(&pda::campaign_counter())
+            .await
+            .count;
+        let instruction = ix::create_campaign(&creator.pubkey(), campaign_id, asset, args);
+        self.process(&[instruction], &[creator]).await?;
+
+        Ok(CampaignKeys::new(&creator.pubkey(), campaign_id, asset))
+    }
+
+    /// Creates and launches a campaign with default arguments and `funding_goal`
+    pub async fn active_campaign(
+        &mut self,
+        creator: &Keypair,
+        asset: Asset,
+        funding_goal: u64,
+    ) -> CampaignKeys {
+        let args = CampaignArgs {
+            funding_goal,
+            ..campaign_args()
+        };
+        let campaign = self.create_campaign(creator, asset, args).await.unwrap();
+        self.process(&[ix::launch_campaign(&campaign)], &[creator])
+            .await
+            .unwrap();
+        campaign
+    }
+
+    /// Creates a backer holding `balance` of the campaign's asset
+    pub async fn backer(&mut self, campaign: &CampaignKeys, balance: u64) -> Keypair {
+        match campaign.asset {
+            Asset::Token {
+                mint,
+                token_program,
+            } => {
+                let backer = self.funded_keypair(LAMPORTS_PER_SOL).await;
+                self.fund_token_account(&mint, &token_program, &backer.pubkey(), balance)
+                    .await;
+                backer
+            }
+            Asset::Sol => self.funded_keypair(LAMPORTS_PER_SOL + balance).await,
+        }
+    }
+
+    pub async fn fund(
+        &mut self,
+        campaign: &CampaignKeys,
+        contributor: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let instruction = ix::fund_campaign(campaign, &contributor.pubkey(), amount, None, 0..0);
+        self.process(&[instruction], &[contributor]).await
+    }
+
+    /// Funds `campaign`, claiming the reward tier at `tier`
+    pub async fn fund_with_tier(
+        &mut self,
+        campaign: &CampaignKeys,
+        contributor: &Keypair,
+        amount: u64,
+        tier: u8,
+    ) -> Result<(), BanksClientError> {
+        let instruction =
+            ix::fund_campaign(campaign, &contributor.pubkey(), amount, Some(tier), 0..0);
+        self.process(&[instruction], &[contributor]).await
+    }
+
+    /// Funds `campaign`, passing its locked stretch goals so the pledge can
+    /// unlock them
+    pub async fn fund_unlocking(
+        &mut self,
+        campaign: &CampaignKeys,
+        contributor: &Keypair,
+        amount: u64,
+    ) -> Result<(), BanksClientError> {
+        let state = self.campaign(&campaign.address).await;
+        let locked = state.unlocked_stretch_goals..state.stretch_goal_count;
+        let instruction = ix::fund_campaign(campaign, &contributor.pubkey(), amount, None, locked);
+        self.process(&[instruction], &[contributor]).await
+    }
+
+    /// Balance of the campaign's vault, in tokens or lamports above rent
+    pub async fn vault_balance(&mut self, campaign: &CampaignKeys) -> u64 {
+        match campaign.asset {
+            Asset::Token { .. } => {
+                self.token_balance(&pda::campaign_vault(&campaign.address))
+                    .await
+            }
+            Asset::Sol => {
+                let rent = self.ctx.banks_client.get_rent().await.unwrap();
+                self.lamports(&pda::sol_vault(&campaign.address)).await - rent.minimum_balance(0)
+            }
+        }
+    }
+
+    /// Balance of `owner`'s wallet in the campaign's asset
+    pub async fn asset_balance(&mut self, campaign: &CampaignKeys, owner: &Pubkey) -> u64 {
+        match campaign.asset {
+            Asset::Token {
+                mint,
+                token_program,
+            } => {
+                let ata =
+                    get_associated_token_address_with_program_id(owner, &mint, &token_program);
+                self.token_balance(&ata).await
+            }
+            Asset::Sol => self.lamports(owner).await,
+        }
+    }
+
+    /// Runs the campaign past its deadline and finalizes it, passing all of
+    /// its milestones and locked stretch goals
+    pub async fn finalize_after_deadline(&mut self, campaign: &CampaignKeys) {
+        let state = self.campaign(&campaign.address).await;
+        let now = self.now().await;
+        self.warp_forward(state.deadline - now + 1).await;
+        let instruction = ix::finalize_campaign(
+            &campaign.address,
+            0..state.milestone_count,
+            state.unlocked_stretch_goals..state.stretch_goal_count,
+        );
+        self.process(&[instruction], &[]).await.unwrap();
+    }
+}
+
+/// Replaces the optional account at `index` with the program id, which Anchor reads as `None`
+pub fn without_account(mut instruction: Instruction, index: usize) -> Instruction {
+    instruction.accounts[index].pubkey = hope_rise::ID;
+    instruction.accounts[index].is_writable = false;
+    instruction
+}
+
+/// Asserts that a transaction failed on its first instruction with `error`
+pub fn assert_error(result: Result<(), BanksClientError>, error: impl Into<u32>) {
+    let code = error.into();
+    match result {
+        Err(BanksClientError::TransactionError(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(actual),
+        ))) => assert_eq!(actual, code, "expected custom error {code}, got {actual}"),
+        other => panic!("expected custom error {code}, got {other:?}"),
+    }
+}
+
+pub fn assert_hope_rise_error(result: Result<(), BanksClientError>, error: HopeRiseError) {
+    assert_error(result, error);
+}
+
+/// Decodes the `T` events emitted through `emit!` in `logs`, in order
+pub fn events<T: Event>(logs: &[String]) -> Vec<T> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .filter_map(|data| BASE64_STANDARD.decode(data).ok())
+        .filter_map(|data| {
+            let mut payload = data.strip_prefix(T::DISCRIMINATOR)?;
+            T::deserialize(&mut payload).ok()
+        })
+        .collect()
+}
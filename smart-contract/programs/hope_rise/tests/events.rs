@@ -0,0 +1,380 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{
+    constants::MILESTONE_VOTING_PERIOD,
+    events::*,
+    state::{CampaignStatus, FundingAsset},
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+#[tokio::test]
+async fn funding_and_withdrawal_emit_running_totals() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let treasury = Keypair::new().pubkey();
+    env.process(&[ix::set_platform_fee(&admin, 500, &treasury)], &[])
+        .await
+        .unwrap();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let Asset::Token { mint, .. } = asset else {
+        unreachable!()
+    };
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+
+    let args = CampaignArgs {
+        funding_goal: 4_000,
+        ..campaign_args()
+    };
+    let instruction = ix::create_campaign(&creator.pubkey(), 0, asset, args);
+    let logs = env
+        .process_with_logs(&[instruction], &[&creator])
+        .await
+        .unwrap();
+    let [created] = &events::<CampaignCreated>(&logs)[..] else {
+        panic!("expected one CampaignCreated event")
+    };
+    let campaign = CampaignKeys {
+        address: pda::campaign(&creator.pubkey(), 0),
+        creator: creator.pubkey(),
+        asset,
+    };
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(created.campaign, campaign.address);
+    assert_eq!(created.creator, creator.pubkey());
+    assert_eq!(created.campaign_id, 0);
+    assert_eq!(created.asset, FundingAsset::Token);
+    assert_eq!(created.mint, mint);
+    assert_eq!(created.funding_goal, 4_000);
+    assert_eq!(created.deadline, state.deadline);
+    assert_eq!(created.fee_bps, 500);
+    assert_eq!(created.timestamp, state.created_at);
+
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, 3_000).await;
+    let bob = env.backer(&campaign, 1_000).await;
+    let mut feed = Vec::new();
+    for (backer, amount) in [(&alice, 1_000), (&bob, 1_000), (&alice, 2_000)] {
+        let instruction = ix::fund_campaign(&campaign, &backer.pubkey(), amount, None, 0..0);
+        let logs = env
+            .process_with_logs(&[instruction], &[backer])
+            .await
+            .unwrap();
+        feed.extend(events::<ContributionMade>(&logs));
+    }
+    let now = env.now().await;
+    let totals: Vec<_> = feed
+        .iter()
+        .map(|event| {
+            assert_eq!(event.campaign, campaign.address);
+            assert_eq!(event.timestamp, now);
+            (
+                event.contributor,
+                event.amount,
+                event.contribution_total,
+                event.amount_raised,
+                event.backer_count,
+            )
+        })
+        .collect();
+    assert_eq!(
+        totals,
+        vec![
+            (alice.pubkey(), 1_000, 1_000, 1_000, 1),
+            (bob.pubkey(), 1_000, 1_000, 2_000, 2),
+            (alice.pubkey(), 2_000, 3_000, 4_000, 2),
+        ]
+    );
+
+    env.finalize_after_deadline(&campaign).await;
+    let logs = env
+        .process_with_logs(&[ix::withdraw_funds(&campaign, &treasury)], &[&creator])
+        .await
+        .unwrap();
+    let [withdrawn] = &events::<FundsWithdrawn>(&logs)[..] else {
+        panic!("expected one FundsWithdrawn event")
+    };
+    assert_eq!(withdrawn.campaign, campaign.address);
+    assert_eq!(withdrawn.creator, creator.pubkey());
+    assert_eq!(withdrawn.milestone_index, None);
+    assert_eq!(withdrawn.amount, 4_000);
+    assert_eq!(withdrawn.creator_amount, 3_800);
+    assert_eq!(withdrawn.fee, 200);
+    assert_eq!(withdrawn.released_amount, 4_000);
+    assert_eq!(withdrawn.timestamp, env.now().await);
+    let [completed] = &events::<CampaignCompleted>(&logs)[..] else {
+        panic!("expected one CampaignCompleted event")
+    };
+    assert_eq!(completed.campaign, campaign.address);
+    assert_eq!(completed.released_amount, 4_000);
+    assert_eq!(completed.timestamp, withdrawn.timestamp);
+}
+
+#[tokio::test]
+async fn milestones_emit_lifecycle_events() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 2_000,
+        ..campaign_args()
+    };
+    let campaign = env.create_campaign(&creator, asset, args).await.unwrap();
+
+    let mut added = Vec::new();
+    for (index, payout_bps) in [(0, 6_000), (1, 4_000)] {
+        let instruction = ix::add_milestone(&campaign, index, "Step", 1_000, payout_bps, None);
+        let logs = env
+            .process_with_logs(&[instruction], &[&creator])
+            .await
+            .unwrap();
+        added.extend(events::<MilestoneAdded>(&logs));
+    }
+    let added: Vec<_> = added
+        .iter()
+        .map(|event| {
+            assert_eq!(event.campaign, campaign.address);
+            assert_eq!(event.target_amount, 1_000);
+            (
+                event.milestone,
+                event.milestone_index,
+                event.payout_bps,
+                event.allocated_bps,
+            )
+        })
+        .collect();
+    assert_eq!(
+        added,
+        vec![
+            (pda::milestone(&campaign.address, 0), 0, 6_000, 6_000),
+            (pda::milestone(&campaign.address, 1), 1, 4_000, 10_000),
+        ]
+    );
+
+    let logs = env
+        .process_with_logs(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let [launched] = &events::<CampaignLaunched>(&logs)[..] else {
+        panic!("expected one CampaignLaunched event")
+    };
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(launched.campaign, campaign.address);
+    assert_eq!(launched.deadline, state.deadline);
+    assert_eq!(launched.timestamp, env.now().await);
+
+    let alice = env.backer(&campaign, 2_000).await;
+    env.fund(&campaign, &alice, 2_000).await.unwrap();
+    let now = env.now().await;
+    env.warp_forward(state.deadline - now + 1).await;
+    let logs = env
+        .process_with_logs(&[ix::finalize_campaign(&campaign.address, 0..0, 0..0)], &[])
+        .await
+        .unwrap();
+    let [finalized] = &events::<CampaignFinalized>(&logs)[..] else {
+        panic!("expected one CampaignFinalized event")
+    };
+    assert_eq!(finalized.campaign, campaign.address);
+    assert_eq!(finalized.status, CampaignStatus::Succeeded);
+    assert_eq!(finalized.amount_raised, 2_000);
+    assert_eq!(finalized.backer_count, 1);
+    assert_eq!(finalized.timestamp, env.now().await);
+
+    let logs = env
+        .process_with_logs(
+            &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+    let [submitted] = &events::<MilestoneProofSubmitted>(&logs)[..] else {
+        panic!("expected one MilestoneProofSubmitted event")
+    };
+    let now = env.now().await;
+    assert_eq!(submitted.milestone, pda::milestone(&campaign.address, 0));
+    assert_eq!(submitted.milestone_index, 0);
+    assert_eq!(submitted.proof_url, "ipfs://proof");
+    assert_eq!(submitted.voting_ends_at, now + MILESTONE_VOTING_PERIOD);
+    assert_eq!(submitted.timestamp, now);
+
+    let logs = env
+        .process_with_logs(
+            &[ix::vote_milestone(
+                &campaign.address,
+                0,
+                &alice.pubkey(),
+                true,
+            )],
+            &[&alice],
+        )
+        .await
+        .unwrap();
+    let [voted] = &events::<MilestoneVoted>(&logs)[..] else {
+        panic!("expected one MilestoneVoted event")
+    };
+    assert_eq!(voted.campaign, campaign.address);
+    assert_eq!(voted.milestone_index, 0);
+    assert_eq!(voted.voter, alice.pubkey());
+    assert!(voted.approve);
+    assert_eq!(voted.weight, 2_000);
+    assert_eq!((voted.yes_weight, voted.no_weight), (2_000, 0));
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+    let logs = env
+        .process_with_logs(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+    let [completed] = &events::<MilestoneCompleted>(&logs)[..] else {
+        panic!("expected one MilestoneCompleted event")
+    };
+    assert_eq!(completed.campaign, campaign.address);
+    assert_eq!(completed.milestone, pda::milestone(&campaign.address, 0));
+    assert_eq!(completed.milestone_index, 0);
+    assert_eq!(completed.yes_weight, 2_000);
+    assert_eq!(completed.no_weight, 0);
+    assert_eq!(completed.timestamp, env.now().await);
+
+    let logs = env
+        .process_with_logs(&[ix::release_milestone(&campaign, 0, &admin)], &[&creator])
+        .await
+        .unwrap();
+    let [released] = &events::<MilestoneReleased>(&logs)[..] else {
+        panic!("expected one MilestoneReleased event")
+    };
+    assert_eq!(released.milestone, pda::milestone(&campaign.address, 0));
+    assert_eq!(released.milestone_index, 0);
+    assert_eq!(released.released_bps, 6_000);
+    let [payout] = &events::<FundsWithdrawn>(&logs)[..] else {
+        panic!("expected one FundsWithdrawn event")
+    };
+    assert_eq!(payout.milestone_index, Some(0));
+    assert_eq!(payout.amount, 1_200);
+    assert_eq!(payout.creator_amount, 1_200);
+    assert_eq!(payout.fee, 0);
+    assert_eq!(payout.released_amount, 1_200);
+    // The second tranche is still locked
+    assert!(events::<CampaignCompleted>(&logs).is_empty());
+}
+
+#[tokio::test]
+async fn rejection_and_moderation_emit_status_changes() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    env.process(
+        &[ix::add_milestone(&campaign, 0, "Step", 1_000, 5_000, None)],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &alice.pubkey(),
+            false,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+
+    let logs = env
+        .process_with_logs(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+    let [failed] = &events::<CampaignFailed>(&logs)[..] else {
+        panic!("expected one CampaignFailed event")
+    };
+    assert_eq!(failed.campaign, campaign.address);
+    assert_eq!(failed.milestone, pda::milestone(&campaign.address, 0));
+    assert_eq!(failed.milestone_index, 0);
+    assert_eq!((failed.yes_weight, failed.no_weight), (0, 1_000));
+    assert_eq!(failed.timestamp, env.now().await);
+    assert!(events::<MilestoneCompleted>(&logs).is_empty());
+
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let mut moderation = Vec::new();
+    for instruction in [
+        ix::suspend_campaign(&campaign.address, &admin, 3),
+        ix::force_fail_campaign(&campaign.address, &admin, 4),
+    ] {
+        let logs = env.process_with_logs(&[instruction], &[]).await.unwrap();
+        moderation.extend(events::<CampaignModerated>(&logs));
+    }
+    let moderation: Vec<_> = moderation
+        .iter()
+        .map(|event| {
+            assert_eq!(event.campaign, campaign.address);
+            (event.status, event.reason)
+        })
+        .collect();
+    assert_eq!(
+        moderation,
+        vec![(CampaignStatus::Suspended, 3), (CampaignStatus::Failed, 4)]
+    );
+}
+
+#[tokio::test]
+async fn closing_and_refunds_emit_events() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .active_campaign(&creator, Asset::Sol, LAMPORTS_PER_SOL)
+        .await;
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL / 4)
+        .await
+        .unwrap();
+
+    let logs = env
+        .process_with_logs(&[ix::close_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let [closed] = &events::<CampaignClosed>(&logs)[..] else {
+        panic!("expected one CampaignClosed event")
+    };
+    assert_eq!(closed.campaign, campaign.address);
+    assert_eq!(closed.creator, creator.pubkey());
+    assert_eq!(closed.amount_raised, LAMPORTS_PER_SOL / 4);
+    assert_eq!(closed.backer_count, 1);
+    assert_eq!(closed.timestamp, env.now().await);
+    assert_eq!(
+        env.campaign(&campaign.address).await.status,
+        CampaignStatus::Cancelled
+    );
+
+    let logs = env
+        .process_with_logs(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    let [refund] = &events::<RefundClaimed>(&logs)[..] else {
+        panic!("expected one RefundClaimed event")
+    };
+    assert_eq!(refund.campaign, campaign.address);
+    assert_eq!(refund.contributor, alice.pubkey());
+    assert_eq!(refund.amount, LAMPORTS_PER_SOL / 4);
+    assert_eq!(refund.contribution_amount, LAMPORTS_PER_SOL / 4);
+    assert!(!refund.partial);
+    assert_eq!(refund.amount_raised, 0);
+    assert_eq!(refund.backer_count, 0);
+    assert_eq!(refund.total_refunded, LAMPORTS_PER_SOL / 4);
+    assert_eq!(refund.timestamp, env.now().await);
+    assert!(events::<CampaignClosed>(&logs).is_empty());
+}
@@ -0,0 +1,439 @@
+#![cfg(feature = "test-sbf")]
+
+//! Property test driving random sequences of campaign instructions and
+//! checking the vault accounting invariants after every step.
+
+mod common;
+
+use std::collections::HashSet;
+
+use anchor_lang::AccountDeserialize;
+use common::*;
+use hope_rise::state::Contribution;
+use proptest::prelude::*;
+use solana_sdk::signature::{Keypair, Signer};
+
+const CREATORS: usize = 2;
+const BACKERS: usize = 3;
+const BACKER_TOKENS: u64 = 1_000_000;
+
+#[derive(Clone, Debug)]
+enum Action {
+    Create {
+        creator: usize,
+        sol: bool,
+        goal: u64,
+    },
+    Fund {
+        campaign: usize,
+        backer: usize,
+        amount: u64,
+    },
+    Unpledge {
+        campaign: usize,
+        backer: usize,
+        amount: u64,
+    },
+    Close {
+        campaign: usize,
+    },
+    EndFunding,
+    Withdraw {
+        campaign: usize,
+    },
+    Refund {
+        campaign: usize,
+        backer: usize,
+    },
+}
+
+fn create() -> impl Strategy<Value = Action> {
+    (0..CREATORS, any::<bool>(), 1..8_000u64).prop_map(|(creator, sol, goal)| Action::Create {
+        creator,
+        sol,
+        goal,
+    })
+}
+
+fn fund() -> impl Strategy<Value = Action> {
+    (any::<usize>(), 0..BACKERS, 1..5_000u64).prop_map(|(campaign, backer, amount)| Action::Fund {
+        campaign,
+        backer,
+        amount,
+    })
+}
+
+fn unpledge() -> impl Strategy<Value = Action> {
+    (any::<usize>(), 0..BACKERS, 1..5_000u64).prop_map(|(campaign, backer, amount)| {
+        Action::Unpledge {
+            campaign,
+            backer,
+            amount,
+        }
+    })
+}
+
+fn close() -> impl Strategy<Value = Action> {
+    any::<usize>().prop_map(|campaign| Action::Close { campaign })
+}
+
+fn funding() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        1 => create(),
+        8 => fund(),
+        2 => unpledge(),
+        1 => close(),
+    ]
+}
+
+fn settlement() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        1 => fund(),
+        1 => close(),
+        3 => any::<usize>().prop_map(|campaign| Action::Withdraw { campaign }),
+        4 => (any::<usize>(), 0..BACKERS)
+            .prop_map(|(campaign, backer)| Action::Refund { campaign, backer }),
+    ]
+}
+
+/// A few campaigns, a funding phase, then settlement once every deadline
+/// has passed. Withdrawals and refunds are also tried during funding.
+fn scenario() -> impl Strategy<Value = Vec<Action>> {
+    (
+        prop::collection::vec(create(), 1..=3),
+        prop::collection::vec(prop_oneof![9 => funding(), 1 => settlement()], 0..24),
+        prop::collection::vec(settlement(), 1..24),
+    )
+        .prop_map(|(mut actions, funding, settlement)| {
+            actions.extend(funding);
+            actions.push(Action::EndFunding);
+            actions.extend(settlement);
+            actions
+        })
+}
+
+/// What the test observed leaving a campaign's vault
+struct CampaignModel {
+    campaign: CampaignKeys,
+    creator: usize,
+    /// Paid to the creator, net of platform fees
+    creator_received: u64,
+    /// Paid to the platform treasury
+    fees_received: u64,
+    /// Backers refunded so far
+    refunded: HashSet<usize>,
+    /// Pledges of the backers refunded so far
+    refunded_pledges: u64,
+}
+
+struct Harness {
+    env: TestEnv,
+    asset: Asset,
+    treasury: Keypair,
+    creators: Vec<Keypair>,
+    backers: Vec<Keypair>,
+    campaigns: Vec<CampaignModel>,
+}
+
+impl Harness {
+    async fn new() -> Self {
+        let mut env = TestEnv::new().await;
+        let admin = env.admin.pubkey();
+        let treasury = env.funded_keypair(LAMPORTS_PER_SOL).await;
+        env.process(
+            &[ix::set_platform_fee(&admin, 250, &treasury.pubkey())],
+            &[],
+        )
+        .await
+        .unwrap();
+        let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+        let Asset::Token { mint, .. } = asset else {
+            unreachable!()
+        };
+
+        let mut creators = Vec::new();
+        for _ in 0..CREATORS {
+            creators.push(env.funded_keypair(10 * LAMPORTS_PER_SOL).await);
+        }
+        let mut backers = Vec::new();
+        for _ in 0..BACKERS {
+            let backer = env.funded_keypair(10 * LAMPORTS_PER_SOL).await;
+            env.fund_token_account(&mint, &TOKEN_PROGRAM_ID, &backer.pubkey(), BACKER_TOKENS)
+                .await;
+            backers.push(backer);
+        }
+
+        Self {
+            env,
+            asset,
+            treasury,
+            creators,
+            backers,
+            campaigns: Vec::new(),
+        }
+    }
+
+    fn pick(&self, campaign: usize) -> Option<usize> {
+        (!self.campaigns.is_empty()).then(|| campaign % self.campaigns.len())
+    }
+
+    async fn apply(&mut self, action: &Action) {
+        match *action {
+            Action::Create { creator, sol, goal } => {
+                let asset = if sol { Asset::Sol } else { self.asset };
+                let args = CampaignArgs {
+                    funding_goal: goal,
+                    ..campaign_args()
+                };
+                let campaign = self
+                    .env
+                    .create_campaign(&self.creators[creator], asset, args)
+                    .await
+                    .unwrap();
+                self.env
+                    .process(
+                        &[ix::launch_campaign(&campaign)],
+                        &[&self.creators[creator]],
+                    )
+                    .await
+                    .unwrap();
+                self.campaigns.push(CampaignModel {
+                    campaign,
+                    creator,
+                    creator_received: 0,
+                    fees_received: 0,
+                    refunded: HashSet::new(),
+                    refunded_pledges: 0,
+                });
+            }
+            Action::Fund {
+                campaign,
+                backer,
+                amount,
+            } => {
+                let Some(index) = self.pick(campaign) else {
+                    return;
+                };
+                let campaign = self.campaigns[index].campaign;
+                // Rejections (ended, closed) are expected and leave no trace
+                let _ = self
+                    .env
+                    .fund(&campaign, &self.backers[backer], amount)
+                    .await;
+            }
+            Action::Unpledge {
+                campaign,
+                backer,
+                amount,
+            } => {
+                let Some(index) = self.pick(campaign) else {
+                    return;
+                };
+                let campaign = self.campaigns[index].campaign;
+                let pledged = self.pledge(&campaign, backer).await;
+                let contributor = &self.backers[backer];
+
+                // Unpledging everything on a SOL campaign also hands back the
+                // receipt's rent
+                let address = pda::contribution(&campaign.address, &contributor.pubkey());
+                let receipt_rent = if matches!(campaign.asset, Asset::Sol) && pledged == amount {
+                    let receipt =
+                        ix::receipt_token_account(&campaign.address, &contributor.pubkey());
+                    self.env.lamports(&pda::receipt_mint(&address)).await
+                        + self.env.lamports(&receipt).await
+                } else {
+                    0
+                };
+                let before = self
+                    .env
+                    .asset_balance(&campaign, &contributor.pubkey())
+                    .await;
+                let instruction = ix::unpledge(&campaign, &contributor.pubkey(), amount, None);
+                let unpledged = self
+                    .env
+                    .process(&[instruction], &[contributor])
+                    .await
+                    .is_ok();
+                let after = self
+                    .env
+                    .asset_balance(&campaign, &contributor.pubkey())
+                    .await;
+
+                if unpledged {
+                    assert_eq!(
+                        after,
+                        before + amount + receipt_rent,
+                        "unpledge paid the wrong amount"
+                    );
+                } else {
+                    assert_eq!(after, before, "failed unpledge moved funds");
+                }
+            }
+            Action::Close { campaign } => {
+                let Some(index) = self.pick(campaign) else {
+                    return;
+                };
+                let model = &self.campaigns[index];
+                let instruction = ix::close_campaign(&model.campaign);
+                let creator = &self.creators[model.creator];
+                let _ = self.env.process(&[instruction], &[creator]).await;
+            }
+            Action::EndFunding => {
+                let mut deadline = 0;
+                for model in &self.campaigns {
+                    let state = self.env.campaign(&model.campaign.address).await;
+                    deadline = deadline.max(state.deadline);
+                }
+                let now = self.env.now().await;
+                if now <= deadline {
+                    self.env.warp_forward(deadline - now + 1).await;
+                }
+                for index in 0..self.campaigns.len() {
+                    let address = self.campaigns[index].campaign.address;
+                    // Closed campaigns are already final
+                    let _ = self
+                        .env
+                        .process(&[ix::finalize_campaign(&address, 0..0, 0..0)], &[])
+                        .await;
+                }
+            }
+            Action::Withdraw { campaign } => {
+                let Some(index) = self.pick(campaign) else {
+                    return;
+                };
+                let model = &self.campaigns[index];
+                let campaign = model.campaign;
+                let creator = &self.creators[model.creator];
+                let treasury = self.treasury.pubkey();
+
+                let creator_before = self.env.asset_balance(&campaign, &campaign.creator).await;
+                let treasury_before = self.env.asset_balance(&campaign, &treasury).await;
+                let instruction = ix::withdraw_funds(&campaign, &treasury);
+                let _ = self.env.process(&[instruction], &[creator]).await;
+                let creator_after = self.env.asset_balance(&campaign, &campaign.creator).await;
+                let treasury_after = self.env.asset_balance(&campaign, &treasury).await;
+
+                let model = &mut self.campaigns[index];
+                model.creator_received += creator_after - creator_before;
+                model.fees_received += treasury_after - treasury_before;
+            }
+            Action::Refund { campaign, backer } => {
+                let Some(index) = self.pick(campaign) else {
+                    return;
+                };
+                let campaign = self.campaigns[index].campaign;
+                let pledged = self.pledge(&campaign, backer).await;
+                let contributor = &self.backers[backer];
+
+                let before = self
+                    .env
+                    .asset_balance(&campaign, &contributor.pubkey())
+                    .await;
+                let instruction = ix::claim_refund(&campaign, &contributor.pubkey());
+                let refunded = self
+                    .env
+                    .process(&[instruction], &[contributor])
+                    .await
+                    .is_ok();
+                let after = self
+                    .env
+                    .asset_balance(&campaign, &contributor.pubkey())
+                    .await;
+
+                if refunded {
+                    assert!(
+                        self.campaigns[index].refunded.insert(backer),
+                        "backer {backer} was refunded twice"
+                    );
+                    assert!(after > before, "refund paid nothing");
+                    self.campaigns[index].refunded_pledges += pledged;
+                } else {
+                    assert_eq!(after, before, "failed refund moved funds");
+                }
+            }
+        }
+    }
+
+    /// Amount `backer` has pledged to `campaign`
+    async fn pledge(&mut self, campaign: &CampaignKeys, backer: usize) -> u64 {
+        let address = pda::contribution(&campaign.address, &self.backers[backer].pubkey());
+        match self.env.raw_account(&address).await {
+            Some(account) => {
+                Contribution::try_deserialize(&mut account.data.as_slice())
+                    .unwrap()
+                    .amount
+            }
+            None => 0,
+        }
+    }
+
+    async fn contributions(&mut self, campaign: &CampaignKeys) -> Vec<(usize, Contribution)> {
+        let mut contributions = Vec::new();
+        for (index, backer) in self.backers.iter().enumerate() {
+            let address = pda::contribution(&campaign.address, &backer.pubkey());
+            if let Some(account) = self.env.raw_account(&address).await {
+                let contribution =
+                    Contribution::try_deserialize(&mut account.data.as_slice()).unwrap();
+                contributions.push((index, contribution));
+            }
+        }
+        contributions
+    }
+
+    async fn check_invariants(&mut self) {
+        for index in 0..self.campaigns.len() {
+            let campaign = self.campaigns[index].campaign;
+            let state = self.env.campaign(&campaign.address).await;
+            let contributions = self.contributions(&campaign).await;
+
+            // Refunds zero the contribution, so only live pledges count
+            let contributed: u64 = contributions.iter().map(|(_, c)| c.amount).sum();
+            let backers = contributions.iter().filter(|(_, c)| c.amount > 0).count();
+            let model = &self.campaigns[index];
+            let withdrawn = model.creator_received + model.fees_received;
+
+            assert_eq!(
+                self.env.vault_balance(&campaign).await,
+                contributed - withdrawn,
+                "vault balance drifted from live contributions minus withdrawals"
+            );
+            assert_eq!(withdrawn, state.released_amount);
+            assert_eq!(contributed, state.amount_raised);
+            assert_eq!(backers as u64, state.backer_count);
+            assert_eq!(model.refunded_pledges, state.total_refunded);
+            assert!(
+                model.creator_received <= contributed,
+                "creator received more than was contributed"
+            );
+
+            let claimed: HashSet<usize> = contributions
+                .iter()
+                .filter(|(_, c)| c.refund_claimed)
+                .map(|(backer, _)| *backer)
+                .collect();
+            assert_eq!(claimed, model.refunded);
+        }
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 32,
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn vault_accounting_holds(actions in scenario()) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        runtime.block_on(async {
+            let mut harness = Harness::new().await;
+            for action in &actions {
+                harness.apply(action).await;
+                harness.check_invariants().await;
+            }
+        });
+    }
+}
@@ -0,0 +1,372 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use anchor_lang::{error::ErrorCode, AnchorSerialize, Discriminator};
+use common::*;
+use hope_rise::{
+    constants::{LEGACY_USDC_MINT, MAX_DESCRIPTION_LENGTH, MAX_TITLE_LENGTH, MAX_URL_LENGTH},
+    errors::HopeRiseError,
+    state::{Campaign, CampaignStatus, Category, Contribution, FundingAsset, Milestone},
+};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+/// Accounts as the first deployment of the program wrote them, before
+/// layouts were versioned
+mod original {
+    use anchor_lang::prelude::borsh;
+
+    use super::*;
+
+    #[derive(AnchorSerialize)]
+    pub struct Campaign {
+        pub campaign_id: u64,
+        pub creator: Pubkey,
+        pub title: String,
+        pub short_description: String,
+        pub category: Category,
+        pub cover_image_url: String,
+        pub story_url: String,
+        pub funding_goal: u64,
+        pub deadline: i64,
+        pub amount_raised: u64,
+        pub backer_count: u64,
+        pub is_active: bool,
+        pub created_at: i64,
+        pub milestone_count: u8,
+        pub bump: u8,
+    }
+
+    impl Campaign {
+        pub const SIZE: usize = 800;
+    }
+
+    #[derive(AnchorSerialize)]
+    pub struct Milestone {
+        pub campaign: Pubkey,
+        pub milestone_index: u8,
+        pub title: String,
+        pub target_amount: u64,
+        pub is_completed: bool,
+        pub bump: u8,
+    }
+
+    impl Milestone {
+        pub const SIZE: usize = 160;
+    }
+
+    #[derive(AnchorSerialize)]
+    pub struct Contribution {
+        pub campaign: Pubkey,
+        pub contributor: Pubkey,
+        pub amount: u64,
+        pub contributed_at: i64,
+        pub refund_claimed: bool,
+        pub bump: u8,
+    }
+
+    impl Contribution {
+        pub const SIZE: usize = 96;
+    }
+
+    /// Discriminator and fields of `state`, followed by the zeroed rest of
+    /// an account of `size` bytes
+    pub fn data(discriminator: &[u8], state: &impl AnchorSerialize, size: usize) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        state.serialize(&mut data).unwrap();
+        assert!(data.len() <= size);
+        data.resize(size, 0);
+        data
+    }
+
+    /// A campaign with short metadata, as the original program created it
+    pub fn campaign(
+        is_active: bool,
+        funding_goal: u64,
+        amount_raised: u64,
+        created_at: i64,
+        deadline: i64,
+    ) -> Vec<u8> {
+        let state = Campaign {
+            campaign_id: 0,
+            creator: Pubkey::new_unique(),
+            title: "Clean water for Kisumu".to_string(),
+            short_description: "Drilling two boreholes for the local school".to_string(),
+            category: Category::Community,
+            cover_image_url: "ipfs://cover".to_string(),
+            story_url: "ipfs://story".to_string(),
+            funding_goal,
+            deadline,
+            amount_raised,
+            backer_count: u64::from(amount_raised > 0),
+            is_active,
+            created_at,
+            milestone_count: 0,
+            bump: 255,
+        };
+        data(super::Campaign::DISCRIMINATOR, &state, Campaign::SIZE)
+    }
+}
+
+#[tokio::test]
+async fn original_accounts_are_rejected_until_migrated() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let migrator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let usdc = env.legacy_usdc().await;
+    let campaign = env
+        .create_campaign(&creator, usdc, campaign_args())
+        .await
+        .unwrap();
+    env.process(
+        &[ix::add_milestone(&campaign, 0, "Ship", 0, 5_000, None)],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, 1_000_000).await;
+    env.fund(&campaign, &alice, 500_000).await.unwrap();
+
+    // Metadata this long left the original fixed size too little room for
+    // the current layout, so the campaign doesn't decode until migrating
+    // grows it at the migrator's expense
+    let state = env.campaign(&campaign.address).await;
+    let legacy = original::Campaign {
+        campaign_id: state.campaign_id,
+        creator: state.creator,
+        title: "t".repeat(MAX_TITLE_LENGTH),
+        short_description: "d".repeat(MAX_DESCRIPTION_LENGTH),
+        category: state.category,
+        cover_image_url: "c".repeat(MAX_URL_LENGTH),
+        story_url: "s".repeat(MAX_URL_LENGTH),
+        funding_goal: state.funding_goal,
+        deadline: state.deadline,
+        amount_raised: state.amount_raised,
+        backer_count: state.backer_count,
+        is_active: true,
+        created_at: state.created_at,
+        milestone_count: state.milestone_count,
+        bump: state.bump,
+    };
+    let data = original::data(Campaign::DISCRIMINATOR, &legacy, original::Campaign::SIZE);
+    env.set_legacy_account(&campaign.address, data).await;
+    let result = env.fund(&campaign, &alice, 500_000).await;
+    assert_error(result, ErrorCode::AccountDidNotDeserialize);
+
+    let rent = env.ctx.banks_client.get_rent().await.unwrap();
+    let space = Campaign::space(
+        &legacy.title,
+        &legacy.short_description,
+        &legacy.cover_image_url,
+        &legacy.story_url,
+    );
+    let before = env.lamports(&migrator.pubkey()).await;
+    env.process(
+        &[ix::migrate_account(&campaign.address, &migrator.pubkey())],
+        &[&migrator],
+    )
+    .await
+    .unwrap();
+    let account = env.raw_account(&campaign.address).await.unwrap();
+    assert_eq!(account.data.len(), space);
+    assert_eq!(account.lamports, rent.minimum_balance(space));
+    let top_up = rent.minimum_balance(space) - rent.minimum_balance(original::Campaign::SIZE);
+    assert_eq!(env.lamports(&migrator.pubkey()).await, before - top_up);
+    let migrated = env.campaign(&campaign.address).await;
+    assert_eq!(migrated.version, Campaign::VERSION);
+    assert_eq!(migrated.title, legacy.title);
+    assert_eq!(migrated.story_url, legacy.story_url);
+    assert_eq!(migrated.status, CampaignStatus::Active);
+    assert_eq!(migrated.mint, LEGACY_USDC_MINT);
+    assert_eq!(migrated.asset, FundingAsset::Token);
+    assert_eq!(migrated.vote_quorum_bps, 0);
+    assert_eq!(migrated.vote_approval_bps, 5_000);
+    assert_eq!(migrated.amount_raised, 500_000);
+    assert_eq!(migrated.allocated_bps, 0);
+    assert_eq!(migrated.open_contributions, 1);
+    assert_eq!(migrated.open_milestones, 1);
+    assert_eq!(
+        migrated.unpledge_cutoff,
+        migrated.deadline - migrated.created_at
+    );
+    assert_eq!(migrated.suspended_from, None);
+
+    // The contribution's spare bytes hold every field added since, so it
+    // keeps its size and nobody pays anything
+    let address = pda::contribution(&campaign.address, &alice.pubkey());
+    let state = env.contribution(&campaign.address, &alice.pubkey()).await;
+    let legacy = original::Contribution {
+        campaign: state.campaign,
+        contributor: state.contributor,
+        amount: state.amount,
+        contributed_at: state.contributed_at,
+        refund_claimed: false,
+        bump: state.bump,
+    };
+    let data = original::data(
+        Contribution::DISCRIMINATOR,
+        &legacy,
+        original::Contribution::SIZE,
+    );
+    env.set_legacy_account(&address, data).await;
+    let result = env.fund(&campaign, &alice, 500_000).await;
+    assert_hope_rise_error(result, HopeRiseError::AccountNotMigrated);
+
+    let before = env.lamports(&migrator.pubkey()).await;
+    env.process(
+        &[ix::migrate_account(&address, &migrator.pubkey())],
+        &[&migrator],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.lamports(&migrator.pubkey()).await, before);
+    let account = env.raw_account(&address).await.unwrap();
+    assert_eq!(account.data.len(), Contribution::SIZE);
+    let migrated = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert_eq!(migrated.version, Contribution::VERSION);
+    assert_eq!(migrated.amount, 500_000);
+    assert_eq!(migrated.reward_tier, None);
+
+    env.fund(&campaign, &alice, 500_000).await.unwrap();
+    assert_eq!(
+        env.contribution(&campaign.address, &alice.pubkey())
+            .await
+            .amount,
+        1_000_000
+    );
+    env.finalize_after_deadline(&campaign).await;
+
+    // Milestones grew past their original size, and had no payout share
+    let address = pda::milestone(&campaign.address, 0);
+    let state = env.milestone(&address).await;
+    let legacy = original::Milestone {
+        campaign: state.campaign,
+        milestone_index: 0,
+        title: "Ship".to_string(),
+        target_amount: 0,
+        is_completed: false,
+        bump: state.bump,
+    };
+    let data = original::data(Milestone::DISCRIMINATOR, &legacy, original::Milestone::SIZE);
+    env.set_legacy_account(&address, data).await;
+    let instruction = ix::submit_milestone_proof(&campaign, 0, "ipfs://proof");
+    let result = env.process(&[instruction], &[&creator]).await;
+    assert_hope_rise_error(result, HopeRiseError::AccountNotMigrated);
+
+    env.process(
+        &[ix::migrate_account(&address, &migrator.pubkey())],
+        &[&migrator],
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        env.raw_account(&address).await.unwrap().data.len(),
+        Milestone::SIZE
+    );
+    let migrated = env.milestone(&address).await;
+    assert_eq!(migrated.version, Milestone::VERSION);
+    assert_eq!(migrated.title, "Ship");
+    assert_eq!(migrated.payout_bps, 0);
+    assert_eq!(migrated.stretch_goal, None);
+
+    let instruction = ix::submit_milestone_proof(&campaign, 0, "ipfs://proof");
+    env.process(&[instruction], &[&creator]).await.unwrap();
+    assert_eq!(env.milestone(&address).await.proof_url, "ipfs://proof");
+}
+
+#[tokio::test]
+async fn original_campaigns_take_their_status_from_the_deadline_and_goal() {
+    let mut env = TestEnv::new().await;
+    let migrator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let now = env.now().await;
+    let created_at = now - 10 * 86_400;
+    let (running, ended) = (now + 86_400, now - 86_400);
+
+    // Closing a campaign was the only way to end it early, and the deadline
+    // settled the rest on whether the goal was met
+    let cases = [
+        (true, running, 0, CampaignStatus::Active, 0),
+        (true, ended, 1_000, CampaignStatus::Succeeded, ended),
+        (true, ended, 999, CampaignStatus::Failed, ended),
+        (false, running, 1_000, CampaignStatus::Succeeded, now),
+        (false, running, 999, CampaignStatus::Cancelled, 0),
+    ];
+    for (is_active, deadline, amount_raised, status, finalized_at) in cases {
+        let address = Pubkey::new_unique();
+        let data = original::campaign(is_active, 1_000, amount_raised, created_at, deadline);
+        env.set_legacy_account(&address, data).await;
+        env.process(
+            &[ix::migrate_account(&address, &migrator.pubkey())],
+            &[&migrator],
+        )
+        .await
+        .unwrap();
+
+        let migrated = env.campaign(&address).await;
+        assert_eq!(migrated.status, status);
+        assert_eq!(migrated.finalized_at, finalized_at);
+        assert_eq!(migrated.amount_raised, amount_raised);
+        assert_eq!(migrated.mint, LEGACY_USDC_MINT);
+        assert_eq!(migrated.unpledge_cutoff, deadline - created_at);
+    }
+}
+
+#[tokio::test]
+async fn migration_only_upgrades_outdated_program_accounts() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let migrator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+
+    let result = env
+        .process(
+            &[ix::migrate_account(&campaign.address, &migrator.pubkey())],
+            &[&migrator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::AccountAlreadyMigrated);
+
+    let result = env
+        .process(
+            &[ix::migrate_account(
+                &pda::platform_config(),
+                &migrator.pubkey(),
+            )],
+            &[&migrator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::NotVersionedAccount);
+
+    let result = env
+        .process(
+            &[ix::migrate_account(
+                &pda::campaign_vault(&campaign.address),
+                &migrator.pubkey(),
+            )],
+            &[&migrator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::NotVersionedAccount);
+
+    // Accounts can be upgraded while the program is paused for the upgrade
+    let address = Pubkey::new_unique();
+    let now = env.now().await;
+    let data = original::campaign(true, 1_000, 0, now, now + 86_400);
+    env.set_legacy_account(&address, data).await;
+    assert_eq!(env.campaign(&address).await.version, 0);
+    env.process(&[ix::set_paused(&admin, true)], &[])
+        .await
+        .unwrap();
+    env.process(
+        &[ix::migrate_account(&address, &migrator.pubkey())],
+        &[&migrator],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.campaign(&address).await.version, Campaign::VERSION);
+}
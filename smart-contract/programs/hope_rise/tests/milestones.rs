@@ -0,0 +1,676 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{
+    constants::{MAX_MILESTONES_PER_CAMPAIGN, MILESTONE_VOTING_PERIOD},
+    errors::HopeRiseError,
+    events::{MilestoneQuorumMissed, RefundClaimed},
+    state::CampaignStatus,
+};
+use solana_sdk::signature::{Keypair, Signer};
+
+/// Milestones added to a campaign before launch: (title, target_amount, payout_bps)
+type MilestonePlan<'a> = &'a [(&'a str, u64, u16)];
+
+/// Creates a campaign with `milestones`, funds it with one backer per amount
+/// and finalizes it after the deadline
+async fn funded_campaign(
+    env: &mut TestEnv,
+    creator: &Keypair,
+    args: CampaignArgs,
+    milestones: MilestonePlan<'_>,
+    contributions: &[u64],
+) -> (CampaignKeys, Vec<Keypair>) {
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let campaign = env.create_campaign(creator, asset, args).await.unwrap();
+    for (index, (title, target_amount, payout_bps)) in milestones.iter().enumerate() {
+        let instruction = ix::add_milestone(
+            &campaign,
+            index as u8,
+            title,
+            *target_amount,
+            *payout_bps,
+            None,
+        );
+        env.process(&[instruction], &[creator]).await.unwrap();
+    }
+    env.process(&[ix::launch_campaign(&campaign)], &[creator])
+        .await
+        .unwrap();
+
+    let mut backers = Vec::new();
+    for amount in contributions {
+        let backer = env.backer(&campaign, *amount).await;
+        env.fund(&campaign, &backer, *amount).await.unwrap();
+        backers.push(backer);
+    }
+    env.finalize_after_deadline(&campaign).await;
+
+    (campaign, backers)
+}
+
+#[tokio::test]
+async fn add_milestone_validates_arguments() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+
+    let result = env
+        .process(
+            &[ix::add_milestone(
+                &campaign,
+                0,
+                &"m".repeat(101),
+                0,
+                0,
+                None,
+            )],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneTitleTooLong);
+
+    let impostor = CampaignKeys {
+        creator: outsider.pubkey(),
+        ..campaign
+    };
+    let result = env
+        .process(
+            &[ix::add_milestone(&impostor, 0, "Design", 0, 0, None)],
+            &[&outsider],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    env.process(
+        &[ix::add_milestone(&campaign, 0, "Design", 100, 6_000, None)],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let result = env
+        .process(
+            &[ix::add_milestone(&campaign, 1, "Build", 200, 4_001, None)],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidPayoutShare);
+
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 0)).await;
+    assert_eq!(milestone.milestone_index, 0);
+    assert_eq!(milestone.title, "Design");
+    assert_eq!(milestone.target_amount, 100);
+    assert_eq!(milestone.payout_bps, 6_000);
+    assert_eq!(env.campaign(&campaign.address).await.allocated_bps, 6_000);
+
+    for index in 1..MAX_MILESTONES_PER_CAMPAIGN {
+        env.process(
+            &[ix::add_milestone(&campaign, index, "Step", 0, 0, None)],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+    }
+    let result = env
+        .process(
+            &[ix::add_milestone(
+                &campaign,
+                MAX_MILESTONES_PER_CAMPAIGN,
+                "Step",
+                0,
+                0,
+                None,
+            )],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MaxMilestonesReached);
+}
+
+#[tokio::test]
+async fn milestones_are_fixed_once_funding_ends() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let (campaign, _) =
+        funded_campaign(&mut env, &creator, campaign_args(), &[], &[1_000_000]).await;
+
+    let result = env
+        .process(
+            &[ix::add_milestone(&campaign, 0, "Late", 0, 0, None)],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotActive);
+
+    // No instruction pays out an Active campaign, so the payout is staged
+    let mut state = env.campaign(&campaign.address).await;
+    state.status = CampaignStatus::Active;
+    state.released_amount = 1;
+    env.set_account(&campaign.address, &state).await;
+    let result = env
+        .process(
+            &[ix::add_milestone(&campaign, 0, "Late", 0, 0, None)],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::FundsAlreadyReleased);
+}
+
+#[tokio::test]
+async fn approved_milestones_release_their_tranches() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 10_000,
+        ..campaign_args()
+    };
+    let milestones = [("Prototype", 5_000, 3_000), ("Launch", 10_000, 2_000)];
+    let (campaign, backers) = funded_campaign(
+        &mut env,
+        &creator,
+        args,
+        &milestones,
+        &[5_000, 3_000, 2_000],
+    )
+    .await;
+    let [alice, bob, carol] = &backers[..] else {
+        unreachable!()
+    };
+
+    // Half of the funds are not tied to a milestone
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &creator.pubkey()).await, 5_000);
+
+    let result = env
+        .process(
+            &[ix::vote_milestone(
+                &campaign.address,
+                0,
+                &alice.pubkey(),
+                true,
+            )],
+            &[alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::VotingNotOpen);
+    let result = env
+        .process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::VotingNotOpen);
+
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 0)).await;
+    assert_eq!(milestone.proof_url, "ipfs://proof");
+    assert_eq!(
+        milestone.voting_ends_at,
+        env.now().await + MILESTONE_VOTING_PERIOD
+    );
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&campaign, 0, "ipfs://again")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneProofAlreadySubmitted);
+
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &alice.pubkey(),
+            true,
+        )],
+        &[alice],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &bob.pubkey(),
+            false,
+        )],
+        &[bob],
+    )
+    .await
+    .unwrap();
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 0)).await;
+    assert_eq!(milestone.yes_weight, 5_000);
+    assert_eq!(milestone.no_weight, 3_000);
+
+    let result = env
+        .process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::VotingStillOpen);
+    let result = env
+        .process(&[ix::release_milestone(&campaign, 0, &admin)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneNotCompleted);
+
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+    let result = env
+        .process(
+            &[ix::vote_milestone(
+                &campaign.address,
+                0,
+                &carol.pubkey(),
+                true,
+            )],
+            &[carol],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::VotingClosed);
+
+    env.process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+    assert!(
+        env.milestone(&pda::milestone(&campaign.address, 0))
+            .await
+            .is_completed
+    );
+    let result = env
+        .process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneAlreadyCompleted);
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&campaign, 0, "ipfs://again")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneAlreadyCompleted);
+
+    env.process(&[ix::release_milestone(&campaign, 0, &admin)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &creator.pubkey()).await, 8_000);
+    let result = env
+        .process(&[ix::release_milestone(&campaign, 0, &admin)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneAlreadyReleased);
+
+    // Votes on a released milestone are no longer needed, and anyone may
+    // return their rent to the voter
+    let vote = pda::milestone_vote(&pda::milestone(&campaign.address, 0), &alice.pubkey());
+    let rent = env.lamports(&vote).await;
+    let before = env.lamports(&alice.pubkey()).await;
+    env.process(
+        &[ix::close_milestone_vote(
+            &campaign.address,
+            0,
+            &alice.pubkey(),
+        )],
+        &[],
+    )
+    .await
+    .unwrap();
+    assert!(env.raw_account(&vote).await.is_none());
+    assert_eq!(env.lamports(&alice.pubkey()).await, before + rent);
+
+    // The last tranche completes the campaign
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 1, "ipfs://launch")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            1,
+            &carol.pubkey(),
+            true,
+        )],
+        &[carol],
+    )
+    .await
+    .unwrap();
+    let result = env
+        .process(
+            &[ix::close_milestone_vote(
+                &campaign.address,
+                1,
+                &carol.pubkey(),
+            )],
+            &[],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneVoteStillNeeded);
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+    env.process(&[ix::complete_milestone(&campaign.address, 1)], &[])
+        .await
+        .unwrap();
+    env.process(&[ix::release_milestone(&campaign, 1, &admin)], &[&creator])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        env.asset_balance(&campaign, &creator.pubkey()).await,
+        10_000
+    );
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.released_bps, 5_000);
+    assert_eq!(state.released_amount, 10_000);
+    assert_eq!(state.status, CampaignStatus::Completed);
+}
+
+#[tokio::test]
+async fn milestone_proof_needs_a_funded_campaign_and_target() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let active = env.active_campaign(&creator, asset, 1_000).await;
+    env.process(
+        &[ix::add_milestone(&active, 0, "Build", 0, 5_000, None)],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&active, 0, "ipfs://proof")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidCampaignStatus);
+
+    let args = CampaignArgs {
+        funding_goal: 1_000,
+        ..campaign_args()
+    };
+    let (campaign, _) = funded_campaign(
+        &mut env,
+        &creator,
+        args,
+        &[("Stretch", 2_000, 5_000)],
+        &[1_000],
+    )
+    .await;
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&campaign, 0, &"u".repeat(201))],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::UrlTooLong);
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneTargetNotReached);
+}
+
+#[tokio::test]
+async fn rejected_milestone_fails_the_campaign() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 10_000,
+        ..campaign_args()
+    };
+    let milestones = [("Build", 0, 5_000), ("Ship", 0, 0)];
+    let (campaign, backers) =
+        funded_campaign(&mut env, &creator, args, &milestones, &[6_000, 4_000]).await;
+    let [alice, bob] = &backers[..] else {
+        unreachable!()
+    };
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &alice.pubkey(),
+            false,
+        )],
+        &[alice],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &bob.pubkey(),
+            true,
+        )],
+        &[bob],
+    )
+    .await
+    .unwrap();
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+    env.process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Failed);
+    assert_eq!(state.failed_milestone, Some(0));
+    assert!(
+        !env.milestone(&pda::milestone(&campaign.address, 0))
+            .await
+            .is_completed
+    );
+
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&campaign, 1, "ipfs://ship")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneVoteFailed);
+    let result = env
+        .process(
+            &[ix::vote_milestone(
+                &campaign.address,
+                1,
+                &alice.pubkey(),
+                true,
+            )],
+            &[alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneVoteFailed);
+    let result = env
+        .process(&[ix::complete_milestone(&campaign.address, 1)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneVoteFailed);
+
+    // Backers split the half that was never released
+    env.process(
+        &[ix::claim_partial_refund(&campaign, &alice.pubkey())],
+        &[alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 3_000);
+    // Later shares still come out of the gross amount raised
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 4_000);
+    assert_eq!(state.total_refunded, 6_000);
+    assert_eq!(state.gross_raised().unwrap(), 10_000);
+    let result = env
+        .process(
+            &[ix::claim_partial_refund(&campaign, &alice.pubkey())],
+            &[alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::RefundAlreadyClaimed);
+
+    // Either refund instruction pays the unreleased share, flagged as partial
+    let logs = env
+        .process_with_logs(&[ix::claim_refund(&campaign, &bob.pubkey())], &[bob])
+        .await
+        .unwrap();
+    let [refund] = &events::<RefundClaimed>(&logs)[..] else {
+        panic!("expected one RefundClaimed event")
+    };
+    assert!(refund.partial);
+    assert_eq!(refund.amount, 2_000);
+    assert_eq!(refund.contribution_amount, 4_000);
+    assert_eq!(env.asset_balance(&campaign, &bob.pubkey()).await, 2_000);
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 0);
+    assert_eq!(state.backer_count, 0);
+    assert_eq!(state.total_refunded, 10_000);
+}
+
+#[tokio::test]
+async fn milestone_votes_need_quorum() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 10_000,
+        vote_quorum_bps: 6_000,
+        ..campaign_args()
+    };
+    let (campaign, backers) = funded_campaign(
+        &mut env,
+        &creator,
+        args,
+        &[("Build", 0, 5_000)],
+        &[4_000, 6_000],
+    )
+    .await;
+
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    // A unanimous vote from 40% of the funds is below quorum
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &backers[0].pubkey(),
+            true,
+        )],
+        &[&backers[0]],
+    )
+    .await
+    .unwrap();
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+    let logs = env
+        .process_with_logs(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+    let [missed] = &events::<MilestoneQuorumMissed>(&logs)[..] else {
+        panic!("expected one MilestoneQuorumMissed event")
+    };
+    assert_eq!(missed.milestone_index, 0);
+    assert_eq!((missed.yes_weight, missed.no_weight), (4_000, 0));
+
+    // Missing quorum is not a rejection: the campaign carries on and the
+    // creator can put the milestone to a vote again
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Succeeded);
+    assert_eq!(state.failed_milestone, None);
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 0)).await;
+    assert!(!milestone.is_completed);
+    assert_eq!(milestone.voting_ends_at, 0);
+    let result = env
+        .process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::VotingNotOpen);
+
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof2")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    // Earlier votes carry over into the new round
+    env.process(
+        &[ix::vote_milestone(
+            &campaign.address,
+            0,
+            &backers[1].pubkey(),
+            true,
+        )],
+        &[&backers[1]],
+    )
+    .await
+    .unwrap();
+    env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+    env.process(&[ix::complete_milestone(&campaign.address, 0)], &[])
+        .await
+        .unwrap();
+
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 0)).await;
+    assert!(milestone.is_completed);
+    assert_eq!((milestone.yes_weight, milestone.no_weight), (10_000, 0));
+    assert_eq!(
+        env.campaign(&campaign.address).await.status,
+        CampaignStatus::Succeeded
+    );
+}
+
+#[tokio::test]
+async fn empty_contributions_cannot_vote() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 1_000,
+        ..campaign_args()
+    };
+    let (campaign, backers) =
+        funded_campaign(&mut env, &creator, args, &[("Build", 0, 5_000)], &[1_000]).await;
+    let alice = &backers[0];
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://proof")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+
+    // Contributions are never emptied by an instruction yet
+    let address = pda::contribution(&campaign.address, &alice.pubkey());
+    let mut contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    contribution.amount = 0;
+    env.set_account(&address, &contribution).await;
+
+    let result = env
+        .process(
+            &[ix::vote_milestone(
+                &campaign.address,
+                0,
+                &alice.pubkey(),
+                true,
+            )],
+            &[alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::NoContribution);
+}
@@ -0,0 +1,219 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use anchor_lang::solana_program::system_instruction;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use common::*;
+use hope_rise::{constants::RECEIPT_SYMBOL, errors::HopeRiseError};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::{
+    extension::{non_transferable::NonTransferable, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint,
+};
+
+// Position of the receipt mint in ClaimRefund's accounts
+const REFUND_RECEIPT_MINT: usize = 11;
+
+/// Transfers the receipt of `from`'s contribution to `to`
+fn transfer_receipt(
+    campaign: &CampaignKeys,
+    from: &Pubkey,
+    to: &Pubkey,
+) -> anchor_lang::solana_program::instruction::Instruction {
+    let receipt_mint = pda::receipt_mint(&pda::contribution(&campaign.address, from));
+    spl_token_2022::instruction::transfer_checked(
+        &TOKEN_2022_PROGRAM_ID,
+        &ix::receipt_token_account(&campaign.address, from),
+        &receipt_mint,
+        &get_associated_token_address_with_program_id(to, &receipt_mint, &TOKEN_2022_PROGRAM_ID),
+        from,
+        &[],
+        1,
+        0,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn first_contribution_mints_a_receipt() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 5_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+
+    let contribution = pda::contribution(&campaign.address, &alice.pubkey());
+    assert!(
+        env.contribution(&campaign.address, &alice.pubkey())
+            .await
+            .has_receipt
+    );
+    let receipt = ix::receipt_token_account(&campaign.address, &alice.pubkey());
+    assert_eq!(env.token_balance(&receipt).await, 1);
+
+    let receipt_mint = pda::receipt_mint(&contribution);
+    let account = env.raw_account(&receipt_mint).await.unwrap();
+    assert_eq!(account.owner, TOKEN_2022_PROGRAM_ID);
+    let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    assert_eq!(mint.base.supply, 1);
+    assert_eq!(mint.base.decimals, 0);
+    assert!(mint.get_extension::<NonTransferable>().is_ok());
+    let metadata = mint.get_variable_len_extension::<TokenMetadata>().unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(metadata.name, state.title);
+    assert_eq!(metadata.symbol, RECEIPT_SYMBOL);
+    assert_eq!(metadata.uri, state.story_url);
+    assert_eq!(
+        metadata.additional_metadata,
+        vec![("campaign".to_string(), campaign.address.to_string())]
+    );
+
+    // Receipts stay with the backer unless the campaign allows transfers
+    let bob = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    env.fund_token_account(&receipt_mint, &TOKEN_2022_PROGRAM_ID, &bob.pubkey(), 0)
+        .await;
+    let result = env
+        .process(
+            &[transfer_receipt(&campaign, &alice.pubkey(), &bob.pubkey())],
+            &[&alice],
+        )
+        .await;
+    assert_error(
+        result,
+        spl_token_2022::error::TokenError::NonTransferable as u32,
+    );
+}
+
+#[tokio::test]
+async fn prefunded_receipt_mints_are_still_issued() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 5_000).await;
+    let bob = env.backer(&campaign, 5_000).await;
+
+    // Lamports sent to the mint's address ahead of time must not block the
+    // backer's first contribution
+    let receipt_mint = pda::receipt_mint(&pda::contribution(&campaign.address, &alice.pubkey()));
+    let grief = system_instruction::transfer(&env.admin.pubkey(), &receipt_mint, 1_000);
+    env.process(&[grief], &[]).await.unwrap();
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.fund(&campaign, &bob, 1_000).await.unwrap();
+
+    let account = env.raw_account(&receipt_mint).await.unwrap();
+    assert_eq!(account.owner, TOKEN_2022_PROGRAM_ID);
+    let mint = StateWithExtensions::<Mint>::unpack(&account.data).unwrap();
+    assert_eq!(mint.base.supply, 1);
+    let receipt = ix::receipt_token_account(&campaign.address, &alice.pubkey());
+    assert_eq!(env.token_balance(&receipt).await, 1);
+    // The backer only made up the shortfall to the usual rent
+    let bob_mint = pda::receipt_mint(&pda::contribution(&campaign.address, &bob.pubkey()));
+    assert_eq!(
+        env.lamports(&receipt_mint).await,
+        env.lamports(&bob_mint).await
+    );
+}
+
+#[tokio::test]
+async fn refunds_burn_the_receipt() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .active_campaign(&creator, Asset::Sol, LAMPORTS_PER_SOL)
+        .await;
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL / 2)
+        .await
+        .unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    let instruction = ix::claim_refund(&campaign, &alice.pubkey());
+    let result = env
+        .process(
+            &[without_account(instruction, REFUND_RECEIPT_MINT)],
+            &[&alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MissingReceiptAccount);
+
+    // The refund needs the account that actually holds the receipt
+    let contribution = pda::contribution(&campaign.address, &alice.pubkey());
+    let receipt_mint = pda::receipt_mint(&contribution);
+    let bob = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let empty = env
+        .fund_token_account(&receipt_mint, &TOKEN_2022_PROGRAM_ID, &bob.pubkey(), 0)
+        .await;
+    let mut instruction = ix::claim_refund(&campaign, &alice.pubkey());
+    instruction.accounts[REFUND_RECEIPT_MINT + 1].pubkey = empty;
+    let result = env.process(&[instruction], &[&alice]).await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidReceiptAccount);
+
+    let receipt = ix::receipt_token_account(&campaign.address, &alice.pubkey());
+    let receipt_rent = env.lamports(&receipt_mint).await + env.lamports(&receipt).await;
+    let before = env.lamports(&alice.pubkey()).await;
+    env.process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.lamports(&alice.pubkey()).await,
+        before + LAMPORTS_PER_SOL / 2 + receipt_rent
+    );
+    assert!(env.raw_account(&receipt_mint).await.is_none());
+    assert!(env.raw_account(&receipt).await.is_none());
+    assert!(
+        !env.contribution(&campaign.address, &alice.pubkey())
+            .await
+            .has_receipt
+    );
+}
+
+#[tokio::test]
+async fn transferable_receipts_are_burned_wherever_they_are_held() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_2022_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        funding_goal: 10_000,
+        transferable_receipts: true,
+        ..campaign_args()
+    };
+    let campaign = env.create_campaign(&creator, asset, args).await.unwrap();
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, 5_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+
+    let contribution = pda::contribution(&campaign.address, &alice.pubkey());
+    let receipt_mint = pda::receipt_mint(&contribution);
+    let bob = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let bobs_receipt = env
+        .fund_token_account(&receipt_mint, &TOKEN_2022_PROGRAM_ID, &bob.pubkey(), 0)
+        .await;
+    env.process(
+        &[transfer_receipt(&campaign, &alice.pubkey(), &bob.pubkey())],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.token_balance(&bobs_receipt).await, 1);
+
+    // The refund still goes to the backer, and the mint's rent with it
+    env.finalize_after_deadline(&campaign).await;
+    let mint_rent = env.lamports(&receipt_mint).await;
+    let before = env.lamports(&alice.pubkey()).await;
+    let mut instruction = ix::claim_refund(&campaign, &alice.pubkey());
+    instruction.accounts[REFUND_RECEIPT_MINT + 1].pubkey = bobs_receipt;
+    env.process(&[instruction], &[&alice]).await.unwrap();
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 5_000);
+    assert_eq!(env.lamports(&alice.pubkey()).await, before + mint_rent);
+    assert!(env.raw_account(&receipt_mint).await.is_none());
+    // Bob's emptied account is his to close
+    assert_eq!(env.token_balance(&bobs_receipt).await, 0);
+    assert!(env.raw_account(&bobs_receipt).await.is_some());
+}
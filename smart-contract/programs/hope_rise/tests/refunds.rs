@@ -0,0 +1,161 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::errors::HopeRiseError;
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn failed_campaign_refunds_contributions() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 5_000).await;
+    let bob = env.backer(&campaign, 5_000).await;
+    env.fund(&campaign, &alice, 3_000).await.unwrap();
+    env.fund(&campaign, &bob, 2_000).await.unwrap();
+
+    let result = env
+        .process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignStillActive);
+
+    env.finalize_after_deadline(&campaign).await;
+    let result = env
+        .process(
+            &[ix::claim_partial_refund(&campaign, &alice.pubkey())],
+            &[&alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotFailed);
+
+    env.process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 5_000);
+    let contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert!(contribution.refund_claimed);
+    assert_eq!(contribution.amount, 0);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 2_000);
+    assert_eq!(state.backer_count, 1);
+    assert_eq!(state.total_refunded, 3_000);
+
+    let result = env
+        .process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::RefundAlreadyClaimed);
+
+    env.process(&[ix::claim_refund(&campaign, &bob.pubkey())], &[&bob])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &bob.pubkey()).await, 5_000);
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 0);
+    assert_eq!(state.backer_count, 0);
+    assert_eq!(state.total_refunded, 5_000);
+}
+
+#[tokio::test]
+async fn successful_campaign_keeps_contributions() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    let result = env
+        .process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::GoalWasMet);
+}
+
+#[tokio::test]
+async fn cancelled_campaign_refunds_sol() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .active_campaign(&creator, Asset::Sol, LAMPORTS_PER_SOL)
+        .await;
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL / 4)
+        .await
+        .unwrap();
+    env.process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+
+    // Burning the receipt hands its rent back as well
+    let receipt_mint = pda::receipt_mint(&pda::contribution(&campaign.address, &alice.pubkey()));
+    let receipt = ix::receipt_token_account(&campaign.address, &alice.pubkey());
+    let receipt_rent = env.lamports(&receipt_mint).await + env.lamports(&receipt).await;
+    let before = env.lamports(&alice.pubkey()).await;
+    env.process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert_eq!(
+        env.lamports(&alice.pubkey()).await,
+        before + LAMPORTS_PER_SOL / 4 + receipt_rent
+    );
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+}
+
+#[tokio::test]
+async fn force_failed_campaign_refunds_token_2022() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_2022_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 4_000).await;
+    env.fund(&campaign, &alice, 4_000).await.unwrap();
+
+    env.process(&[ix::suspend_campaign(&campaign.address, &admin, 3)], &[])
+        .await
+        .unwrap();
+    let result = env
+        .process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignSuspended);
+
+    env.process(
+        &[ix::force_fail_campaign(&campaign.address, &admin, 3)],
+        &[],
+    )
+    .await
+    .unwrap();
+    env.process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 4_000);
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+}
+
+#[tokio::test]
+async fn empty_contributions_get_no_refund() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 1_000).await;
+    env.fund(&campaign, &alice, 1_000).await.unwrap();
+    env.process(&[ix::close_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+
+    // Contributions are never emptied by an instruction yet
+    let address = pda::contribution(&campaign.address, &alice.pubkey());
+    let mut contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    contribution.amount = 0;
+    env.set_account(&address, &contribution).await;
+
+    let result = env
+        .process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::NoContribution);
+}
@@ -0,0 +1,209 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{
+    constants::{MAX_REWARD_TIERS_PER_CAMPAIGN, MAX_URL_LENGTH},
+    errors::HopeRiseError,
+};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn pledges_meeting_the_minimum_claim_a_tier() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    env.process(
+        &[
+            ix::add_reward_tier(&campaign, 0, 1_000, 1, "ipfs://signed-copy"),
+            ix::add_reward_tier(&campaign, 1, 100, 5, "ipfs://sticker"),
+        ],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.reward_tier_count, 2);
+    assert_eq!(state.open_reward_tiers, 2);
+    let tier = env
+        .reward_tier(&pda::reward_tier(&campaign.address, 0))
+        .await;
+    assert_eq!(tier.min_pledge, 1_000);
+    assert_eq!(tier.metadata_url, "ipfs://signed-copy");
+
+    let alice = env.backer(&campaign, 2_000).await;
+    let bob = env.backer(&campaign, 2_000).await;
+    let result = env.fund_with_tier(&campaign, &alice, 500, 0).await;
+    assert_hope_rise_error(result, HopeRiseError::PledgeBelowTierMinimum);
+
+    // Earlier contributions count toward the minimum
+    env.fund(&campaign, &alice, 500).await.unwrap();
+    env.fund_with_tier(&campaign, &alice, 500, 0).await.unwrap();
+    let contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert_eq!(contribution.reward_tier, Some(0));
+    let tier = env
+        .reward_tier(&pda::reward_tier(&campaign.address, 0))
+        .await;
+    assert_eq!(tier.claimed, 1);
+    assert_eq!(tier.remaining(), 0);
+
+    let result = env.fund_with_tier(&campaign, &bob, 1_000, 0).await;
+    assert_hope_rise_error(result, HopeRiseError::RewardTierSoldOut);
+    let result = env.fund_with_tier(&campaign, &alice, 100, 1).await;
+    assert_hope_rise_error(result, HopeRiseError::RewardTierAlreadyClaimed);
+
+    // Topping up the tier already claimed takes nothing more from its supply
+    env.fund_with_tier(&campaign, &alice, 100, 0).await.unwrap();
+    let tier = env
+        .reward_tier(&pda::reward_tier(&campaign.address, 0))
+        .await;
+    assert_eq!(tier.claimed, 1);
+
+    // The tier account has to be the one the index names
+    let mut instruction = ix::fund_campaign(&campaign, &bob.pubkey(), 100, Some(1), 0..0);
+    let tier_index = instruction
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == pda::reward_tier(&campaign.address, 1))
+        .unwrap();
+    instruction.accounts[tier_index].pubkey = pda::reward_tier(&campaign.address, 0);
+    let result = env.process(&[instruction], &[&bob]).await;
+    assert_hope_rise_error(result, HopeRiseError::RewardTierMismatch);
+    let instruction = ix::fund_campaign(&campaign, &bob.pubkey(), 100, Some(1), 0..0);
+    let result = env
+        .process(&[without_account(instruction, tier_index)], &[&bob])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::RewardTierMismatch);
+
+    env.fund_with_tier(&campaign, &bob, 100, 1).await.unwrap();
+    let contribution = env.contribution(&campaign.address, &bob.pubkey()).await;
+    assert_eq!(contribution.reward_tier, Some(1));
+    let tier = env
+        .reward_tier(&pda::reward_tier(&campaign.address, 1))
+        .await;
+    assert_eq!(tier.claimed, 1);
+}
+
+#[tokio::test]
+async fn reward_tiers_are_validated() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env
+        .create_campaign(&creator, asset, campaign_args())
+        .await
+        .unwrap();
+
+    let result = env
+        .process(
+            &[ix::add_reward_tier(&campaign, 0, 0, 10, "ipfs://tier")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidRewardTier);
+    let result = env
+        .process(
+            &[ix::add_reward_tier(&campaign, 0, 100, 0, "ipfs://tier")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidRewardTier);
+    let url = "u".repeat(MAX_URL_LENGTH + 1);
+    let result = env
+        .process(
+            &[ix::add_reward_tier(&campaign, 0, 100, 10, &url)],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::UrlTooLong);
+
+    let outsider = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let impostor = CampaignKeys {
+        creator: outsider.pubkey(),
+        ..campaign
+    };
+    let result = env
+        .process(
+            &[ix::add_reward_tier(&impostor, 0, 100, 10, "ipfs://tier")],
+            &[&outsider],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::Unauthorized);
+
+    for index in 0..MAX_REWARD_TIERS_PER_CAMPAIGN {
+        env.process(
+            &[ix::add_reward_tier(
+                &campaign,
+                index,
+                100,
+                10,
+                "ipfs://tier",
+            )],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+    }
+    let instruction = ix::add_reward_tier(
+        &campaign,
+        MAX_REWARD_TIERS_PER_CAMPAIGN,
+        100,
+        10,
+        "ipfs://tier",
+    );
+    let result = env.process(&[instruction], &[&creator]).await;
+    assert_hope_rise_error(result, HopeRiseError::MaxRewardTiersReached);
+}
+
+#[tokio::test]
+async fn reward_tiers_close_once_the_campaign_settles() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    env.process(
+        &[ix::add_reward_tier(&campaign, 0, 100, 10, "ipfs://tier")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let alice = env.backer(&campaign, 100).await;
+    env.fund_with_tier(&campaign, &alice, 100, 0).await.unwrap();
+
+    let result = env
+        .process(&[ix::close_reward_tier(&campaign, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotSettled);
+
+    env.finalize_after_deadline(&campaign).await;
+    env.process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    env.process(
+        &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    let result = env
+        .process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignAccountsStillOpen);
+
+    let tier = pda::reward_tier(&campaign.address, 0);
+    let rent = env.lamports(&tier).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    env.process(&[ix::close_reward_tier(&campaign, 0)], &[])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&tier).await.is_none());
+    assert_eq!(env.lamports(&creator.pubkey()).await, before + rent);
+    assert_eq!(env.campaign(&campaign.address).await.open_reward_tiers, 0);
+
+    env.process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&campaign.address).await.is_none());
+}
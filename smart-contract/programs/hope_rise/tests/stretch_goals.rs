@@ -0,0 +1,469 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{
+    constants::{MAX_STRETCH_GOALS_PER_CAMPAIGN, MILESTONE_VOTING_PERIOD},
+    errors::HopeRiseError,
+    events::{MilestonePayoutReassigned, StretchGoalUnlocked},
+    state::CampaignStatus,
+};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn pledges_crossing_a_threshold_unlock_the_stretch_goal() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+
+    let result = env
+        .process(
+            &[ix::add_stretch_goal(&campaign, 0, 1_000, "ipfs://solar")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidStretchGoalThreshold);
+    env.process(
+        &[ix::add_stretch_goal(&campaign, 0, 1_500, "ipfs://solar")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let result = env
+        .process(
+            &[ix::add_stretch_goal(&campaign, 1, 1_500, "ipfs://pump")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidStretchGoalThreshold);
+    // Thresholds are checked against the goal before
+    let instruction = ix::add_stretch_goal(&campaign, 1, 2_000, "ipfs://pump");
+    let result = env
+        .process(&[without_account(instruction, 3)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::StretchGoalMismatch);
+    env.process(
+        &[ix::add_stretch_goal(&campaign, 1, 2_000, "ipfs://pump")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.stretch_goal_count, 2);
+    assert_eq!(state.open_stretch_goals, 2);
+    let goal = env
+        .stretch_goal(&pda::stretch_goal(&campaign.address, 1))
+        .await;
+    assert_eq!(goal.threshold, 2_000);
+    assert_eq!(goal.metadata_url, "ipfs://pump");
+    assert!(!goal.is_unlocked());
+
+    let alice = env.backer(&campaign, 5_000).await;
+    let bob = env.backer(&campaign, 5_000).await;
+    env.fund_unlocking(&campaign, &alice, 1_200).await.unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.unlocked_stretch_goals,
+        0
+    );
+
+    // Pledges leaving the locked goals out don't unlock them, while the
+    // goals passed have to start with the next locked one
+    env.fund(&campaign, &bob, 400).await.unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.unlocked_stretch_goals,
+        0
+    );
+    let instruction = ix::fund_campaign(&campaign, &bob.pubkey(), 100, None, 1..2);
+    let result = env.process(&[instruction], &[&bob]).await;
+    assert_hope_rise_error(result, HopeRiseError::StretchGoalMismatch);
+
+    // A single pledge can take the campaign past several goals
+    let instruction = ix::fund_campaign(&campaign, &bob.pubkey(), 500, None, 0..2);
+    let logs = env
+        .process_with_logs(&[instruction], &[&bob])
+        .await
+        .unwrap();
+    let now = env.now().await;
+    let unlocked = events::<StretchGoalUnlocked>(&logs);
+    assert_eq!(unlocked.len(), 2);
+    assert_eq!(
+        unlocked
+            .iter()
+            .map(|event| (event.goal_index, event.threshold, event.amount_raised))
+            .collect::<Vec<_>>(),
+        vec![(0, 1_500, 2_100), (1, 2_000, 2_100)]
+    );
+    for index in 0..2 {
+        let goal = env
+            .stretch_goal(&pda::stretch_goal(&campaign.address, index))
+            .await;
+        assert_eq!(goal.unlocked_at, now);
+    }
+
+    // Unlocks stick once pledges are withdrawn again
+    env.process(
+        &[ix::unpledge(&campaign, &bob.pubkey(), 900, None)],
+        &[&bob],
+    )
+    .await
+    .unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 1_200);
+    assert_eq!(state.unlocked_stretch_goals, 2);
+
+    let result = env
+        .process(
+            &[ix::add_stretch_goal(&campaign, 2, 1_100, "ipfs://well")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidStretchGoalThreshold);
+    env.process(
+        &[ix::add_stretch_goal(&campaign, 2, 3_000, "ipfs://well")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    env.fund(&campaign, &bob, 100).await.unwrap();
+    env.fund_unlocking(&campaign, &bob, 100).await.unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.stretch_goal_count, 3);
+    assert_eq!(state.unlocked_stretch_goals, 2);
+}
+
+#[tokio::test]
+async fn stretch_goals_are_capped() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+
+    let threshold = |index: u8| 2_000 + 1_000 * index as u64;
+    for index in 0..MAX_STRETCH_GOALS_PER_CAMPAIGN {
+        env.process(
+            &[ix::add_stretch_goal(
+                &campaign,
+                index,
+                threshold(index),
+                "ipfs://goal",
+            )],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+    }
+    let instruction = ix::add_stretch_goal(
+        &campaign,
+        MAX_STRETCH_GOALS_PER_CAMPAIGN,
+        threshold(MAX_STRETCH_GOALS_PER_CAMPAIGN),
+        "ipfs://goal",
+    );
+    let result = env.process(&[instruction], &[&creator]).await;
+    assert_hope_rise_error(result, HopeRiseError::MaxStretchGoalsReached);
+    assert_eq!(
+        env.campaign(&campaign.address).await.stretch_goal_count,
+        MAX_STRETCH_GOALS_PER_CAMPAIGN
+    );
+}
+
+#[tokio::test]
+async fn gated_milestones_wait_for_their_stretch_goal() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+
+    for (raised, unlocked) in [(1_500, false), (2_000, true)] {
+        let campaign = env.active_campaign(&creator, asset, 1_000).await;
+        let result = env
+            .process(
+                &[ix::add_milestone(
+                    &campaign,
+                    0,
+                    "Solar panels",
+                    0,
+                    3_000,
+                    Some(0),
+                )],
+                &[&creator],
+            )
+            .await;
+        assert_hope_rise_error(result, HopeRiseError::StretchGoalNotFound);
+        env.process(
+            &[
+                ix::add_stretch_goal(&campaign, 0, 2_000, "ipfs://solar"),
+                ix::add_milestone(&campaign, 0, "Borehole", 0, 5_000, None),
+                ix::add_milestone(&campaign, 1, "Solar panels", 0, 3_000, Some(0)),
+            ],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+        let milestone = env.milestone(&pda::milestone(&campaign.address, 1)).await;
+        assert_eq!(milestone.stretch_goal, Some(0));
+
+        let backer = env.backer(&campaign, raised).await;
+        env.fund_unlocking(&campaign, &backer, raised)
+            .await
+            .unwrap();
+        env.finalize_after_deadline(&campaign).await;
+
+        env.process(
+            &[ix::submit_milestone_proof(&campaign, 0, "ipfs://drilled")],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+        let result = env
+            .process(
+                &[ix::submit_milestone_proof(&campaign, 1, "ipfs://installed")],
+                &[&creator],
+            )
+            .await;
+        if unlocked {
+            result.unwrap();
+        } else {
+            assert_hope_rise_error(result, HopeRiseError::StretchGoalLocked);
+        }
+    }
+}
+
+#[tokio::test]
+async fn shares_gated_on_a_locked_stretch_goal_stay_under_a_vote() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    env.process(
+        &[
+            ix::add_stretch_goal(&campaign, 0, 2_000, "ipfs://solar"),
+            ix::add_milestone(&campaign, 0, "Borehole", 0, 2_000, None),
+            ix::add_milestone(&campaign, 1, "Solar panels", 0, 7_000, Some(0)),
+            ix::add_milestone(&campaign, 2, "Handover", 0, 500, None),
+        ],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let backer = env.backer(&campaign, 1_500).await;
+    env.fund_unlocking(&campaign, &backer, 1_500).await.unwrap();
+
+    let deadline = env.campaign(&campaign.address).await.deadline;
+    let now = env.now().await;
+    env.warp_forward(deadline - now + 1).await;
+    // The goal stayed locked, so every milestone and the goal come along
+    let result = env
+        .process(&[ix::finalize_campaign(&campaign.address, 0..0, 0..0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneMismatch);
+    let instruction = ix::finalize_campaign(&campaign.address, 0..3, 0..0);
+    let result = env.process(&[instruction], &[]).await;
+    assert_hope_rise_error(result, HopeRiseError::StretchGoalMismatch);
+    let mut instruction = ix::finalize_campaign(&campaign.address, 0..3, 0..1);
+    instruction.accounts.swap(2, 3);
+    let result = env.process(&[instruction], &[]).await;
+    assert_hope_rise_error(result, HopeRiseError::MilestoneMismatch);
+    let instruction = ix::finalize_campaign(&campaign.address, 0..3, 0..1);
+    let logs = env.process_with_logs(&[instruction], &[]).await.unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Succeeded);
+    assert_eq!(state.allocated_bps, 9_500);
+
+    // The locked share is split between the milestones still able to take proof
+    let reassigned = events::<MilestonePayoutReassigned>(&logs);
+    let payouts: Vec<_> = reassigned
+        .iter()
+        .map(|event| (event.milestone_index, event.payout_bps))
+        .collect();
+    assert_eq!(payouts, vec![(0, 5_500), (1, 0), (2, 4_000)]);
+    let gated = env.milestone(&pda::milestone(&campaign.address, 1)).await;
+    assert_eq!((gated.payout_bps, gated.stretch_goal), (0, Some(0)));
+
+    // Withdrawing only pays the share no milestone was assigned
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &creator.pubkey()).await, 75);
+    let result = env
+        .process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InsufficientFunds);
+    assert_eq!(env.vault_balance(&campaign).await, 1_425);
+    let result = env
+        .process(
+            &[ix::submit_milestone_proof(&campaign, 1, "ipfs://panels")],
+            &[&creator],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::StretchGoalLocked);
+
+    for (index, released) in [(0, 900), (2, 1_500)] {
+        env.process(
+            &[ix::submit_milestone_proof(&campaign, index, "ipfs://done")],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+        env.process(
+            &[ix::vote_milestone(
+                &campaign.address,
+                index,
+                &backer.pubkey(),
+                true,
+            )],
+            &[&backer],
+        )
+        .await
+        .unwrap();
+        env.warp_forward(MILESTONE_VOTING_PERIOD).await;
+        env.process(&[ix::complete_milestone(&campaign.address, index)], &[])
+            .await
+            .unwrap();
+        env.process(
+            &[ix::release_milestone(&campaign, index, &admin)],
+            &[&creator],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            env.asset_balance(&campaign, &creator.pubkey()).await,
+            released
+        );
+    }
+
+    assert_eq!(env.vault_balance(&campaign).await, 0);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Completed);
+    assert!(state.is_fully_released());
+}
+
+#[tokio::test]
+async fn finalization_unlocks_goals_crossed_by_pledges_leaving_them_out() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    env.process(
+        &[
+            ix::add_stretch_goal(&campaign, 0, 2_000, "ipfs://solar"),
+            ix::add_stretch_goal(&campaign, 1, 5_000, "ipfs://pump"),
+            ix::add_milestone(&campaign, 0, "Borehole", 0, 5_000, None),
+            ix::add_milestone(&campaign, 1, "Solar panels", 0, 3_000, Some(0)),
+        ],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    // A client unaware of stretch goals pledges past the first one
+    let backer = env.backer(&campaign, 2_500).await;
+    env.fund(&campaign, &backer, 2_500).await.unwrap();
+    assert_eq!(
+        env.campaign(&campaign.address).await.unlocked_stretch_goals,
+        0
+    );
+
+    let deadline = env.campaign(&campaign.address).await.deadline;
+    let now = env.now().await;
+    env.warp_forward(deadline - now + 1).await;
+    let instruction = ix::finalize_campaign(&campaign.address, 0..2, 0..2);
+    let logs = env.process_with_logs(&[instruction], &[]).await.unwrap();
+    let unlocked: Vec<_> = events::<StretchGoalUnlocked>(&logs)
+        .iter()
+        .map(|event| (event.goal_index, event.amount_raised))
+        .collect();
+    assert_eq!(unlocked, vec![(0, 2_500)]);
+    assert!(events::<MilestonePayoutReassigned>(&logs).is_empty());
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.status, CampaignStatus::Succeeded);
+    assert_eq!(state.unlocked_stretch_goals, 1);
+
+    // The milestone gated on the crossed goal keeps its share and takes proof
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 1)).await;
+    assert_eq!(milestone.payout_bps, 3_000);
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 1, "ipfs://panels")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn milestones_all_gated_on_a_locked_goal_drop_the_gate() {
+    let mut env = TestEnv::new().await;
+    let admin = env.admin.pubkey();
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 1_000).await;
+    env.process(
+        &[
+            ix::add_stretch_goal(&campaign, 0, 2_000, "ipfs://solar"),
+            ix::add_milestone(&campaign, 0, "Solar panels", 0, 8_000, Some(0)),
+        ],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let backer = env.backer(&campaign, 1_000).await;
+    env.fund_unlocking(&campaign, &backer, 1_000).await.unwrap();
+    env.finalize_after_deadline(&campaign).await;
+
+    // With nowhere to move the share, the milestone itself goes to a vote
+    let milestone = env.milestone(&pda::milestone(&campaign.address, 0)).await;
+    assert_eq!(
+        (milestone.payout_bps, milestone.stretch_goal),
+        (8_000, None)
+    );
+    env.process(&[ix::withdraw_funds(&campaign, &admin)], &[&creator])
+        .await
+        .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &creator.pubkey()).await, 200);
+    env.process(
+        &[ix::submit_milestone_proof(&campaign, 0, "ipfs://panels")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn stretch_goals_close_once_the_campaign_settles() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    env.process(
+        &[ix::add_stretch_goal(&campaign, 0, 20_000, "ipfs://goal")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+
+    let result = env
+        .process(&[ix::close_stretch_goal(&campaign, 0)], &[])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotSettled);
+
+    env.finalize_after_deadline(&campaign).await;
+    let result = env
+        .process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignAccountsStillOpen);
+
+    let goal = pda::stretch_goal(&campaign.address, 0);
+    let rent = env.lamports(&goal).await;
+    let before = env.lamports(&creator.pubkey()).await;
+    env.process(&[ix::close_stretch_goal(&campaign, 0)], &[])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&goal).await.is_none());
+    assert_eq!(env.lamports(&creator.pubkey()).await, before + rent);
+    assert_eq!(env.campaign(&campaign.address).await.open_stretch_goals, 0);
+
+    env.process(&[ix::close_campaign_accounts(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    assert!(env.raw_account(&campaign.address).await.is_none());
+}
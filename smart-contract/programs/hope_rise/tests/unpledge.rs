@@ -0,0 +1,217 @@
+#![cfg(feature = "test-sbf")]
+
+mod common;
+
+use common::*;
+use hope_rise::{constants::SECONDS_PER_HOUR, errors::HopeRiseError};
+use solana_sdk::signature::Signer;
+
+#[tokio::test]
+async fn unpledges_update_the_campaign_totals() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    let alice = env.backer(&campaign, 5_000).await;
+    let bob = env.backer(&campaign, 5_000).await;
+    env.fund(&campaign, &alice, 3_000).await.unwrap();
+    env.fund(&campaign, &bob, 1_000).await.unwrap();
+
+    let result = env
+        .process(
+            &[ix::unpledge(&campaign, &alice.pubkey(), 0, None)],
+            &[&alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::InvalidContributionAmount);
+    let result = env
+        .process(
+            &[ix::unpledge(&campaign, &alice.pubkey(), 3_001, None)],
+            &[&alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::UnpledgeExceedsContribution);
+
+    env.process(
+        &[ix::unpledge(&campaign, &alice.pubkey(), 1_000, None)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 3_000);
+    let contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert_eq!(contribution.amount, 2_000);
+    assert!(contribution.has_receipt);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 3_000);
+    assert_eq!(state.backer_count, 2);
+
+    // Withdrawing everything takes the backer out and burns their receipt
+    env.process(
+        &[ix::unpledge(&campaign, &alice.pubkey(), 2_000, None)],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.asset_balance(&campaign, &alice.pubkey()).await, 5_000);
+    assert_eq!(env.vault_balance(&campaign).await, 1_000);
+    let contribution_address = pda::contribution(&campaign.address, &alice.pubkey());
+    assert!(env
+        .raw_account(&pda::receipt_mint(&contribution_address))
+        .await
+        .is_none());
+    let contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert_eq!(contribution.amount, 0);
+    assert!(!contribution.has_receipt);
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 1_000);
+    assert_eq!(state.backer_count, 1);
+    assert_eq!(state.open_contributions, 2);
+
+    // Backing again reuses the account and mints a fresh receipt
+    env.fund(&campaign, &alice, 500).await.unwrap();
+    let state = env.campaign(&campaign.address).await;
+    assert_eq!(state.amount_raised, 1_500);
+    assert_eq!(state.backer_count, 2);
+    assert_eq!(state.open_contributions, 2);
+    assert!(
+        env.contribution(&campaign.address, &alice.pubkey())
+            .await
+            .has_receipt
+    );
+    let receipt = ix::receipt_token_account(&campaign.address, &alice.pubkey());
+    assert_eq!(env.token_balance(&receipt).await, 1);
+}
+
+#[tokio::test]
+async fn unpledging_closes_at_the_cutoff() {
+    let mut env = TestEnv::new().await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let args = CampaignArgs {
+        duration_days: 2,
+        unpledge_cutoff_hours: 49,
+        ..campaign_args()
+    };
+    let result = env.create_campaign(&creator, Asset::Sol, args).await;
+    assert_hope_rise_error(result.map(|_| ()), HopeRiseError::InvalidUnpledgeCutoff);
+
+    let args = CampaignArgs {
+        funding_goal: LAMPORTS_PER_SOL,
+        duration_days: 3,
+        unpledge_cutoff_hours: 48,
+        ..campaign_args()
+    };
+    let campaign = env
+        .create_campaign(&creator, Asset::Sol, args)
+        .await
+        .unwrap();
+    env.process(&[ix::launch_campaign(&campaign)], &[&creator])
+        .await
+        .unwrap();
+    let alice = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    let bob = env.backer(&campaign, LAMPORTS_PER_SOL).await;
+    env.fund(&campaign, &alice, LAMPORTS_PER_SOL / 4)
+        .await
+        .unwrap();
+    env.fund(&campaign, &bob, LAMPORTS_PER_SOL / 4)
+        .await
+        .unwrap();
+    env.process(
+        &[ix::unpledge(
+            &campaign,
+            &alice.pubkey(),
+            LAMPORTS_PER_SOL / 4,
+            None,
+        )],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+
+    let deadline = env.campaign(&campaign.address).await.deadline;
+    let now = env.now().await;
+    env.warp_forward(deadline - 48 * SECONDS_PER_HOUR - now)
+        .await;
+    let result = env
+        .process(&[ix::unpledge(&campaign, &bob.pubkey(), 1, None)], &[&bob])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::UnpledgeWindowClosed);
+
+    env.finalize_after_deadline(&campaign).await;
+    let result = env
+        .process(&[ix::unpledge(&campaign, &bob.pubkey(), 1, None)], &[&bob])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::CampaignNotActive);
+
+    // Nothing is left to refund, so the emptied contribution closes directly
+    let result = env
+        .process(&[ix::claim_refund(&campaign, &alice.pubkey())], &[&alice])
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::NoContribution);
+    env.process(
+        &[ix::close_contribution(&campaign.address, &alice.pubkey())],
+        &[],
+    )
+    .await
+    .unwrap();
+    let result = env
+        .process(
+            &[ix::close_contribution(&campaign.address, &bob.pubkey())],
+            &[],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::ContributionNotSettled);
+    assert_eq!(env.campaign(&campaign.address).await.open_contributions, 1);
+}
+
+#[tokio::test]
+async fn unpledging_below_the_tier_minimum_releases_the_reward() {
+    let mut env = TestEnv::new().await;
+    let asset = env.accepted_mint(&TOKEN_PROGRAM_ID).await;
+    let creator = env.funded_keypair(LAMPORTS_PER_SOL).await;
+    let campaign = env.active_campaign(&creator, asset, 10_000).await;
+    env.process(
+        &[ix::add_reward_tier(&campaign, 0, 1_000, 1, "ipfs://tier")],
+        &[&creator],
+    )
+    .await
+    .unwrap();
+    let alice = env.backer(&campaign, 2_000).await;
+    let bob = env.backer(&campaign, 2_000).await;
+    env.fund_with_tier(&campaign, &alice, 1_500, 0)
+        .await
+        .unwrap();
+
+    // The tier has to come along while the contributor holds it
+    let result = env
+        .process(
+            &[ix::unpledge(&campaign, &alice.pubkey(), 100, None)],
+            &[&alice],
+        )
+        .await;
+    assert_hope_rise_error(result, HopeRiseError::RewardTierMismatch);
+
+    env.process(
+        &[ix::unpledge(&campaign, &alice.pubkey(), 500, Some(0))],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    let tier = pda::reward_tier(&campaign.address, 0);
+    assert_eq!(env.reward_tier(&tier).await.claimed, 1);
+    let result = env.fund_with_tier(&campaign, &bob, 1_000, 0).await;
+    assert_hope_rise_error(result, HopeRiseError::RewardTierSoldOut);
+
+    env.process(
+        &[ix::unpledge(&campaign, &alice.pubkey(), 1, Some(0))],
+        &[&alice],
+    )
+    .await
+    .unwrap();
+    assert_eq!(env.reward_tier(&tier).await.claimed, 0);
+    let contribution = env.contribution(&campaign.address, &alice.pubkey()).await;
+    assert_eq!(contribution.amount, 999);
+    assert_eq!(contribution.reward_tier, None);
+    env.fund_with_tier(&campaign, &bob, 1_000, 0).await.unwrap();
+    assert_eq!(env.reward_tier(&tier).await.claimed, 1);
+}
@@ -1,9 +1,41 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Program } from "@coral-xyz/anchor";
+import { Program, EventParser } from "@coral-xyz/anchor";
 import { HopeRise } from "../target/types/hope_rise";
 import { expect } from "chai";
 import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
 
+const TOKEN_2022_PROGRAM_ID = new PublicKey(
+  "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+);
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
+  "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
+);
+
+// Receipt mint of a contribution and the contributor's Token-2022 account for it
+function receiptAccounts(
+  programId: PublicKey,
+  contribution: PublicKey,
+  contributor: PublicKey
+) {
+  const [receiptMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from("receipt_mint"), contribution.toBuffer()],
+    programId
+  );
+  const [receiptTokenAccount] = PublicKey.findProgramAddressSync(
+    [
+      contributor.toBuffer(),
+      TOKEN_2022_PROGRAM_ID.toBuffer(),
+      receiptMint.toBuffer(),
+    ],
+    ASSOCIATED_TOKEN_PROGRAM_ID
+  );
+  return {
+    receiptMint,
+    receiptTokenAccount,
+    receiptTokenProgram: TOKEN_2022_PROGRAM_ID,
+  };
+}
+
 describe("hope_rise", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
@@ -16,10 +48,15 @@ describe("hope_rise", () => {
 
   // PDAs
   let campaignCounterPda: PublicKey;
+  let platformConfigPda: PublicKey;
   let campaignPda: PublicKey;
+  let solVaultPda: PublicKey;
   let contributionPda: PublicKey;
   let milestonePda: PublicKey;
 
+  // Transactions whose logs are decoded
+  let fundSignature: string;
+
   // Campaign data
   const campaignTitle = "Test Campaign";
   const shortDescription = "A test campaign for unit testing";
@@ -42,17 +79,21 @@ describe("hope_rise", () => {
     );
     await provider.connection.confirmTransaction(airdropContributor);
 
-    // Derive campaign counter PDA
+    // Derive campaign counter and platform config PDAs
     [campaignCounterPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("campaign_counter")],
       program.programId
     );
+    [platformConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("platform_config")],
+      program.programId
+    );
   });
 
-  it("Initializes the campaign counter", async () => {
+  it("Initializes the campaign counter and platform config", async () => {
     await program.methods
       .initialize()
-      .accounts({
+      .accountsPartial({
         campaignCounter: campaignCounterPda,
         authority: provider.wallet.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -61,6 +102,43 @@ describe("hope_rise", () => {
 
     const counter = await program.account.campaignCounter.fetch(campaignCounterPda);
     expect(counter.count.toNumber()).to.equal(0);
+    expect(counter.authority.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
+
+    // Only the upgrade authority of the deployed program may create the config
+    const [programDataPda] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+    try {
+      await program.methods
+        .initializePlatformConfig()
+        .accountsPartial({
+          platformConfig: platformConfigPda,
+          program: program.programId,
+          programData: programDataPda,
+          authority: creator.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+      expect.fail("Expected the config to require the upgrade authority");
+    } catch (err) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+
+    await program.methods
+      .initializePlatformConfig()
+      .accountsPartial({
+        platformConfig: platformConfigPda,
+        program: program.programId,
+        programData: programDataPda,
+        authority: provider.wallet.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .rpc();
+
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
+    expect(config.admin.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
   });
 
   it("Creates a campaign", async () => {
@@ -75,6 +153,10 @@ describe("hope_rise", () => {
       ],
       program.programId
     );
+    [solVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault"), campaignPda.toBuffer()],
+      program.programId
+    );
 
     await program.methods
       .createCampaign(
@@ -84,13 +166,24 @@ describe("hope_rise", () => {
         coverImageUrl,
         storyUrl,
         fundingGoal,
-        durationDays
+        durationDays,
+        { sol: {} }, // Funded in lamports, no mint needed
+        0, // vote quorum bps
+        5000, // vote approval bps
+        true, // allow early close once the goal is met
+        false, // transferable receipts
+        48 // unpledge cutoff hours
       )
-      .accounts({
+      .accountsPartial({
         campaign: campaignPda,
         campaignCounter: campaignCounterPda,
+        platformConfig: platformConfigPda,
+        mint: null,
+        solVault: solVaultPda,
         creator: creator.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
+        campaignVault: null,
+        tokenProgram: null,
       })
       .signers([creator])
       .rpc();
@@ -99,7 +192,7 @@ describe("hope_rise", () => {
     expect(campaign.title).to.equal(campaignTitle);
     expect(campaign.shortDescription).to.equal(shortDescription);
     expect(campaign.fundingGoal.toNumber()).to.equal(fundingGoal.toNumber());
-    expect(campaign.isActive).to.be.true;
+    expect(campaign.status).to.deep.equal({ draft: {} });
     expect(campaign.amountRaised.toNumber()).to.equal(0);
     expect(campaign.backerCount.toNumber()).to.equal(0);
   });
@@ -117,9 +210,15 @@ describe("hope_rise", () => {
     );
 
     await program.methods
-      .addMilestone("First Milestone", new anchor.BN(0.5 * LAMPORTS_PER_SOL))
-      .accounts({
+      .addMilestone(
+        "First Milestone",
+        new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+        5000, // pays out half of the funds raised
+        null // not gated on a stretch goal
+      )
+      .accountsPartial({
         campaign: campaignPda,
+        platformConfig: platformConfigPda,
         milestone: milestonePda,
         creator: creator.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -129,9 +228,25 @@ describe("hope_rise", () => {
 
     const milestone = await program.account.milestone.fetch(milestonePda);
     expect(milestone.title).to.equal("First Milestone");
+    expect(milestone.payoutBps).to.equal(5000);
     expect(milestone.isCompleted).to.be.false;
   });
 
+  it("Launches the campaign", async () => {
+    await program.methods
+      .launchCampaign()
+      .accountsPartial({
+        campaign: campaignPda,
+        platformConfig: platformConfigPda,
+        creator: creator.publicKey,
+      })
+      .signers([creator])
+      .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ active: {} });
+  });
+
   it("Funds a campaign", async () => {
     [contributionPda] = PublicKey.findProgramAddressSync(
       [
@@ -144,13 +259,22 @@ describe("hope_rise", () => {
 
     const fundAmount = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
 
-    await program.methods
-      .fundCampaign(fundAmount)
-      .accounts({
+    fundSignature = await program.methods
+      .fundCampaign(fundAmount, null)
+      .accountsPartial({
         campaign: campaignPda,
+        platformConfig: platformConfigPda,
         contribution: contributionPda,
+        campaignVault: null,
+        solVault: solVaultPda,
         contributor: contributor.publicKey,
+        contributorTokenAccount: null,
+        mint: null,
+        tokenProgram: null,
         systemProgram: anchor.web3.SystemProgram.programId,
+        rewardTier: null,
+        ...receiptAccounts(program.programId, contributionPda, contributor.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([contributor])
       .rpc();
@@ -161,46 +285,116 @@ describe("hope_rise", () => {
 
     const contribution = await program.account.contribution.fetch(contributionPda);
     expect(contribution.amount.toNumber()).to.equal(fundAmount.toNumber());
+    expect(contribution.hasReceipt).to.be.true;
   });
 
-  it("Completes a milestone when target is reached", async () => {
-    // Fund more to reach milestone target
+  it("Decodes the contribution from the transaction logs", async () => {
+    await provider.connection.confirmTransaction(fundSignature, "confirmed");
+    const tx = await provider.connection.getTransaction(fundSignature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+
+    const parser = new EventParser(program.programId, program.coder);
+    const events = [...parser.parseLogs(tx.meta.logMessages)];
+    const contributionMade = events.find((e) => e.name === "contributionMade");
+
+    expect(contributionMade).to.not.be.undefined;
+    expect(contributionMade.data.campaign.equals(campaignPda)).to.be.true;
+    expect(contributionMade.data.contributor.equals(contributor.publicKey)).to.be.true;
+    expect(contributionMade.data.amount.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+    expect(contributionMade.data.amountRaised.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
+    expect(contributionMade.data.rewardTier).to.be.null;
+  });
+
+  it("Decodes a milestone release from the transaction logs", async () => {
+    // Releases need the voting period to elapse, so decode a log line the
+    // program would emit instead of waiting it out on the validator
+    const released = {
+      campaign: campaignPda,
+      milestone: Keypair.generate().publicKey,
+      milestoneIndex: 0,
+      releasedBps: 2500,
+      timestamp: new anchor.BN(1_700_000_000),
+    };
+    const discriminator = program.idl.events.find(
+      (e) => e.name === "milestoneReleased"
+    ).discriminator;
+    const data = Buffer.concat([
+      Buffer.from(discriminator),
+      program.coder.types.encode("milestoneReleased", released),
+    ]);
+    const logs = [
+      `Program ${program.programId} invoke [1]`,
+      `Program data: ${data.toString("base64")}`,
+      `Program ${program.programId} success`,
+    ];
+
+    const parser = new EventParser(program.programId, program.coder);
+    const [event] = [...parser.parseLogs(logs)];
+
+    expect(event.name).to.equal("milestoneReleased");
+    expect(event.data.campaign.equals(campaignPda)).to.be.true;
+    expect(event.data.milestone.equals(released.milestone)).to.be.true;
+    expect(event.data.milestoneIndex).to.equal(0);
+    expect(event.data.releasedBps).to.equal(2500);
+    expect(event.data.timestamp.toNumber()).to.equal(1_700_000_000);
+  });
+
+  it("Finalizes early once the goal is met", async () => {
+    // Fund the rest of the goal
     const additionalFund = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
 
     await program.methods
-      .fundCampaign(additionalFund)
-      .accounts({
+      .fundCampaign(additionalFund, null)
+      .accountsPartial({
         campaign: campaignPda,
+        platformConfig: platformConfigPda,
         contribution: contributionPda,
+        campaignVault: null,
+        solVault: solVaultPda,
         contributor: contributor.publicKey,
+        contributorTokenAccount: null,
+        mint: null,
+        tokenProgram: null,
         systemProgram: anchor.web3.SystemProgram.programId,
+        rewardTier: null,
+        ...receiptAccounts(program.programId, contributionPda, contributor.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([contributor])
       .rpc();
 
-    // Now complete the milestone
     await program.methods
-      .completeMilestone()
-      .accounts({
+      .finalizeCampaign()
+      .accountsPartial({
         campaign: campaignPda,
-        milestone: milestonePda,
-        creator: creator.publicKey,
+        platformConfig: platformConfigPda,
       })
-      .signers([creator])
       .rpc();
 
-    const milestone = await program.account.milestone.fetch(milestonePda);
-    expect(milestone.isCompleted).to.be.true;
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.status).to.deep.equal({ succeeded: {} });
   });
 
-  it("Allows creator to withdraw when goal is met", async () => {
+  it("Allows creator to withdraw the share no milestone holds", async () => {
+    const config = await program.account.platformConfig.fetch(platformConfigPda);
     const creatorBalanceBefore = await provider.connection.getBalance(creator.publicKey);
 
     await program.methods
       .withdrawFunds()
-      .accounts({
+      .accountsPartial({
         campaign: campaignPda,
+        campaignVault: null,
+        solVault: solVaultPda,
         creator: creator.publicKey,
+        creatorTokenAccount: null,
+        platformConfig: platformConfigPda,
+        treasury: config.treasury,
+        treasuryTokenAccount: null,
+        mint: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
         systemProgram: anchor.web3.SystemProgram.programId,
       })
       .signers([creator])
@@ -208,20 +402,50 @@ describe("hope_rise", () => {
 
     const creatorBalanceAfter = await provider.connection.getBalance(creator.publicKey);
     expect(creatorBalanceAfter).to.be.greaterThan(creatorBalanceBefore);
+
+    // The milestone's half stays in the vault until backers approve it
+    const campaign = await program.account.campaign.fetch(campaignPda);
+    expect(campaign.releasedAmount.toNumber()).to.equal(0.5 * LAMPORTS_PER_SOL);
   });
 
-  it("Closes a campaign", async () => {
+  it("Opens a backer vote on the milestone", async () => {
     await program.methods
-      .closeCampaign()
-      .accounts({
+      .submitMilestoneProof("ipfs://QmProof789")
+      .accountsPartial({
         campaign: campaignPda,
+        platformConfig: platformConfigPda,
+        milestone: milestonePda,
         creator: creator.publicKey,
       })
       .signers([creator])
       .rpc();
 
-    const campaign = await program.account.campaign.fetch(campaignPda);
-    expect(campaign.isActive).to.be.false;
+    const [milestoneVotePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("milestone_vote"),
+        milestonePda.toBuffer(),
+        contributor.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .voteMilestone(true)
+      .accountsPartial({
+        campaign: campaignPda,
+        platformConfig: platformConfigPda,
+        milestone: milestonePda,
+        contribution: contributionPda,
+        milestoneVote: milestoneVotePda,
+        contributor: contributor.publicKey,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      })
+      .signers([contributor])
+      .rpc();
+
+    const milestone = await program.account.milestone.fetch(milestonePda);
+    expect(milestone.yesWeight.toNumber()).to.equal(1 * LAMPORTS_PER_SOL);
+    expect(milestone.isCompleted).to.be.false;
   });
 });
 
@@ -235,7 +459,9 @@ describe("hope_rise - refund flow", () => {
   const contributor2 = Keypair.generate();
 
   let campaignCounterPda: PublicKey;
+  let platformConfigPda: PublicKey;
   let campaign2Pda: PublicKey;
+  let solVault2Pda: PublicKey;
   let contribution2Pda: PublicKey;
 
   before(async () => {
@@ -256,9 +482,13 @@ describe("hope_rise - refund flow", () => {
       [Buffer.from("campaign_counter")],
       program.programId
     );
+    [platformConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("platform_config")],
+      program.programId
+    );
   });
 
-  it("Creates a campaign with high goal", async () => {
+  it("Creates and launches a campaign with high goal", async () => {
     const counter = await program.account.campaignCounter.fetch(campaignCounterPda);
 
     [campaign2Pda] = PublicKey.findProgramAddressSync(
@@ -269,6 +499,10 @@ describe("hope_rise - refund flow", () => {
       ],
       program.programId
     );
+    [solVault2Pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault"), campaign2Pda.toBuffer()],
+      program.programId
+    );
 
     await program.methods
       .createCampaign(
@@ -278,13 +512,34 @@ describe("hope_rise - refund flow", () => {
         "ipfs://test",
         "ipfs://story",
         new anchor.BN(100 * LAMPORTS_PER_SOL), // 100 SOL - high goal
-        new anchor.BN(1) // 1 day duration
+        new anchor.BN(1), // 1 day duration
+        { sol: {} },
+        0,
+        5000,
+        false,
+        false,
+        0
       )
-      .accounts({
+      .accountsPartial({
         campaign: campaign2Pda,
         campaignCounter: campaignCounterPda,
+        platformConfig: platformConfigPda,
+        mint: null,
+        solVault: solVault2Pda,
         creator: creator2.publicKey,
         systemProgram: anchor.web3.SystemProgram.programId,
+        campaignVault: null,
+        tokenProgram: null,
+      })
+      .signers([creator2])
+      .rpc();
+
+    await program.methods
+      .launchCampaign()
+      .accountsPartial({
+        campaign: campaign2Pda,
+        platformConfig: platformConfigPda,
+        creator: creator2.publicKey,
       })
       .signers([creator2])
       .rpc();
@@ -301,12 +556,21 @@ describe("hope_rise - refund flow", () => {
     );
 
     await program.methods
-      .fundCampaign(new anchor.BN(1 * LAMPORTS_PER_SOL))
-      .accounts({
+      .fundCampaign(new anchor.BN(1 * LAMPORTS_PER_SOL), null)
+      .accountsPartial({
         campaign: campaign2Pda,
+        platformConfig: platformConfigPda,
         contribution: contribution2Pda,
+        campaignVault: null,
+        solVault: solVault2Pda,
         contributor: contributor2.publicKey,
+        contributorTokenAccount: null,
+        mint: null,
+        tokenProgram: null,
         systemProgram: anchor.web3.SystemProgram.programId,
+        rewardTier: null,
+        ...receiptAccounts(program.programId, contribution2Pda, contributor2.publicKey),
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .signers([contributor2])
       .rpc();
@@ -315,12 +579,16 @@ describe("hope_rise - refund flow", () => {
   it("Creator closes campaign (goal not met)", async () => {
     await program.methods
       .closeCampaign()
-      .accounts({
+      .accountsPartial({
         campaign: campaign2Pda,
+        platformConfig: platformConfigPda,
         creator: creator2.publicKey,
       })
       .signers([creator2])
       .rpc();
+
+    const campaign = await program.account.campaign.fetch(campaign2Pda);
+    expect(campaign.status).to.deep.equal({ cancelled: {} });
   });
 
   it("Contributor claims refund", async () => {
@@ -328,11 +596,19 @@ describe("hope_rise - refund flow", () => {
 
     await program.methods
       .claimRefund()
-      .accounts({
+      .accountsPartial({
         campaign: campaign2Pda,
+        platformConfig: platformConfigPda,
+        campaignVault: null,
+        solVault: solVault2Pda,
         contribution: contribution2Pda,
         contributor: contributor2.publicKey,
+        contributorTokenAccount: null,
+        mint: null,
+        tokenProgram: null,
+        associatedTokenProgram: null,
         systemProgram: anchor.web3.SystemProgram.programId,
+        ...receiptAccounts(program.programId, contribution2Pda, contributor2.publicKey),
       })
       .signers([contributor2])
       .rpc();
@@ -342,5 +618,6 @@ describe("hope_rise - refund flow", () => {
 
     const contribution = await program.account.contribution.fetch(contribution2Pda);
     expect(contribution.refundClaimed).to.be.true;
+    expect(contribution.hasReceipt).to.be.false;
   });
 });